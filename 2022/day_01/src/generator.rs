@@ -0,0 +1,41 @@
+//
+
+use advent_of_code::generate::{Generator, Rng};
+
+//
+
+/// Calories carried by `size` elves, at least three of them.
+pub struct InputGenerator;
+
+impl Generator for InputGenerator {
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        let inventories: Vec<String> = (0..size.max(3))
+            .map(|_| {
+                let items = rng.range(1..=15);
+                (0..items)
+                    .map(|_| rng.range(1_000..=70_000).to_string())
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            })
+            .collect();
+        inventories.join("\n\n") + "\n"
+    }
+}
+
+#[cfg(test)]
+mod test_generator {
+    use advent_of_code::generate::generate;
+
+    use super::*;
+    use crate::{part_one, part_two};
+
+    #[test]
+    fn test_generated_inputs_are_solvable() {
+        for seed in 0..20 {
+            let input = generate(&InputGenerator, 50, seed);
+            assert_eq!(input.split("\n\n").count(), 50);
+            assert!(part_one(&input).is_some(), "seed {seed}");
+            assert!(part_two(&input).is_some(), "seed {seed}");
+        }
+    }
+}
@@ -1,28 +1,80 @@
 //
 
+mod generator;
+
+//
+
+use advent_of_code::compare::Registry;
+use advent_of_code::helpers::parse::{blocks, lines, parse_all, report, unsigned, ParseError};
+
+pub use generator::InputGenerator;
+
+//
+
 pub fn part_one(input: &str) -> Option<u32> {
-    input
-        .split("\n\n")
-        .map(|inventory| {
-            inventory
-                .lines()
-                .flat_map(|calories| calories.parse::<u32>())
-                .sum()
-        })
-        .max()
+    let most = inventories(input)?
+        .iter()
+        .map(|inventory| inventory.iter().sum())
+        .max();
+    Some(most.unwrap_or(0))
 }
 
 pub fn part_two(input: &str) -> Option<u32> {
     use itertools::Itertools;
-    input
-        .split("\n\n")
-        .map(|inventory| {
-            inventory
-                .lines()
-                .flat_map(|calories| calories.parse::<u32>())
-                .sum1::<u32>()
-        })
+    inventories(input)?
+        .iter()
+        .map(|inventory| inventory.iter().sum::<u32>())
         .sorted_by(|a, b| Ord::cmp(b, a))
         .take(3)
+        .map(Some)
         .sum()
 }
+
+/// Both parts, for `cargo compare` and `cargo minimize`.
+pub fn implementations() -> Registry {
+    Registry::new()
+        .parser(|input| parse_inventories(input).is_ok())
+        .register(1, "solution", part_one)
+        .register(2, "solution", part_two)
+}
+
+//
+
+fn inventories(input: &str) -> Option<Vec<Vec<u32>>> {
+    report("invalid calories list", parse_inventories(input))
+}
+
+/// The inventories of the list, none for an empty list.
+fn parse_inventories(input: &str) -> Result<Vec<Vec<u32>>, ParseError> {
+    if input.trim().is_empty() {
+        return Ok(Vec::new());
+    }
+    parse_all(blocks(lines(unsigned)), input)
+}
+
+#[cfg(test)]
+mod test_inventories {
+    use super::*;
+
+    #[test]
+    fn test_inventories() {
+        assert_eq!(
+            inventories("1000\n2000\n\n4000\n"),
+            Some(vec![vec![1000, 2000], vec![4000]])
+        );
+    }
+
+    #[test]
+    fn test_empty_list() {
+        assert_eq!(inventories(""), Some(Vec::new()));
+        assert_eq!(part_one(""), Some(0));
+        assert_eq!(part_two("\n"), Some(0));
+    }
+
+    #[test]
+    fn test_invalid_calories() {
+        assert_eq!(part_one("1000\n2OOO\n\n4000\n"), None);
+        let error = advent_of_code::helpers::parse::take_reported().unwrap();
+        assert!(error.contains("line 2, column 2: unexpected input (found `OOO`)"));
+    }
+}
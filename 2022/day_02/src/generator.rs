@@ -0,0 +1,47 @@
+//
+
+use advent_of_code::generate::{Generator, Rng};
+
+//
+
+/// A strategy guide of `size` rounds.
+pub struct InputGenerator;
+
+impl Generator for InputGenerator {
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        (0..size.max(1))
+            .map(|_| {
+                format!(
+                    "{} {}\n",
+                    rng.choose(&['A', 'B', 'C']),
+                    rng.choose(&['X', 'Y', 'Z'])
+                )
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test_generator {
+    use advent_of_code::generate::generate;
+
+    use super::*;
+    use crate::{part_one, part_two};
+
+    #[test]
+    fn test_generated_inputs_are_solvable() {
+        for seed in 0..20 {
+            let input = generate(&InputGenerator, 50, seed);
+            assert_eq!(input.lines().count(), 50);
+            // Every round scores between 1 and 9
+            assert!(
+                (50..=450).contains(&part_one(&input).unwrap()),
+                "seed {seed}"
+            );
+            assert!(
+                (50..=450).contains(&part_two(&input).unwrap()),
+                "seed {seed}"
+            );
+        }
+    }
+}
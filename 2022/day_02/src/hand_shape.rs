@@ -1,10 +1,10 @@
 //
 
-use std::convert::Infallible;
+use advent_of_code::helpers::parse::ParseError;
 
 //
 
-#[derive(Copy, Clone, PartialEq, Eq)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum HandShape {
     Rock = 1,
     Paper = 2,
@@ -12,14 +12,38 @@ pub enum HandShape {
 }
 
 impl std::str::FromStr for HandShape {
-    type Err = Infallible;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             "A" | "X" => Ok(Self::Rock),
             "B" | "Y" => Ok(Self::Paper),
             "C" | "Z" => Ok(Self::Scissors),
-            _ => unreachable!("invalid hand shape : {s}"),
+            _ => Err(ParseError::new(
+                s,
+                s,
+                "invalid hand shape, expected A, B, C, X, Y or Z",
+            )),
         }
     }
 }
+
+#[cfg(test)]
+mod test_hand_shape_from_str {
+    use super::*;
+
+    #[test]
+    fn test_rock() {
+        assert_eq!("A".parse(), Ok(HandShape::Rock));
+        assert_eq!("X".parse(), Ok(HandShape::Rock));
+    }
+
+    #[test]
+    fn test_invalid() {
+        let error = "W".parse::<HandShape>().unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.token.as_str()),
+            (1, 1, "W")
+        );
+    }
+}
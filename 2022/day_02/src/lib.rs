@@ -1,56 +1,109 @@
 //
 
 mod beats;
+mod generator;
 mod hand_shape;
 mod round;
 mod strategy;
 
 //
 
-use std::convert::Infallible;
+use advent_of_code::compare::Registry;
+use advent_of_code::helpers::parse::{map_lines, report, ParseError};
 
-use crate::round::Round;
+use crate::{round::Round, strategy::Strategy};
+
+pub use generator::InputGenerator;
 
 //
 
 pub fn part_one(input: &str) -> Option<u32> {
-    let line_to_round = |round_line: &str| -> Result<Round, Infallible> {
-        let (opponent_letter, player_move) = round_line
-            .split_once(' ')
-            .expect("Could not split opponent and player letters");
-        Ok(Round {
-            opponent: opponent_letter.parse()?,
-            player: player_move.parse()?,
-        })
-    };
-
-    input
-        .lines()
-        .flat_map(line_to_round)
+    report("invalid strategy guide", map_lines(input, round_from_move))?
+        .iter()
         .map(|round| round.get_player_score())
         .map(Some)
         .sum()
 }
 
 pub fn part_two(input: &str) -> Option<u32> {
-    use crate::strategy::Strategy;
-
-    let line_to_round = |round_line: &str| -> Result<Round, Infallible> {
-        let (opponent_letter, strategy_letter) = round_line
-            .split_once(' ')
-            .expect("Could not split opponent and strategy letters");
-        let opponent = opponent_letter.parse()?;
-        let player = strategy_letter
-            .parse::<Strategy>()
-            .unwrap()
-            .winning_hand(&opponent);
-        Ok(Round { opponent, player })
-    };
-
-    input
-        .lines()
-        .flat_map(line_to_round)
-        .map(|round| round.get_player_score())
-        .map(Some)
-        .sum()
+    report(
+        "invalid strategy guide",
+        map_lines(input, round_from_strategy),
+    )?
+    .iter()
+    .map(|round| round.get_player_score())
+    .map(Some)
+    .sum()
+}
+
+/// Both parts, for `cargo compare` and `cargo minimize`.
+pub fn implementations() -> Registry {
+    Registry::new()
+        .parser(|input| {
+            map_lines(input, round_from_move).is_ok()
+                && map_lines(input, round_from_strategy).is_ok()
+        })
+        .register(1, "solution", part_one)
+        .register(2, "solution", part_two)
+}
+
+//
+
+/// A round whose second letter is the hand shape to play.
+fn round_from_move(round_line: &str) -> Result<Round, ParseError> {
+    let (opponent_letter, player_move) = split_letters(round_line)?;
+    Ok(Round {
+        opponent: parse_letter(round_line, opponent_letter)?,
+        player: parse_letter(round_line, player_move)?,
+    })
+}
+
+/// A round whose second letter is how it must end.
+fn round_from_strategy(round_line: &str) -> Result<Round, ParseError> {
+    let (opponent_letter, strategy_letter) = split_letters(round_line)?;
+    let opponent = parse_letter(round_line, opponent_letter)?;
+    let player = parse_letter::<Strategy>(round_line, strategy_letter)?.winning_hand(&opponent);
+    Ok(Round { opponent, player })
+}
+
+fn split_letters(round_line: &str) -> Result<(&str, &str), ParseError> {
+    round_line.split_once(' ').ok_or_else(|| {
+        ParseError::new(
+            round_line,
+            round_line,
+            "expected two letters separated by a space",
+        )
+    })
+}
+
+fn parse_letter<T>(round_line: &str, letter: &str) -> Result<T, ParseError>
+where
+    T: std::str::FromStr<Err = ParseError>,
+{
+    letter
+        .parse()
+        .map_err(|e: ParseError| e.within(round_line, letter))
+}
+
+#[cfg(test)]
+mod test_invalid_guide {
+    use advent_of_code::helpers::parse::take_reported;
+
+    use super::*;
+
+    #[test]
+    fn test_invalid_strategy() {
+        assert_eq!(part_two("A Y\nB W\nC Z"), None);
+        let error = take_reported().unwrap();
+        assert!(
+            error.contains("line 2, column 3: invalid strategy, expected X, Y or Z (found `W`)")
+        );
+    }
+
+    #[test]
+    fn test_missing_space() {
+        assert_eq!(part_one("AY"), None);
+        let error = take_reported().unwrap();
+        assert!(error.contains("line 1, column 1: expected two letters separated by a space"));
+    }
 }
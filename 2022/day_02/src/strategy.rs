@@ -1,6 +1,6 @@
 //
 
-use std::convert::Infallible;
+use advent_of_code::helpers::parse::ParseError;
 
 use crate::{beats::Beats, hand_shape::HandShape};
 
@@ -23,14 +23,18 @@ impl Strategy {
 }
 
 impl std::str::FromStr for Strategy {
-    type Err = Infallible;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             "X" => Ok(Self::Won),
             "Y" => Ok(Self::Draw),
             "Z" => Ok(Self::Lost),
-            _ => unreachable!("invalid strategy : {s}"),
+            _ => Err(ParseError::new(
+                s,
+                s,
+                "invalid strategy, expected X, Y or Z",
+            )),
         }
     }
 }
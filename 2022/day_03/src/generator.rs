@@ -0,0 +1,95 @@
+//
+
+use advent_of_code::generate::{Generator, Rng};
+
+//
+
+const ITEM_TYPES: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// `size` groups of three rucksacks.
+///
+/// Both compartments of a rucksack share exactly one item type, and the three
+/// rucksacks of a group share exactly one badge.
+pub struct InputGenerator;
+
+impl Generator for InputGenerator {
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        (0..size.max(1)).map(|_| group(rng)).collect()
+    }
+}
+
+fn group(rng: &mut Rng) -> String {
+    let mut item_types = ITEM_TYPES.to_vec();
+    rng.shuffle(&mut item_types);
+    let (badge, others) = item_types.split_first().unwrap();
+
+    // Rucksacks pick from disjoint item types, so only the badge is common to all three.
+    others
+        .chunks(others.len() / 3)
+        .take(3)
+        .map(|item_types| rucksack(rng, *badge, item_types) + "\n")
+        .collect()
+}
+
+fn rucksack(rng: &mut Rng, badge: u8, item_types: &[u8]) -> String {
+    // One shared item type, the others only ever in one of the compartments.
+    let (shared, others) = item_types.split_first().unwrap();
+    let (left_only, right_only) = others.split_at(others.len() / 2);
+
+    let len = rng.range(2..=16);
+    let mut left = vec![*shared];
+    let mut right = vec![*shared];
+    if rng.chance(0.5) {
+        left.push(badge);
+    } else {
+        right.push(badge);
+    }
+    while left.len() < len {
+        left.push(*rng.choose(left_only));
+    }
+    while right.len() < len {
+        right.push(*rng.choose(right_only));
+    }
+    rng.shuffle(&mut left);
+    rng.shuffle(&mut right);
+
+    String::from_utf8([left, right].concat()).unwrap()
+}
+
+#[cfg(test)]
+mod test_generator {
+    use std::collections::HashSet;
+
+    use advent_of_code::generate::generate;
+
+    use super::*;
+    use crate::{part_one, part_two};
+
+    fn item_types(items: &str) -> HashSet<char> {
+        items.chars().collect()
+    }
+
+    #[test]
+    fn test_generated_inputs_are_solvable() {
+        for seed in 0..20 {
+            let input = generate(&InputGenerator, 20, seed);
+            let rucksacks: Vec<&str> = input.lines().collect();
+            assert_eq!(rucksacks.len(), 60);
+
+            for rucksack in &rucksacks {
+                let (left, right) = rucksack.split_at(rucksack.len() / 2);
+                assert_eq!(item_types(left).intersection(&item_types(right)).count(), 1);
+            }
+            for group in rucksacks.chunks(3) {
+                let badges: Vec<char> = item_types(group[0])
+                    .into_iter()
+                    .filter(|c| group[1].contains(*c) && group[2].contains(*c))
+                    .collect();
+                assert_eq!(badges.len(), 1, "seed {seed}");
+            }
+
+            assert!(part_one(&input).is_some());
+            assert!(part_two(&input).is_some());
+        }
+    }
+}
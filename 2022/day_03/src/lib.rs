@@ -1,5 +1,15 @@
 //
 
+mod generator;
+
+//
+
+use advent_of_code::compare::Registry;
+
+pub use generator::InputGenerator;
+
+//
+
 pub fn part_one(input: &str) -> Option<u32> {
     use std::collections::HashSet;
 
@@ -33,8 +43,21 @@ pub fn part_two(input: &str) -> Option<u32> {
         .sum()
 }
 
+/// Both parts, for `cargo compare` and `cargo minimize`.
+pub fn implementations() -> Registry {
+    Registry::new()
+        .parser(|input| input.lines().all(is_rucksack))
+        .register(1, "solution", part_one)
+        .register(2, "solution", part_two)
+}
+
 //
 
+/// Two compartments of the same size, holding letters only.
+fn is_rucksack(rucksack: &str) -> bool {
+    rucksack.len().is_multiple_of(2) && rucksack.chars().all(|item| item.is_ascii_alphabetic())
+}
+
 fn char_to_priority(char: char) -> u32 {
     const TO_DIGIT_9: u32 = 9; // '9'.to_digit(36).unwrap()
     const TO_DIGIT_Z: u32 = 35; // 'z'.to_digit(36).unwrap()
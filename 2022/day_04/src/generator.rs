@@ -0,0 +1,39 @@
+//
+
+use advent_of_code::generate::{Generator, Rng};
+
+//
+
+/// `size` pairs of section assignments between 1 and 99.
+pub struct InputGenerator;
+
+impl Generator for InputGenerator {
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        (0..size.max(1))
+            .map(|_| format!("{},{}\n", section(rng), section(rng)))
+            .collect()
+    }
+}
+
+fn section(rng: &mut Rng) -> String {
+    let start = rng.range(1..=99);
+    let end = rng.range(start..=99);
+    format!("{start}-{end}")
+}
+
+#[cfg(test)]
+mod test_generator {
+    use advent_of_code::generate::generate;
+
+    use super::*;
+    use crate::{part_one, part_two};
+
+    #[test]
+    fn test_generated_inputs_are_solvable() {
+        for seed in 0..20 {
+            let input = generate(&InputGenerator, 50, seed);
+            let (contained, overlapping) = (part_one(&input).unwrap(), part_two(&input).unwrap());
+            assert!(contained <= overlapping && overlapping <= 50, "seed {seed}");
+        }
+    }
+}
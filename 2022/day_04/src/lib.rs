@@ -1,73 +1,169 @@
 //
 
+mod generator;
+
+//
+
+use advent_of_code::compare::Registry;
+
+pub use generator::InputGenerator;
+
+//
+
 pub fn part_one(input: &str) -> Option<u32> {
-    Some(
-        input
-            .lines()
-            .flat_map(|pairs| pairs.parse())
-            .filter(|pairs: &ElfPair| pairs.is_fully_overlapping())
-            .count() as u32,
-    )
+    Some(fully_overlapping(&elf_pairs(input)?))
 }
 
 pub fn part_two(input: &str) -> Option<u32> {
-    Some(
-        input
-            .lines()
-            .flat_map(|pairs| pairs.parse())
-            .filter(|pairs: &ElfPair| pairs.is_overlapping())
-            .count() as u32,
-    )
+    Some(overlapping(&elf_pairs(input)?))
+}
+
+/// Both parts and their parsers, for `cargo compare` and `cargo minimize`, parsing with
+/// `FromStr` or with `scan!`.
+pub fn implementations() -> Registry {
+    Registry::new()
+        .parser(|input| parse_lines::<ElfPair>(input).is_ok())
+        .register(1, "solution", part_one)
+        .register(1, "scan", |input| {
+            Some(fully_overlapping(&scan_elf_pairs(input)?))
+        })
+        .register(2, "solution", part_two)
+        .register(2, "scan", |input| {
+            Some(overlapping(&scan_elf_pairs(input)?))
+        })
+        .register_parser("solution", elf_pairs)
+        .register_parser("scan", scan_elf_pairs)
 }
 
 //
 
-use std::{convert::Infallible, ops::RangeInclusive, str::FromStr};
+use std::{ops::RangeInclusive, str::FromStr};
+
+use advent_of_code::{
+    helpers::{
+        parse::{parse_lines, report, ParseError},
+        range::RangeSet,
+    },
+    scan,
+};
+
+fn elf_pairs(input: &str) -> Option<Vec<ElfPair>> {
+    report("invalid section assignments", parse_lines(input))
+}
+
+fn scan_elf_pairs(input: &str) -> Option<Vec<ElfPair>> {
+    let pairs = scan!(lines(input), "{usize}-{usize},{usize}-{usize}")
+        .map(|pair| pair.map(|(a, b, c, d)| ElfPair(Elf(a..=b), Elf(c..=d))))
+        .collect();
+    report("invalid section assignments", pairs)
+}
+
+fn fully_overlapping(pairs: &[ElfPair]) -> u32 {
+    pairs
+        .iter()
+        .filter(|pairs| pairs.is_fully_overlapping())
+        .count() as u32
+}
+
+fn overlapping(pairs: &[ElfPair]) -> u32 {
+    pairs.iter().filter(|pairs| pairs.is_overlapping()).count() as u32
+}
 
 struct Elf(RangeInclusive<usize>);
 
 impl FromStr for Elf {
-    type Err = Infallible;
+    type Err = ParseError;
 
     fn from_str(section: &str) -> Result<Self, Self::Err> {
         let (section_start, section_end) = section
             .split_once('-')
-            .expect("invalid section assignments : {section_1}");
-        let start = section_start.parse().expect("invalid section id");
-        let end = section_end.parse().expect("invalid section id");
+            .ok_or_else(|| ParseError::new(section, section, "expected a `start-end` section"))?;
+        let section_id = |id: &str| {
+            id.parse()
+                .map_err(|_| ParseError::new(section, id, "invalid section id"))
+        };
+        let start = section_id(section_start)?;
+        let end = section_id(section_end)?;
         Ok(Elf(std::ops::RangeInclusive::new(start, end)))
     }
 }
 struct ElfPair(Elf, Elf);
 
 impl ElfPair {
-    fn is_overlapping(&self) -> bool {
-        let ElfPair(first, second) = self;
-        let Elf(first) = first;
-        let Elf(second) = second;
+    fn sections(&self) -> (RangeSet<usize>, RangeSet<usize>) {
+        let ElfPair(Elf(first), Elf(second)) = self;
+        (first.clone().into(), second.clone().into())
+    }
 
-        first.start() <= second.end() && first.end() >= second.start()
+    fn is_overlapping(&self) -> bool {
+        let (first, second) = self.sections();
+        first.intersects(&second)
     }
 
     fn is_fully_overlapping(&self) -> bool {
-        let ElfPair(first, second) = self;
-        let Elf(first) = first;
-        let Elf(second) = second;
-        let first_contains_second = first.contains(second.start()) && first.contains(second.end());
-        let second_contains_first = second.contains(first.start()) && second.contains(first.end());
-        first_contains_second || second_contains_first
+        let (first, second) = self.sections();
+        first.is_superset(&second) || second.is_superset(&first)
     }
 }
 
 impl FromStr for ElfPair {
-    type Err = Infallible;
+    type Err = ParseError;
 
     fn from_str(section_pair: &str) -> Result<Self, Self::Err> {
-        let (section_1, section_2) = section_pair
-            .split_once(',')
-            .expect("invalid section assignments pair : {section_pair}");
-        let first = section_1.parse().expect("invalid elf");
-        let second = section_2.parse().expect("invalid elf");
-        Ok(ElfPair(first, second))
+        let (section_1, section_2) = section_pair.split_once(',').ok_or_else(|| {
+            ParseError::new(
+                section_pair,
+                section_pair,
+                "expected two sections separated by `,`",
+            )
+        })?;
+        let elf = |section: &str| {
+            section
+                .parse()
+                .map_err(|e: ParseError| e.within(section_pair, section))
+        };
+        Ok(ElfPair(elf(section_1)?, elf(section_2)?))
+    }
+}
+
+#[cfg(test)]
+mod test_elf_pair_overlap {
+    use super::*;
+
+    #[test]
+    fn test_overlapping() {
+        let pair = |s: &str| s.parse::<ElfPair>().unwrap();
+        assert!(!pair("2-4,6-8").is_overlapping());
+        assert!(pair("5-7,7-9").is_overlapping());
+        assert!(!pair("5-7,7-9").is_fully_overlapping());
+        assert!(pair("6-6,4-6").is_fully_overlapping());
+        assert!(pair("2-8,3-7").is_fully_overlapping());
+    }
+}
+
+#[cfg(test)]
+mod test_elf_pair_from_str {
+    use super::*;
+
+    #[test]
+    fn test_valid() {
+        let ElfPair(Elf(first), Elf(second)) = "2-4,6-8".parse().unwrap();
+        assert_eq!((first, second), (2..=4, 6..=8));
+    }
+
+    #[test]
+    fn test_invalid_section_id() {
+        let error = "2-4,6-x".parse::<ElfPair>().err().unwrap();
+        assert_eq!(
+            (error.line, error.column, error.token.as_str()),
+            (1, 7, "x")
+        );
+    }
+
+    #[test]
+    fn test_invalid_line_is_not_skipped() {
+        assert_eq!(part_one("2-4,6-8\n2-3;4-5"), None);
+        let error = advent_of_code::helpers::parse::take_reported().unwrap();
+        assert!(error.contains("line 2, column 1: expected two sections separated by `,`"));
     }
 }
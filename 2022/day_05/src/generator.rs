@@ -0,0 +1,87 @@
+//
+
+use advent_of_code::generate::{Generator, Rng};
+
+//
+
+/// Up to nine stacks of crates and `size` rearrangement steps.
+///
+/// No step ever moves the last crate of a stack, so every stack has a top crate
+/// at the end.
+pub struct InputGenerator;
+
+impl Generator for InputGenerator {
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        let stack_count = rng.range(2..=9);
+        // Top crate first, the first stack can always give one away.
+        let mut stacks: Vec<Vec<u8>> = (0..stack_count)
+            .map(|i| {
+                let height = rng.range(if i == 0 { 2 } else { 1 }..=8);
+                rng.string(b"ABCDEFGHIJKLMNOPQRSTUVWXYZ", height)
+                    .into_bytes()
+            })
+            .collect();
+
+        let mut input = drawing(&stacks);
+        for _ in 0..size.max(1) {
+            let from = loop {
+                let from = rng.below(stack_count);
+                if stacks[from].len() > 1 {
+                    break from;
+                }
+            };
+            let to = (from + rng.range(1..=stack_count - 1)) % stack_count;
+            let moves = rng.range(1..=stacks[from].len() - 1);
+
+            let moved: Vec<u8> = stacks[from].drain(..moves).collect();
+            stacks[to].splice(0..0, moved);
+            input += &format!("move {} from {} to {}\n", moves, from + 1, to + 1);
+        }
+        input
+    }
+}
+
+fn drawing(stacks: &[Vec<u8>]) -> String {
+    let height = stacks.iter().map(Vec::len).max().unwrap_or(0);
+    let mut drawing = String::new();
+    for level in (0..height).rev() {
+        let row: Vec<String> = stacks
+            .iter()
+            .map(|stack| match stack.len().checked_sub(level + 1) {
+                Some(index) => format!("[{}]", stack[index] as char),
+                None => "   ".to_string(),
+            })
+            .collect();
+        drawing += &(row.join(" ") + "\n");
+    }
+    let numbers: Vec<String> = (1..=stacks.len()).map(|i| format!(" {i} ")).collect();
+    drawing + &numbers.join(" ") + "\n\n"
+}
+
+#[cfg(test)]
+mod test_generator {
+    use advent_of_code::generate::generate;
+
+    use super::*;
+    use crate::{part_one, part_two};
+
+    #[test]
+    fn test_drawing() {
+        let stacks = vec![b"NZ".to_vec(), b"DCM".to_vec(), b"P".to_vec()];
+        assert_eq!(
+            drawing(&stacks),
+            "    [D]    \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 \n\n"
+        );
+    }
+
+    #[test]
+    fn test_generated_inputs_are_solvable() {
+        for seed in 0..20 {
+            let input = generate(&InputGenerator, 50, seed);
+            let stacks = input.lines().find(|line| line.starts_with(" 1")).unwrap();
+            let stack_count = stacks.split_whitespace().count();
+            assert_eq!(part_one(&input).map(|tops| tops.len()), Some(stack_count));
+            assert_eq!(part_two(&input).map(|tops| tops.len()), Some(stack_count));
+        }
+    }
+}
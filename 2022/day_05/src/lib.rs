@@ -1,65 +1,104 @@
 //
 
+mod generator;
 mod procedure;
 mod stacks;
 
 //
 
+use advent_of_code::helpers::parse::{parse_all, report, unsigned, IResult, ParseError};
+use advent_of_code::{compare::Registry, scan, trace};
 use nom::{
-    character::{
-        self,
-        complete::{newline, space0},
-    },
+    character::complete::{newline, space0},
     multi::many1,
-    sequence::{delimited, terminated},
-    IResult,
+    sequence::{delimited, terminated, tuple},
 };
 use procedure::Procedure;
 use std::collections::VecDeque;
 
+pub use generator::InputGenerator;
+
 //
 
 pub fn part_one(input: &str) -> Option<String> {
-    let (crate_stacks, rearrangement_procedure) = input_parser(input);
+    crate_mover_9000(input_parser(input)?)
+}
+
+pub fn part_two(input: &str) -> Option<String> {
+    crate_mover_9001(input_parser(input)?)
+}
+
+/// Both parts and their parsers, for `cargo compare` and `cargo minimize`, parsing the
+/// procedure with nom or with `scan!`.
+pub fn implementations() -> Registry {
+    Registry::new()
+        .parser(|input| parse_input(input).is_ok())
+        .register(1, "solution", part_one)
+        .register(1, "scan", |input| {
+            crate_mover_9000(scan_input_parser(input)?)
+        })
+        .register(2, "solution", part_two)
+        .register(2, "scan", |input| {
+            crate_mover_9001(scan_input_parser(input)?)
+        })
+        .register_parser("solution", input_parser)
+        .register_parser("scan", scan_input_parser)
+}
 
+//
+
+/// The stacks of crates and the procedure to rearrange them.
+type Rearrangement = (Vec<VecDeque<char>>, Vec<Procedure>);
+
+/// The crate on top of each stack, a space for empty stacks.
+fn tops(crates: &[VecDeque<char>]) -> String {
+    crates
+        .iter()
+        .map(|stack| stack.front().unwrap_or(&' '))
+        .collect()
+}
+
+/// Moves crates one at a time.
+fn crate_mover_9000((crate_stacks, rearrangement_procedure): Rearrangement) -> Option<String> {
     let mut crates = crate_stacks;
     for Procedure { moves, from, to } in rearrangement_procedure.into_iter() {
         let moved_crates: Vec<char> = crates[from].drain(..moves).collect();
         for crate_ in moved_crates.iter() {
             crates[to].push_front(*crate_);
         }
+        trace!(
+            "move {} from {} to {}, tops {}",
+            moves,
+            from + 1,
+            to + 1,
+            tops(&crates)
+        );
     }
-    Some(
-        crates
-            .into_iter()
-            .fold(String::new(), |tops, stack| tops + &stack[0].to_string()),
-    )
+    Some(tops(&crates))
 }
 
-pub fn part_two(input: &str) -> Option<String> {
-    let (crate_stacks, rearrangement_procedure) = input_parser(input);
-
+/// Moves crates all at once.
+fn crate_mover_9001((crate_stacks, rearrangement_procedure): Rearrangement) -> Option<String> {
     let mut crates = crate_stacks;
     for Procedure { moves, from, to } in rearrangement_procedure.into_iter() {
         let moved_crates: Vec<char> = crates[from].drain(..moves).collect();
         for crate_ in moved_crates.iter().rev() {
             crates[to].push_front(*crate_);
         }
+        trace!(
+            "move {} from {} to {}, tops {}",
+            moves,
+            from + 1,
+            to + 1,
+            tops(&crates)
+        );
     }
-    Some(
-        crates
-            .into_iter()
-            .fold(String::new(), |tops, stack| tops + &stack[0].to_string()),
-    )
+    Some(tops(&crates))
 }
 
-//
-
-fn input_parser(input: &str) -> (Vec<VecDeque<char>>, Vec<Procedure>) {
-    let (input, crate_stacks) = stacks::parse(input).expect("invalid crate stack");
-    let (input, _) = crate_number_parsing(input).expect("invalide number lines");
-    let (_, procedure) = procedure::parse(input).expect("invalide procedure");
-    (
+fn input_parser(input: &str) -> Option<Rearrangement> {
+    let (crate_stacks, _, procedure) = report("invalid drawing or procedure", parse_input(input))?;
+    Some((
         crate_stacks,
         procedure
             .iter()
@@ -69,16 +108,58 @@ fn input_parser(input: &str) -> (Vec<VecDeque<char>>, Vec<Procedure>) {
                 to: to as usize - 1,
             })
             .collect(),
+    ))
+}
+
+fn scan_input_parser(input: &str) -> Option<Rearrangement> {
+    let drawing_end = input.find("\n\n").map_or(input.len(), |i| i + 2);
+    let (drawing, procedure) = input.split_at(drawing_end);
+    let (crate_stacks, _) = report(
+        "invalid drawing",
+        parse_all(tuple((stacks::parse, crate_number_parsing)), drawing),
+    )?;
+    let procedure = scan!(lines(procedure), "move {usize} from {usize} to {usize}")
+        .map(|step| {
+            step.map(|(moves, from, to)| Procedure {
+                moves,
+                from: from - 1,
+                to: to - 1,
+            })
+        })
+        .collect::<Result<_, ParseError>>()
+        .map_err(|err| err.at_line(drawing.lines().count() + 1));
+    Some((crate_stacks, report("invalid procedure", procedure)?))
+}
+
+/// The drawing of the stacks, their numbers and the moves of the procedure.
+type ParsedInput = (Vec<VecDeque<char>>, Vec<u8>, Vec<(u8, u8, u8)>);
+
+fn parse_input(input: &str) -> Result<ParsedInput, ParseError> {
+    parse_all(
+        tuple((stacks::parse, crate_number_parsing, procedure::parse)),
+        input,
     )
 }
 
 fn crate_number_parsing(input: &str) -> IResult<&str, Vec<u8>> {
     let (input, result) = terminated(
-        terminated(
-            many1(delimited(space0, character::complete::u8, space0)),
-            newline,
-        ),
+        terminated(many1(delimited(space0, unsigned, space0)), newline),
         newline,
     )(input)?;
     Ok((input, result))
 }
+
+#[cfg(test)]
+mod test_tops {
+    use super::*;
+
+    #[test]
+    fn test_tops() {
+        let crates = [
+            VecDeque::from(['N', 'Z']),
+            VecDeque::new(),
+            VecDeque::from(['P']),
+        ];
+        assert_eq!(tops(&crates), "N P");
+    }
+}
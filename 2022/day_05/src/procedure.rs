@@ -1,11 +1,10 @@
 //
 
+use advent_of_code::helpers::parse::{lines, unsigned, IResult};
 use nom::{
     bytes::complete::tag,
-    character::complete::{newline, space1, u8},
-    multi::separated_list1,
+    character::complete::space1,
     sequence::{delimited, preceded, tuple},
-    IResult,
 };
 
 //
@@ -19,12 +18,9 @@ pub struct Procedure {
 //
 
 pub fn parse(input: &str) -> IResult<&str, Vec<(u8, u8, u8)>> {
-    separated_list1(
-        newline,
-        tuple((
-            preceded(tag("move"), delimited(space1, u8, space1)),
-            preceded(tag("from"), delimited(space1, u8, space1)),
-            preceded(tag("to"), preceded(space1, u8)),
-        )),
-    )(input)
+    lines(tuple((
+        preceded(tag("move"), delimited(space1, unsigned, space1)),
+        preceded(tag("from"), delimited(space1, unsigned, space1)),
+        preceded(tag("to"), preceded(space1, unsigned)),
+    )))(input)
 }
@@ -9,9 +9,10 @@ use nom::{
     combinator::value,
     multi::{fold_many1, separated_list1},
     sequence::{delimited, terminated},
-    IResult,
 };
 
+use advent_of_code::helpers::parse::IResult;
+
 //
 
 pub fn parse(input: &str) -> IResult<&str, Vec<VecDeque<char>>> {
@@ -20,9 +21,8 @@ pub fn parse(input: &str) -> IResult<&str, Vec<VecDeque<char>>> {
         Vec::new,
         |mut col: Vec<VecDeque<char>>, row| {
             row.into_iter().enumerate().for_each(|(i, name)| {
-                match col.get(i) {
-                    None => col.push(VecDeque::new()),
-                    Some(_) => (),
+                if col.get(i).is_none() {
+                    col.push(VecDeque::new())
                 }
                 if name.is_alphanumeric() {
                     col[i].push_back(name);
@@ -34,6 +34,7 @@ pub fn parse(input: &str) -> IResult<&str, Vec<VecDeque<char>>> {
 }
 
 #[cfg(test)]
+#[allow(clippy::useless_vec)]
 mod test_crate_stacks {
     use super::*;
 
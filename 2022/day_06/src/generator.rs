@@ -0,0 +1,44 @@
+//
+
+use advent_of_code::generate::{Generator, Rng};
+
+//
+
+const MARKER_LEN: usize = 14;
+
+/// A datastream of `size` letters with a start-of-message marker somewhere in it.
+pub struct InputGenerator;
+
+impl Generator for InputGenerator {
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        let len = size.max(MARKER_LEN);
+        // Few distinct letters make markers rare before the planted one.
+        let alphabet = &b"abcdefghijklmnopqrstuvwxyz"[..rng.range(3..=8)];
+        let mut datastream = rng.string(alphabet, len).into_bytes();
+
+        let mut marker = b"abcdefghijklmnopqrstuvwxyz".to_vec();
+        rng.shuffle(&mut marker);
+        let start = rng.range((len - MARKER_LEN) / 2..=len - MARKER_LEN);
+        datastream[start..start + MARKER_LEN].copy_from_slice(&marker[..MARKER_LEN]);
+
+        String::from_utf8(datastream).unwrap() + "\n"
+    }
+}
+
+#[cfg(test)]
+mod test_generator {
+    use advent_of_code::generate::generate;
+
+    use super::*;
+    use crate::{part_one, part_two};
+
+    #[test]
+    fn test_generated_inputs_are_solvable() {
+        for seed in 0..20 {
+            let input = generate(&InputGenerator, 200, seed);
+            assert_eq!(input.trim_end().len(), 200);
+            let (packet, message) = (part_one(&input).unwrap(), part_two(&input).unwrap());
+            assert!(packet <= message && message <= 200, "seed {seed}");
+        }
+    }
+}
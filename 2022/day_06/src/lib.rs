@@ -1,29 +1,96 @@
 //
 
+mod generator;
+
+//
+
 use std::collections::HashSet;
 
+use advent_of_code::compare::Registry;
+
+pub use generator::InputGenerator;
+
 //
 
-pub fn part_one(input: &str) -> Option<usize> {
-    const SEQUENCE_LEN: usize = 4;
+const PACKET_MARKER_LEN: usize = 4;
+const MESSAGE_MARKER_LEN: usize = 14;
 
-    input
-        .chars()
-        .collect::<Vec<_>>()
-        .as_slice()
-        .windows(SEQUENCE_LEN)
-        .position(|datastream| HashSet::<_>::from_iter(datastream).len() == datastream.len())
-        .map(|start| start + SEQUENCE_LEN)
+pub fn part_one(input: &str) -> Option<usize> {
+    end_of_marker(input, PACKET_MARKER_LEN)
 }
 
 pub fn part_two(input: &str) -> Option<usize> {
-    const SEQUENCE_LEN: usize = 14;
+    end_of_marker(input, MESSAGE_MARKER_LEN)
+}
 
+/// Every implementation of both parts, the naive one first.
+pub fn implementations() -> Registry {
+    Registry::new()
+        .register(1, "hashset", |input| {
+            end_of_marker_hashset(input, PACKET_MARKER_LEN)
+        })
+        .register(1, "last_seen", part_one)
+        .register(2, "hashset", |input| {
+            end_of_marker_hashset(input, MESSAGE_MARKER_LEN)
+        })
+        .register(2, "last_seen", part_two)
+}
+
+//
+
+/// Walks the datastream once, moving the window start past the previous
+/// occurrence of every byte seen again.
+fn end_of_marker(input: &str, marker_len: usize) -> Option<usize> {
+    let mut last_seen = [None; 256];
+    let mut start = 0;
+    for (index, &byte) in input.as_bytes().iter().enumerate() {
+        if let Some(previous) = last_seen[byte as usize] {
+            start = start.max(previous + 1);
+        }
+        last_seen[byte as usize] = Some(index);
+        if index + 1 - start == marker_len {
+            return Some(index + 1);
+        }
+    }
+    None
+}
+
+/// Checks every window of bytes with a fresh `HashSet`.
+fn end_of_marker_hashset(input: &str, marker_len: usize) -> Option<usize> {
     input
-        .chars()
-        .collect::<Vec<_>>()
-        .as_slice()
-        .windows(SEQUENCE_LEN)
+        .as_bytes()
+        .windows(marker_len)
         .position(|datastream| HashSet::<_>::from_iter(datastream).len() == datastream.len())
-        .map(|start| start + SEQUENCE_LEN)
+        .map(|start| start + marker_len)
+}
+
+#[cfg(test)]
+mod test_implementations {
+    use advent_of_code::compare::{generated, Input};
+
+    use super::*;
+
+    #[test]
+    fn test_implementations_agree() {
+        let example = advent_of_code::read_example();
+        let examples: Vec<Input> = example
+            .lines()
+            // Positions count bytes, even past a multi-byte character
+            .chain(["aaaa", "aébcd"])
+            .map(|datastream| Input::new(datastream, datastream))
+            .collect();
+        let inputs = examples
+            .into_iter()
+            .chain(generated(&InputGenerator, 200, 50));
+        assert_eq!(
+            implementations().check(inputs).map_err(|d| d.to_string()),
+            Ok(57)
+        );
+    }
+
+    #[test]
+    fn test_counts_bytes() {
+        assert_eq!(end_of_marker("aébcd", PACKET_MARKER_LEN), Some(4));
+        assert_eq!(end_of_marker_hashset("aébcd", PACKET_MARKER_LEN), Some(4));
+    }
 }
@@ -1,11 +1,15 @@
 fn main() {
     use puzzles::{part_one, part_two};
+    if advent_of_code::solve::all_inputs_requested() {
+        return advent_of_code::solve::all_inputs(part_one, part_two);
+    }
     let input = &advent_of_code::read_input();
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
 
 #[cfg(test)]
+#[allow(clippy::needless_borrow, clippy::iter_nth_zero)]
 mod tests_part_one {
     use puzzles::part_one;
 
@@ -41,6 +45,7 @@ mod tests_part_one {
 }
 
 #[cfg(test)]
+#[allow(clippy::needless_borrow, clippy::iter_nth_zero)]
 mod tests_part_two {
     use puzzles::part_two;
 
@@ -0,0 +1,157 @@
+//
+
+use advent_of_code::generate::{Generator, Rng};
+
+//
+
+const TOTAL_SPACE: usize = 70_000_000;
+const UPDATE_SPACE: usize = 30_000_000;
+
+/// A terminal session browsing `size` directories, each listed once.
+///
+/// The disk holds between 40M and 70M, so there is not enough room for the
+/// update, but it fits once some directory is deleted.
+pub struct InputGenerator;
+
+struct Directory {
+    name: String,
+    children: Vec<usize>,
+    files: Vec<(String, usize)>,
+}
+
+impl Generator for InputGenerator {
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        let mut directories = vec![Directory {
+            name: "/".to_string(),
+            children: Vec::new(),
+            files: Vec::new(),
+        }];
+        for index in 1..size.max(1) {
+            let parent = rng.below(index);
+            let name = unique_name(rng, &directories, parent);
+            directories[parent].children.push(index);
+            directories.push(Directory {
+                name,
+                children: Vec::new(),
+                files: Vec::new(),
+            });
+        }
+
+        // Every directory holds a file, so that no `ls` is empty.
+        let weights: Vec<Vec<usize>> = directories
+            .iter()
+            .map(|_| {
+                (0..rng.range(1..=4))
+                    .map(|_| rng.range(1..=1_000).pow(2))
+                    .collect()
+            })
+            .collect();
+        let file_count: usize = weights.iter().map(Vec::len).sum();
+        let total_weight: usize = weights.iter().flatten().sum();
+        let used_space = rng.range(TOTAL_SPACE - UPDATE_SPACE + 1 + file_count..=TOTAL_SPACE);
+        for (index, weights) in weights.into_iter().enumerate() {
+            // Names are unique among the files and child directories listed together.
+            let mut taken: Vec<String> = directories[index]
+                .children
+                .iter()
+                .map(|&child| directories[child].name.clone())
+                .collect();
+            let mut files = Vec::with_capacity(weights.len());
+            for weight in weights {
+                let name = loop {
+                    let name = name(rng) + *rng.choose(&["", ".txt", ".dat", ".log"]);
+                    if !taken.contains(&name) {
+                        break name;
+                    }
+                };
+                taken.push(name.clone());
+                // Rounding down loses less than one byte per file
+                files.push((name, 1 + (used_space - file_count) * weight / total_weight));
+            }
+            directories[index].files = files;
+        }
+
+        let mut session = String::new();
+        browse(rng, &directories, 0, &mut session);
+        session
+    }
+}
+
+fn name(rng: &mut Rng) -> String {
+    let len = rng.range(1..=8);
+    rng.string(b"abcdefghijklmnopqrstuvwxyz", len)
+}
+
+fn unique_name(rng: &mut Rng, directories: &[Directory], parent: usize) -> String {
+    loop {
+        let name = name(rng);
+        let taken = directories[parent]
+            .children
+            .iter()
+            .any(|&child| directories[child].name == name);
+        if !taken {
+            return name;
+        }
+    }
+}
+
+fn browse(rng: &mut Rng, directories: &[Directory], index: usize, session: &mut String) {
+    let directory = &directories[index];
+    *session += &format!("$ cd {}\n$ ls\n", directory.name);
+
+    let mut listing: Vec<String> = directory
+        .children
+        .iter()
+        .map(|&child| format!("dir {}", directories[child].name))
+        .chain(
+            directory
+                .files
+                .iter()
+                .map(|(name, size)| format!("{size} {name}")),
+        )
+        .collect();
+    rng.shuffle(&mut listing);
+    for line in listing {
+        *session += &(line + "\n");
+    }
+
+    for &child in &directory.children {
+        browse(rng, directories, child, session);
+        *session += "$ cd ..\n";
+    }
+}
+
+#[cfg(test)]
+mod test_generator {
+    use advent_of_code::generate::generate;
+
+    use super::*;
+    use crate::{part_one, part_two};
+
+    #[test]
+    fn test_generated_inputs_are_solvable() {
+        for seed in 0..20 {
+            let input = generate(&InputGenerator, 30, seed);
+            assert_eq!(input.matches("$ ls").count(), 30);
+            let used_space: usize = input
+                .lines()
+                .filter_map(|line| line.split_once(' ')?.0.parse::<usize>().ok())
+                .sum();
+            assert!(used_space > TOTAL_SPACE - UPDATE_SPACE && used_space <= TOTAL_SPACE);
+
+            let mut listing: Vec<&str> = Vec::new();
+            for line in input.lines() {
+                if line.starts_with("$ ls") {
+                    listing.clear();
+                } else if !line.starts_with('$') {
+                    let (_, name) = line.split_once(' ').unwrap();
+                    assert!(!listing.contains(&name), "seed {seed}: {name} listed twice");
+                    listing.push(name);
+                }
+            }
+
+            assert!(part_one(&input).is_some(), "seed {seed}");
+            assert!(part_two(&input).is_some(), "seed {seed}");
+        }
+    }
+}
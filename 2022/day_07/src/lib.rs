@@ -1,22 +1,24 @@
 //
 
+mod generator;
 mod terminal_output;
 
 //
 
-use std::{collections::BTreeMap, path::PathBuf, process};
+use std::{collections::BTreeMap, path::PathBuf};
 
 use terminal_output::{Cd, Command, TerminalOutput};
 
 use crate::terminal_output::Content;
 
+use advent_of_code::{compare::Registry, helpers::parse::report, trace};
+
+pub use generator::InputGenerator;
+
 //
 
 pub fn part_one(input: &str) -> Option<u32> {
-    let terminal_output = input.parse().unwrap_or_else(|err| {
-        eprintln!("Problem parsing arguments: {err}");
-        process::exit(1);
-    });
+    let terminal_output = report("invalid terminal output", input.parse())?;
 
     directories_sizes(terminal_output)
         .iter()
@@ -30,10 +32,7 @@ pub fn part_two(input: &str) -> Option<u32> {
     const TOTAL_SPACE: usize = 70_000_000;
     const UPDATE_SPACE: usize = 30_000_000;
 
-    let terminal_output = input.parse().unwrap_or_else(|err| {
-        eprintln!("Problem parsing arguments: {err}");
-        process::exit(1);
-    });
+    let terminal_output = report("invalid terminal output", input.parse())?;
 
     let sizes = directories_sizes(terminal_output);
     let used_space = sizes.get("/").unwrap();
@@ -47,6 +46,14 @@ pub fn part_two(input: &str) -> Option<u32> {
         .min()
 }
 
+/// Both parts, for `cargo compare` and `cargo minimize`.
+pub fn implementations() -> Registry {
+    Registry::new()
+        .parser(|input| input.parse::<TerminalOutput>().is_ok())
+        .register(1, "solution", part_one)
+        .register(2, "solution", part_two)
+}
+
 //
 
 fn directories_sizes(TerminalOutput(output): TerminalOutput) -> BTreeMap<String, usize> {
@@ -54,12 +61,17 @@ fn directories_sizes(TerminalOutput(output): TerminalOutput) -> BTreeMap<String,
         (PathBuf::from("/"), BTreeMap::<String, usize>::new()),
         |(mut path, mut sizes), command| {
             match command {
-                Command::Cd(Cd::RootDir) => path.push("/"),
+                Command::Cd(Cd::RootDir) => {
+                    path.push("/");
+                    trace!("cd / -> {}", path.display());
+                }
                 Command::Cd(Cd::Out) => {
                     path.pop();
+                    trace!("cd .. -> {}", path.display());
                 }
                 Command::Cd(Cd::In(directory)) => {
                     path.push(directory);
+                    trace!("cd {} -> {}", directory, path.display());
                 }
                 Command::Ls(contents) => {
                     let files_size = contents
@@ -1,15 +1,14 @@
 //
 
+use advent_of_code::helpers::parse::{lines, parse_all, unsigned, IResult, ParseError};
 use nom::{
     branch::alt,
     bytes::complete::tag,
     character::complete::{alphanumeric1, newline, one_of, space1},
     combinator::{map, recognize},
-    error::{context, convert_error, VerboseError},
-    multi::{many1, separated_list0, separated_list1},
-    number::complete::float,
+    error::context,
+    multi::{many1, separated_list0},
     sequence::{preceded, separated_pair},
-    Finish,
 };
 use std::str::FromStr;
 
@@ -20,27 +19,22 @@ pub struct TerminalOutput(pub Vec<Command>);
 //
 
 impl FromStr for TerminalOutput {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match parse(s).finish() {
-            Ok((_, commands)) => Ok(Self(commands)),
-            Err(e) => Err(convert_error(s, e)),
-        }
+        parse_all(parse, s).map(Self)
     }
 }
 
 //
 
-type IResult<T, U> = nom::IResult<T, U, VerboseError<T>>;
-
 fn parse(input: &str) -> IResult<&str, Vec<Command>> {
     context(
         "commands",
-        separated_list1(
-            newline,
-            alt((map(command_cd, Command::Cd), map(command_ls, Command::Ls))),
-        ),
+        lines(alt((
+            map(command_cd, Command::Cd),
+            map(command_ls, Command::Ls),
+        ))),
     )(input)
 }
 
@@ -71,17 +65,13 @@ fn command_ls(input: &str) -> IResult<&str, Vec<Content>> {
                 newline,
                 alt((
                     // dir a
-                    map(
-                        separated_pair(tag("dir"), space1, is_filename),
-                        |(_, dir)| Content::Directory(String::from(dir)),
-                    ),
+                    map(separated_pair(tag("dir"), space1, is_filename), |_| {
+                        Content::Directory
+                    }),
                     // 14848514 b.txt
                     map(
-                        separated_pair(float, space1, is_filename),
-                        |(size, name)| Content::File {
-                            name: String::from(name),
-                            size: size as usize,
-                        },
+                        separated_pair(unsigned, space1, is_filename),
+                        |(size, _)| Content::File { size },
                     ),
                 )),
             ),
@@ -110,6 +100,6 @@ pub enum Cd {
 }
 
 pub enum Content {
-    Directory(String),
-    File { name: String, size: usize },
+    Directory,
+    File { size: usize },
 }
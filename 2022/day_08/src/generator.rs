@@ -0,0 +1,35 @@
+//
+
+use advent_of_code::generate::{Generator, Rng};
+
+//
+
+/// A `size` x `size` forest of tree heights.
+pub struct InputGenerator;
+
+impl Generator for InputGenerator {
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        let side = size.max(1);
+        (0..side)
+            .map(|_| rng.string(b"0123456789", side) + "\n")
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test_generator {
+    use advent_of_code::generate::generate;
+
+    use super::*;
+    use crate::{part_one, part_two};
+
+    #[test]
+    fn test_generated_inputs_are_solvable() {
+        for seed in 0..20 {
+            let input = generate(&InputGenerator, 20, seed);
+            // At least the edge is visible
+            assert!(part_one(&input).unwrap() >= 4 * 19, "seed {seed}");
+            assert!(part_two(&input).is_some());
+        }
+    }
+}
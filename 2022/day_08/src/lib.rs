@@ -1,36 +1,47 @@
 //
 
-mod direction;
+mod generator;
+mod picture;
 mod scenic_score;
 mod tree;
-mod ugrid;
 mod visibility_check;
 
 //
 
-use direction::Direction;
+use advent_of_code::helpers::geometry::Dir4;
+use advent_of_code::helpers::{
+    grid::Grid,
+    parse::{report, ParseError},
+};
 use tree::Tree;
-use ugrid::UGrid;
+
+use advent_of_code::compare::Registry;
+
+pub use generator::InputGenerator;
+pub use picture::forest_image;
+
+/// Tree heights, one digit per tree.
+type Forest = Grid<usize>;
 
 //
 
 pub fn part_one(input: &str) -> Option<u32> {
     use visibility_check::VisibilityCheck;
 
-    let forest: UGrid = input.parse().expect("invalid input");
+    let forest = report("invalid forest", parse_forest(input))?;
 
-    let visible_interior_trees = (0..forest.rows * forest.columns)
+    let visible_interior_trees = (0..forest.len())
         .map(tree_from_forest_id(&forest))
         .filter(|tree| {
             let Tree { position, .. } = tree;
             let &(x, y) = position;
 
-            if x == 0 || x == forest.rows - 1 || y == 0 || y == forest.columns - 1 {
+            if x == 0 || x == forest.width() - 1 || y == 0 || y == forest.height() - 1 {
                 // All of the trees around the edge of the grid are *visible*
                 return true;
             };
 
-            Direction::iter().any(|direction| {
+            Dir4::iter().any(|direction| {
                 VisibilityCheck::in_forest(&forest).is_visible_from_outside(tree, direction)
             })
         })
@@ -45,19 +56,31 @@ pub fn part_two(input: &str) -> Option<u32> {
 
     //
 
-    let forest: UGrid = input.parse().expect("invalid input");
+    let forest = report("invalid forest", parse_forest(input))?;
 
-    (0..forest.rows * forest.columns)
+    (0..forest.len())
         .map(tree_from_forest_id(&forest))
         .map(|tree| ScenicScore::calculate_from(&VisibilityCheck::in_forest(&forest), &tree))
         .max()
 }
 
+/// Both parts, for `cargo compare` and `cargo minimize`.
+pub fn implementations() -> Registry {
+    Registry::new()
+        .parser(|input| parse_forest(input).is_ok())
+        .register(1, "solution", part_one)
+        .register(2, "solution", part_two)
+}
+
 //
 
-fn tree_from_forest_id(forest: &UGrid) -> impl Fn(usize) -> Tree + '_ {
+fn parse_forest(input: &str) -> Result<Forest, ParseError> {
+    Grid::parse(input, |c| c.to_digit(10).map(|height| height as usize))
+}
+
+fn tree_from_forest_id(forest: &Forest) -> impl Fn(usize) -> Tree + '_ {
     move |index| {
-        let position = forest.index_to_coord(index);
+        let position = forest.index_to_position(index);
 
         Tree {
             position,
@@ -0,0 +1,36 @@
+//
+
+use advent_of_code::helpers::{
+    image::{gradient, Image},
+    parse::report,
+};
+
+use crate::{parse_forest, Forest};
+
+//
+
+/// The forest seen from above, taller trees in darker blue.
+pub fn forest_image(input: &str) -> Option<Image> {
+    let forest = report("invalid forest", parse_forest(input))?;
+    Some(forest_picture(&forest))
+}
+
+fn forest_picture(forest: &Forest) -> Image {
+    Image::from_grid(forest, |&height| gradient(height, 9))
+}
+
+#[cfg(test)]
+mod test_forest_picture {
+    use advent_of_code::helpers::grid::Grid;
+
+    use super::*;
+
+    #[test]
+    fn test_tallest_tree_is_darkest() {
+        let forest = Grid::new(2, 1, vec![0, 9]);
+        let image = forest_picture(&forest);
+        assert_eq!(image.pixel(0, 0), gradient(0, 9));
+        assert_eq!(image.pixel(1, 0), gradient(9, 9));
+        assert!(image.pixel(1, 0) < image.pixel(0, 0));
+    }
+}
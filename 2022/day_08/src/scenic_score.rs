@@ -1,12 +1,14 @@
 //
 
-use crate::{direction::Direction, tree::Tree, visibility_check::VisibilityCheck};
+use advent_of_code::helpers::geometry::Dir4;
+
+use crate::{tree::Tree, visibility_check::VisibilityCheck};
 
 pub struct ScenicScore {}
 
 impl ScenicScore {
     pub fn calculate_from(visibility_checker: &VisibilityCheck, tree: &Tree) -> u32 {
-        Direction::iter()
+        Dir4::iter()
             .map(|direction| visibility_checker.count_visible_trees(tree, direction))
             // NOTE(douglasduteil): One does not simply multiply by 0 but ...
             // """
@@ -23,17 +25,18 @@ impl ScenicScore {
 //
 
 #[cfg(test)]
+#[allow(clippy::useless_vec)]
 mod test_scenic_score_count_visible_trees {
-    use crate::ugrid::UGrid;
+    use crate::parse_forest;
 
     use super::*;
 
     #[test]
     fn test_best_1x1() {
-        let forest: UGrid = "5".parse().unwrap();
+        let forest = parse_forest("5").unwrap();
 
         for (index, &score) in vec![0u32].iter().enumerate() {
-            let position = forest.index_to_coord(index);
+            let position = forest.index_to_position(index);
             assert_eq!(
                 (
                     index,
@@ -52,14 +55,10 @@ mod test_scenic_score_count_visible_trees {
 
     #[test]
     fn test_best_3x3() {
-        let forest: UGrid = "533
-                           \n354
-                           \n539"
-            .parse()
-            .unwrap();
+        let forest = parse_forest("533\n354\n539").unwrap();
 
         for (index, &score) in vec![0u32, 0, 0, 0, 1, 0, 0, 0].iter().enumerate() {
-            let position = forest.index_to_coord(index);
+            let position = forest.index_to_position(index);
             assert_eq!(
                 (
                     index,
@@ -78,13 +77,7 @@ mod test_scenic_score_count_visible_trees {
 
     #[test]
     fn test_best_5x5() {
-        let forest: UGrid = "30373
-                           \n25512
-                           \n65332
-                           \n33549
-                           \n35390"
-            .parse()
-            .unwrap();
+        let forest = parse_forest("30373\n25512\n65332\n33549\n35390").unwrap();
 
         for (index, &score) in vec![
             // 1  2  3  4
@@ -97,7 +90,7 @@ mod test_scenic_score_count_visible_trees {
         .iter()
         .enumerate()
         {
-            let position = forest.index_to_coord(index);
+            let position = forest.index_to_position(index);
             assert_eq!(
                 (
                     (index, position),
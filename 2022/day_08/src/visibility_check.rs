@@ -1,16 +1,18 @@
 //
 
-use crate::{direction::Direction, tree::Tree, ugrid::UGrid};
+use advent_of_code::helpers::geometry::Dir4;
+
+use crate::{tree::Tree, Forest};
 
 pub struct VisibilityCheck<'a> {
-    forest: &'a UGrid,
+    forest: &'a Forest,
 }
 impl<'a> VisibilityCheck<'a> {
-    pub fn in_forest(forest: &'a UGrid) -> Self {
+    pub fn in_forest(forest: &'a Forest) -> Self {
         Self { forest }
     }
 
-    pub fn is_visible_from_outside(&self, tree: &Tree, direction: &Direction) -> bool {
+    pub fn is_visible_from_outside(&self, tree: &Tree, direction: Dir4) -> bool {
         !self
             .iter_from_tree(tree, direction)
             .map(|index| self.nth_tree_height(tree, direction, &index))
@@ -19,7 +21,7 @@ impl<'a> VisibilityCheck<'a> {
             .any(|near_tree_height| near_tree_height >= tree.height)
     }
 
-    pub fn count_visible_trees(&self, tree: &Tree, direction: &Direction) -> u32 {
+    pub fn count_visible_trees(&self, tree: &Tree, direction: Dir4) -> u32 {
         use itertools::Itertools;
 
         self.iter_from_tree(tree, direction)
@@ -62,49 +64,43 @@ impl<'a> VisibilityCheck<'a> {
     //
     //
 
-    fn iter_from_tree(
-        &self,
-        tree: &Tree,
-        direction: &Direction,
-    ) -> Box<dyn Iterator<Item = usize> + '_> {
+    fn iter_from_tree(&self, tree: &Tree, direction: Dir4) -> Box<dyn Iterator<Item = usize> + '_> {
         match direction {
-            Direction::Up => Box::new(tree.iter_looking_up()),
-            Direction::Down => Box::new(
+            Dir4::Up => Box::new(tree.iter_looking_up()),
+            Dir4::Down => Box::new(
                 tree.iter_looking_down()
-                    .take_while(|&y| y < self.forest.rows),
+                    .take_while(|&y| y < self.forest.height()),
             ),
-            Direction::Left => Box::new(tree.iter_looking_left()),
-            Direction::Right => Box::new(
+            Dir4::Left => Box::new(tree.iter_looking_left()),
+            Dir4::Right => Box::new(
                 tree.iter_looking_right()
-                    .take_while(|&x| x < self.forest.columns),
+                    .take_while(|&x| x < self.forest.width()),
             ),
         }
     }
 
-    fn nth_tree_height(&self, tree: &Tree, direction: &Direction, &n: &usize) -> usize {
+    fn nth_tree_height(&self, tree: &Tree, direction: Dir4, &n: &usize) -> usize {
         let Tree { position, .. } = tree;
         let &(start_x, start_y) = position;
         match direction {
-            Direction::Up | Direction::Down => self.forest[(start_x, n)],
-            Direction::Left | Direction::Right => self.forest[(n, start_y)],
+            Dir4::Up | Dir4::Down => self.forest[(start_x, n)],
+            Dir4::Left | Dir4::Right => self.forest[(n, start_y)],
         }
     }
 }
 
 #[cfg(test)]
 mod test_visibility_check_visible_from_outside {
+    use crate::parse_forest;
+
     use super::*;
 
     #[test]
     fn test_center_visible_3x3() {
-        let forest: UGrid = "123
-                           \n495
-                           \n678"
-            .parse()
-            .unwrap();
+        let forest = parse_forest("123\n495\n678").unwrap();
         let index = 4;
-        for direction in Direction::iter() {
-            let position = forest.index_to_coord(index);
+        for direction in Dir4::iter() {
+            let position = forest.index_to_position(index);
             assert_eq!(
                 (
                     (index, position),
@@ -123,16 +119,12 @@ mod test_visibility_check_visible_from_outside {
 
     #[test]
     fn test_center_not_visible_3x3() {
-        let forest: UGrid = "123
-                           \n405
-                           \n678"
-            .parse()
-            .unwrap();
+        let forest = parse_forest("123\n405\n678").unwrap();
 
         let index = 4;
-        for direction in Direction::iter() {
+        for direction in Dir4::iter() {
             dbg!(direction);
-            let position = forest.index_to_coord(index);
+            let position = forest.index_to_position(index);
             assert_eq!(
                 (
                     direction,
@@ -151,16 +143,12 @@ mod test_visibility_check_visible_from_outside {
 
     #[test]
     fn test_x_visible_3x3() {
-        let forest: UGrid = "551
-                           \n533
-                           \n354"
-            .parse()
-            .unwrap();
+        let forest = parse_forest("551\n533\n354").unwrap();
 
         let index = 4;
 
-        for (direction, &is_visible) in Direction::iter().zip(&[false, false, false, false]) {
-            let position = forest.index_to_coord(index);
+        for (direction, &is_visible) in Dir4::iter().zip(&[false, false, false, false]) {
+            let position = forest.index_to_position(index);
             assert_eq!(
                 (
                     direction,
@@ -0,0 +1,3 @@
+fn main() {
+    advent_of_code::picture::main(puzzles::tail_visits_image::<10>);
+}
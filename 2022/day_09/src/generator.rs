@@ -0,0 +1,40 @@
+//
+
+use advent_of_code::generate::{Generator, Rng};
+
+//
+
+/// A series of `size` motions of the head, 1 to 19 steps each.
+pub struct InputGenerator;
+
+impl Generator for InputGenerator {
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        (0..size.max(1))
+            .map(|_| {
+                format!(
+                    "{} {}\n",
+                    rng.choose(&['D', 'L', 'R', 'U']),
+                    rng.range(1..=19)
+                )
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test_generator {
+    use advent_of_code::generate::generate;
+
+    use super::*;
+    use crate::{part_one, part_two};
+
+    #[test]
+    fn test_generated_inputs_are_solvable() {
+        for seed in 0..20 {
+            let input = generate(&InputGenerator, 100, seed);
+            // The start is always visited
+            assert!(part_one(&input).unwrap() >= 1, "seed {seed}");
+            assert!(part_two(&input).unwrap() >= 1, "seed {seed}");
+        }
+    }
+}
@@ -1,6 +1,8 @@
 //
 
+mod generator;
 mod motion;
+mod picture;
 mod position;
 mod rope;
 
@@ -8,32 +10,42 @@ mod rope;
 
 use std::collections::HashSet;
 
+use advent_of_code::helpers::parse::{parse_lines, report};
+
 use motion::Motion;
+use position::Position;
 use rope::Rope;
 
+use advent_of_code::compare::Registry;
+
+pub use generator::InputGenerator;
+pub use picture::tail_visits_image;
+
 //
 
 pub fn part_one(input: &str) -> Option<u32> {
-    let mut rope = Rope::<2>::default();
-    let mut tail_positions = HashSet::new();
-
-    let series_of_motions: Vec<Motion> = input.lines().flat_map(Motion::from_line).collect();
+    Some(tail_positions::<2>(input)?.len() as u32)
+}
 
-    for motion in series_of_motions {
-        for vector in motion.as_vectors() {
-            rope.move_head_by(vector);
-            tail_positions.insert(*rope.tail());
-        }
-    }
+pub fn part_two(input: &str) -> Option<u32> {
+    Some(tail_positions::<10>(input)?.len() as u32)
+}
 
-    Some(tail_positions.len() as u32)
+/// Both parts, for `cargo compare` and `cargo minimize`.
+pub fn implementations() -> Registry {
+    Registry::new()
+        .parser(|input| parse_lines::<Motion>(input).is_ok())
+        .register(1, "solution", part_one)
+        .register(2, "solution", part_two)
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    let mut rope = Rope::<10>::default();
+//
+
+fn tail_positions<const KNOTS: usize>(input: &str) -> Option<HashSet<Position>> {
+    let mut rope = Rope::<KNOTS>::default();
     let mut tail_positions = HashSet::new();
 
-    let series_of_motions: Vec<Motion> = input.lines().flat_map(Motion::from_line).collect();
+    let series_of_motions: Vec<Motion> = report("invalid series of motions", parse_lines(input))?;
 
     for motion in series_of_motions {
         for vector in motion.as_vectors() {
@@ -42,5 +54,5 @@ pub fn part_two(input: &str) -> Option<u32> {
         }
     }
 
-    Some(tail_positions.len() as u32)
+    Some(tail_positions)
 }
@@ -1,8 +1,10 @@
 //
 
-use std::{iter, num::ParseIntError, ops::RangeInclusive, str::FromStr};
+use std::{ops::RangeInclusive, str::FromStr};
 
-use crate::position::Position;
+use advent_of_code::helpers::parse::ParseError;
+
+use crate::position::Vector;
 
 //
 
@@ -17,41 +19,49 @@ pub enum Motion {
 //
 
 impl Motion {
-    pub fn from_line(s: &str) -> Result<Motion, ParseIntError> {
-        Self::from_str(s)
-    }
-
-    pub fn as_vectors(&self) -> impl Iterator<Item = Position> {
+    pub fn as_vectors(&self) -> impl Iterator<Item = Vector> {
         use Motion::*;
         let range = match self {
             Up(steps) | Down(steps) | Right(steps) | Left(steps) => steps.to_owned(),
         };
 
         let vector = match self {
-            Up(_) => Position(0, 1),
-            Down(_) => Position(0, -1),
-            Right(_) => Position(1, 0),
-            Left(_) => Position(-1, 0),
+            Up(_) => Vector::new(0, 1),
+            Down(_) => Vector::new(0, -1),
+            Right(_) => Vector::new(1, 0),
+            Left(_) => Vector::new(-1, 0),
         };
 
-        iter::repeat(vector).take(range.count())
+        std::iter::repeat_n(vector, range.count())
     }
 }
 
 impl FromStr for Motion {
-    type Err = ParseIntError;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let [direction, steps] = s.split(' ').collect::<Vec<&str>>()[..] else {
-            unreachable!()
+            return Err(ParseError::new(
+                s,
+                s,
+                "expected a direction and a number of steps",
+            ));
         };
-        let steps: usize = steps.parse()?;
+        let steps: usize = steps
+            .parse()
+            .map_err(|_| ParseError::new(s, steps, "invalid number of steps"))?;
         let motion = match direction {
             "D" => Self::Down(1..=steps),
             "L" => Self::Left(1..=steps),
             "R" => Self::Right(1..=steps),
             "U" => Self::Up(1..=steps),
-            _ => unreachable!(),
+            _ => {
+                return Err(ParseError::new(
+                    s,
+                    direction,
+                    "invalid direction, expected D, L, R or U",
+                ))
+            }
         };
         Ok(motion)
     }
@@ -64,7 +74,10 @@ mod test_motion_as_vectors {
     #[test]
     fn test_repeat_up_positions() {
         let iter = Motion::Up(1..=2).as_vectors();
-        assert_eq!(iter.collect::<Vec<_>>(), &[Position(0, 1), Position(0, 1)])
+        assert_eq!(
+            iter.collect::<Vec<_>>(),
+            &[Vector::new(0, 1), Vector::new(0, 1)]
+        )
     }
 
     #[test]
@@ -72,7 +85,7 @@ mod test_motion_as_vectors {
         let iter = Motion::Down(1..=2).as_vectors();
         assert_eq!(
             iter.collect::<Vec<_>>(),
-            &[Position(0, -1), Position(0, -1)]
+            &[Vector::new(0, -1), Vector::new(0, -1)]
         )
     }
 
@@ -81,14 +94,17 @@ mod test_motion_as_vectors {
         let iter = Motion::Left(1..=2).as_vectors();
         assert_eq!(
             iter.collect::<Vec<_>>(),
-            &[Position(-1, 0), Position(-1, 0)]
+            &[Vector::new(-1, 0), Vector::new(-1, 0)]
         )
     }
 
     #[test]
     fn test_repeat_right_positions() {
         let iter = Motion::Right(1..=2).as_vectors();
-        assert_eq!(iter.collect::<Vec<_>>(), &[Position(1, 0), Position(1, 0)])
+        assert_eq!(
+            iter.collect::<Vec<_>>(),
+            &[Vector::new(1, 0), Vector::new(1, 0)]
+        )
     }
 }
 
@@ -115,4 +131,16 @@ mod test_motion {
     fn test_parse_down_1() {
         assert_eq!("D 1".parse::<Motion>().unwrap(), Motion::Down(1..=1))
     }
+
+    #[test]
+    fn test_parse_invalid_direction() {
+        let error = "X 1".parse::<Motion>().unwrap_err();
+        assert_eq!((error.column, error.token.as_str()), (1, "X"));
+    }
+
+    #[test]
+    fn test_parse_invalid_steps() {
+        let error = "R -1".parse::<Motion>().unwrap_err();
+        assert_eq!((error.column, error.token.as_str()), (3, "-1"));
+    }
 }
@@ -0,0 +1,60 @@
+//
+
+use std::collections::HashSet;
+
+use advent_of_code::helpers::{
+    grid::Grid,
+    image::{Image, Rgb, BLACK, WHITE},
+};
+
+use crate::{position::Position, tail_positions};
+
+const START: Rgb = [220, 50, 47];
+
+//
+
+/// Every square the tail of a `KNOTS` long rope visited in black, the start in red.
+///
+/// Positions have `y` growing upwards, so the image rows go from `max_y` down.
+pub fn tail_visits_image<const KNOTS: usize>(input: &str) -> Option<Image> {
+    Some(tail_visits_picture(&tail_positions::<KNOTS>(input)?))
+}
+
+fn tail_visits_picture(visited: &HashSet<Position>) -> Image {
+    let start = Position::default();
+    let min_x = visited.iter().map(|p| p.x).chain([start.x]).min().unwrap();
+    let max_x = visited.iter().map(|p| p.x).chain([start.x]).max().unwrap();
+    let min_y = visited.iter().map(|p| p.y).chain([start.y]).min().unwrap();
+    let max_y = visited.iter().map(|p| p.y).chain([start.y]).max().unwrap();
+
+    let width = (max_x - min_x + 1) as usize;
+    let height = (max_y - min_y + 1) as usize;
+    let mut map = Grid::filled(width, height, false);
+    for position in visited {
+        map[((position.x - min_x) as usize, (max_y - position.y) as usize)] = true;
+    }
+
+    let mut image = Image::from_grid(&map, |&seen| if seen { BLACK } else { WHITE });
+    image.set_pixel(
+        (start.x - min_x) as usize,
+        (max_y - start.y) as usize,
+        START,
+    );
+    image
+}
+
+#[cfg(test)]
+mod test_tail_visits_picture {
+    use super::*;
+
+    #[test]
+    fn test_bounds_include_start() {
+        let visited = HashSet::from([Position::new(2, -1), Position::new(1, -1)]);
+        let image = tail_visits_picture(&visited);
+        assert_eq!((image.width(), image.height()), (3, 2));
+        assert_eq!(image.pixel(0, 0), START);
+        assert_eq!(image.pixel(0, 1), WHITE);
+        assert_eq!(image.pixel(1, 1), BLACK);
+        assert_eq!(image.pixel(2, 1), BLACK);
+    }
+}
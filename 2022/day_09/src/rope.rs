@@ -1,6 +1,6 @@
 //
 
-use crate::position::Position;
+use crate::position::{Position, Vector};
 
 //
 
@@ -20,14 +20,14 @@ impl<const N: usize> Rope<N> {
         self.knots.get(N - 1).unwrap()
     }
 
-    pub fn move_head_by(&mut self, vector: Position) {
+    pub fn move_head_by(&mut self, vector: Vector) {
         self.knots[0] += vector;
 
         for i in 1..N {
             let head = self.knots[i - 1];
             let tail = self.knots[i];
-            if tail.distance(&head) > 1 {
-                self.knots[i] += (head - tail).normalize();
+            if tail.chebyshev(head) > 1 {
+                self.knots[i] += (head - tail).signum();
             }
         }
     }
@@ -42,75 +42,75 @@ mod test_rope {
     #[test]
     fn test_simple_rope() {
         let Rope { knots, .. } = Rope::<2>::default();
-        assert_eq!(&knots, &[Position(0, 0), Position(0, 0)])
+        assert_eq!(&knots, &[Position::new(0, 0), Position::new(0, 0)])
     }
 
     #[test]
     fn test_move_head_by_right_4() {
         let mut rope = Rope::<2>::default();
-        assert_eq!(&rope.knots, &[Position(0, 0), Position(0, 0)]);
+        assert_eq!(&rope.knots, &[Position::new(0, 0), Position::new(0, 0)]);
 
         //
 
-        rope.move_head_by(Position(1, 0));
-        assert_eq!(&rope.knots, &[Position(1, 0), Position(0, 0)]);
+        rope.move_head_by(Vector::new(1, 0));
+        assert_eq!(&rope.knots, &[Position::new(1, 0), Position::new(0, 0)]);
 
-        rope.move_head_by(Position(1, 0));
-        assert_eq!(&rope.knots, &[Position(2, 0), Position(1, 0)]);
+        rope.move_head_by(Vector::new(1, 0));
+        assert_eq!(&rope.knots, &[Position::new(2, 0), Position::new(1, 0)]);
 
-        rope.move_head_by(Position(1, 0));
-        assert_eq!(&rope.knots, &[Position(3, 0), Position(2, 0)]);
+        rope.move_head_by(Vector::new(1, 0));
+        assert_eq!(&rope.knots, &[Position::new(3, 0), Position::new(2, 0)]);
 
-        rope.move_head_by(Position(1, 0));
-        assert_eq!(&rope.knots, &[Position(4, 0), Position(3, 0)]);
+        rope.move_head_by(Vector::new(1, 0));
+        assert_eq!(&rope.knots, &[Position::new(4, 0), Position::new(3, 0)]);
     }
 
     #[test]
     fn test_move_head_by_up_4() {
         let mut rope = Rope::<2>::default();
-        assert_eq!(&rope.knots, &[Position(0, 0), Position(0, 0)]);
+        assert_eq!(&rope.knots, &[Position::new(0, 0), Position::new(0, 0)]);
 
         //
 
-        rope.move_head_by(Position(0, 1));
-        assert_eq!(&rope.knots, &[Position(0, 1), Position(0, 0)]);
+        rope.move_head_by(Vector::new(0, 1));
+        assert_eq!(&rope.knots, &[Position::new(0, 1), Position::new(0, 0)]);
 
-        rope.move_head_by(Position(0, 1));
-        assert_eq!(&rope.knots, &[Position(0, 2), Position(0, 1)]);
+        rope.move_head_by(Vector::new(0, 1));
+        assert_eq!(&rope.knots, &[Position::new(0, 2), Position::new(0, 1)]);
 
-        rope.move_head_by(Position(0, 1));
-        assert_eq!(&rope.knots, &[Position(0, 3), Position(0, 2)]);
+        rope.move_head_by(Vector::new(0, 1));
+        assert_eq!(&rope.knots, &[Position::new(0, 3), Position::new(0, 2)]);
 
-        rope.move_head_by(Position(0, 1));
-        assert_eq!(&rope.knots, &[Position(0, 4), Position(0, 3)]);
+        rope.move_head_by(Vector::new(0, 1));
+        assert_eq!(&rope.knots, &[Position::new(0, 4), Position::new(0, 3)]);
     }
 
     #[test]
     fn test_move_head_by_left_3() {
         let mut rope = Rope::<2>::default();
-        assert_eq!(&rope.knots, &[Position(0, 0), Position(0, 0)]);
+        assert_eq!(&rope.knots, &[Position::new(0, 0), Position::new(0, 0)]);
 
         //
 
-        rope.move_head_by(Position(-1, 0));
-        assert_eq!(&rope.knots, &[Position(-1, 0), Position(0, 0)]);
+        rope.move_head_by(Vector::new(-1, 0));
+        assert_eq!(&rope.knots, &[Position::new(-1, 0), Position::new(0, 0)]);
 
-        rope.move_head_by(Position(-1, 0));
-        assert_eq!(&rope.knots, &[Position(-2, 0), Position(-1, 0)]);
+        rope.move_head_by(Vector::new(-1, 0));
+        assert_eq!(&rope.knots, &[Position::new(-2, 0), Position::new(-1, 0)]);
 
-        rope.move_head_by(Position(-1, 0));
-        assert_eq!(&rope.knots, &[Position(-3, 0), Position(-2, 0)]);
+        rope.move_head_by(Vector::new(-1, 0));
+        assert_eq!(&rope.knots, &[Position::new(-3, 0), Position::new(-2, 0)]);
     }
 
     #[test]
     fn test_move_head_by_down_1() {
         let mut rope = Rope::<2>::default();
-        assert_eq!(&rope.knots, &[Position(0, 0), Position(0, 0)]);
+        assert_eq!(&rope.knots, &[Position::new(0, 0), Position::new(0, 0)]);
 
         //
 
-        rope.move_head_by(Position(0, -1));
-        assert_eq!(&rope.knots, &[Position(0, -1), Position(0, 0)]);
+        rope.move_head_by(Vector::new(0, -1));
+        assert_eq!(&rope.knots, &[Position::new(0, -1), Position::new(0, 0)]);
     }
 }
 
@@ -1,6 +1,9 @@
 //
 
-use crate::{position::Position, rope::Rope};
+use crate::{
+    position::{Position, Vector},
+    rope::Rope,
+};
 
 //
 
@@ -15,7 +18,7 @@ fn test_example_one_steps() {
     // ......
     // ......
     // H.....  (H covers 1, 2, 3, 4, 5, 6, 7, 8, 9, s)
-    assert_eq!(&rope.knots, &[Position(0, 0); 10]);
+    assert_eq!(&rope.knots, &[Position::new(0, 0); 10]);
 
     // == R 4 ==
 
@@ -24,12 +27,15 @@ fn test_example_one_steps() {
     // ......
     // ......
     // 1H....  (1 covers 2, 3, 4, 5, 6, 7, 8, 9, s)
-    rope.move_head_by(Position(1, 0));
+    rope.move_head_by(Vector::new(1, 0));
     assert_eq!(
         &rope.knots,
-        [[Position(1, 0)].as_slice(), [Position(0, 0); 9].as_slice()]
-            .concat()
-            .as_slice()
+        [
+            [Position::new(1, 0)].as_slice(),
+            [Position::new(0, 0); 9].as_slice()
+        ]
+        .concat()
+        .as_slice()
     );
 
     // ......
@@ -37,12 +43,12 @@ fn test_example_one_steps() {
     // ......
     // ......
     // 21H...  (2 covers 3, 4, 5, 6, 7, 8, 9, s)
-    rope.move_head_by(Position(1, 0));
+    rope.move_head_by(Vector::new(1, 0));
     assert_eq!(
         &rope.knots,
         [
-            [Position(2, 0), Position(1, 0)].as_slice(),
-            [Position(0, 0); 8].as_slice()
+            [Position::new(2, 0), Position::new(1, 0)].as_slice(),
+            [Position::new(0, 0); 8].as_slice()
         ]
         .concat()
         .as_slice()
@@ -53,12 +59,17 @@ fn test_example_one_steps() {
     // ......
     // ......
     // 321H..  (3 covers 4, 5, 6, 7, 8, 9, s)
-    rope.move_head_by(Position(1, 0));
+    rope.move_head_by(Vector::new(1, 0));
     assert_eq!(
         &rope.knots,
         [
-            [Position(3, 0), Position(2, 0), Position(1, 0)].as_slice(),
-            [Position(0, 0); 7].as_slice()
+            [
+                Position::new(3, 0),
+                Position::new(2, 0),
+                Position::new(1, 0)
+            ]
+            .as_slice(),
+            [Position::new(0, 0); 7].as_slice()
         ]
         .concat()
         .as_slice()
@@ -69,18 +80,18 @@ fn test_example_one_steps() {
     // ......
     // ......
     // 4321H.  (4 covers 5, 6, 7, 8, 9, s)
-    rope.move_head_by(Position(1, 0));
+    rope.move_head_by(Vector::new(1, 0));
     assert_eq!(
         &rope.knots,
         [
             [
-                Position(4, 0),
-                Position(3, 0),
-                Position(2, 0),
-                Position(1, 0)
+                Position::new(4, 0),
+                Position::new(3, 0),
+                Position::new(2, 0),
+                Position::new(1, 0)
             ]
             .as_slice(),
-            [Position(0, 0); 6].as_slice()
+            [Position::new(0, 0); 6].as_slice()
         ]
         .concat()
         .as_slice()
@@ -93,18 +104,18 @@ fn test_example_one_steps() {
     // ......
     // ....H.
     // 4321..  (4 covers 5, 6, 7, 8, 9, s)
-    rope.move_head_by(Position(0, 1));
+    rope.move_head_by(Vector::new(0, 1));
     assert_eq!(
         &rope.knots,
         [
             [
-                Position(4, 1),
-                Position(3, 0),
-                Position(2, 0),
-                Position(1, 0)
+                Position::new(4, 1),
+                Position::new(3, 0),
+                Position::new(2, 0),
+                Position::new(1, 0)
             ]
             .as_slice(),
-            [Position(0, 0); 6].as_slice()
+            [Position::new(0, 0); 6].as_slice()
         ]
         .concat()
         .as_slice()
@@ -115,19 +126,19 @@ fn test_example_one_steps() {
     // ....H.
     // .4321.
     // 5.....  (5 covers 6, 7, 8, 9, s)
-    rope.move_head_by(Position(0, 1));
+    rope.move_head_by(Vector::new(0, 1));
     assert_eq!(
         &rope.knots,
         [
             [
-                Position(4, 2),
-                Position(4, 1),
-                Position(3, 1),
-                Position(2, 1),
-                Position(1, 1)
+                Position::new(4, 2),
+                Position::new(4, 1),
+                Position::new(3, 1),
+                Position::new(2, 1),
+                Position::new(1, 1)
             ]
             .as_slice(),
-            [Position(0, 0); 5].as_slice()
+            [Position::new(0, 0); 5].as_slice()
         ]
         .concat()
         .as_slice()
@@ -138,19 +149,19 @@ fn test_example_one_steps() {
     // ....1.
     // .432..
     // 5.....  (5 covers 6, 7, 8, 9, s)
-    rope.move_head_by(Position(0, 1));
+    rope.move_head_by(Vector::new(0, 1));
     assert_eq!(
         &rope.knots,
         [
             [
-                Position(4, 3),
-                Position(4, 2),
-                Position(3, 1),
-                Position(2, 1),
-                Position(1, 1)
+                Position::new(4, 3),
+                Position::new(4, 2),
+                Position::new(3, 1),
+                Position::new(2, 1),
+                Position::new(1, 1)
             ]
             .as_slice(),
-            [Position(0, 0); 5].as_slice()
+            [Position::new(0, 0); 5].as_slice()
         ]
         .concat()
         .as_slice()
@@ -161,20 +172,20 @@ fn test_example_one_steps() {
     // ..432.
     // .5....
     // 6.....  (6 covers 7, 8, 9, s)
-    rope.move_head_by(Position(0, 1));
+    rope.move_head_by(Vector::new(0, 1));
     assert_eq!(
         &rope.knots,
         [
             [
-                Position(4, 4),
-                Position(4, 3),
-                Position(4, 2),
-                Position(3, 2),
-                Position(2, 2),
-                Position(1, 1)
+                Position::new(4, 4),
+                Position::new(4, 3),
+                Position::new(4, 2),
+                Position::new(3, 2),
+                Position::new(2, 2),
+                Position::new(1, 1)
             ]
             .as_slice(),
-            [Position(0, 0); 4].as_slice()
+            [Position::new(0, 0); 4].as_slice()
         ]
         .concat()
         .as_slice()
@@ -187,20 +198,20 @@ fn test_example_one_steps() {
     // ..432.
     // .5....
     // 6.....  (6 covers 7, 8, 9, s)
-    rope.move_head_by(Position(-1, 0));
+    rope.move_head_by(Vector::new(-1, 0));
     assert_eq!(
         &rope.knots,
         [
             [
-                Position(3, 4),
-                Position(4, 3),
-                Position(4, 2),
-                Position(3, 2),
-                Position(2, 2),
-                Position(1, 1)
+                Position::new(3, 4),
+                Position::new(4, 3),
+                Position::new(4, 2),
+                Position::new(3, 2),
+                Position::new(2, 2),
+                Position::new(1, 1)
             ]
             .as_slice(),
-            [Position(0, 0); 4].as_slice()
+            [Position::new(0, 0); 4].as_slice()
         ]
         .concat()
         .as_slice()
@@ -211,20 +222,20 @@ fn test_example_one_steps() {
     // ..43..
     // .5....
     // 6.....  (6 covers 7, 8, 9, s)
-    rope.move_head_by(Position(-1, 0));
+    rope.move_head_by(Vector::new(-1, 0));
     assert_eq!(
         &rope.knots,
         [
             [
-                Position(2, 4),
-                Position(3, 4),
-                Position(3, 3),
-                Position(3, 2),
-                Position(2, 2),
-                Position(1, 1)
+                Position::new(2, 4),
+                Position::new(3, 4),
+                Position::new(3, 3),
+                Position::new(3, 2),
+                Position::new(2, 2),
+                Position::new(1, 1)
             ]
             .as_slice(),
-            [Position(0, 0); 4].as_slice()
+            [Position::new(0, 0); 4].as_slice()
         ]
         .concat()
         .as_slice()
@@ -235,20 +246,20 @@ fn test_example_one_steps() {
     // ..43..
     // .5....
     // 6.....  (6 covers 7, 8, 9, s)
-    rope.move_head_by(Position(-1, 0));
+    rope.move_head_by(Vector::new(-1, 0));
     assert_eq!(
         &rope.knots,
         [
             [
-                Position(1, 4),
-                Position(2, 4),
-                Position(3, 3),
-                Position(3, 2),
-                Position(2, 2),
-                Position(1, 1)
+                Position::new(1, 4),
+                Position::new(2, 4),
+                Position::new(3, 3),
+                Position::new(3, 2),
+                Position::new(2, 2),
+                Position::new(1, 1)
             ]
             .as_slice(),
-            [Position(0, 0); 4].as_slice()
+            [Position::new(0, 0); 4].as_slice()
         ]
         .concat()
         .as_slice()
@@ -261,20 +272,20 @@ fn test_example_one_steps() {
     // ..43..
     // .5....
     // 6.....  (6 covers 7, 8, 9, s)
-    rope.move_head_by(Position(0, -1));
+    rope.move_head_by(Vector::new(0, -1));
     assert_eq!(
         &rope.knots,
         [
             [
-                Position(1, 3),
-                Position(2, 4),
-                Position(3, 3),
-                Position(3, 2),
-                Position(2, 2),
-                Position(1, 1)
+                Position::new(1, 3),
+                Position::new(2, 4),
+                Position::new(3, 3),
+                Position::new(3, 2),
+                Position::new(2, 2),
+                Position::new(1, 1)
             ]
             .as_slice(),
-            [Position(0, 0); 4].as_slice()
+            [Position::new(0, 0); 4].as_slice()
         ]
         .concat()
         .as_slice()
@@ -287,20 +298,20 @@ fn test_example_one_steps() {
     // ..43..
     // .5....
     // 6.....  (6 covers 7, 8, 9, s)
-    rope.move_head_by(Position(1, 0));
+    rope.move_head_by(Vector::new(1, 0));
     assert_eq!(
         &rope.knots,
         [
             [
-                Position(2, 3),
-                Position(2, 4),
-                Position(3, 3),
-                Position(3, 2),
-                Position(2, 2),
-                Position(1, 1)
+                Position::new(2, 3),
+                Position::new(2, 4),
+                Position::new(3, 3),
+                Position::new(3, 2),
+                Position::new(2, 2),
+                Position::new(1, 1)
             ]
             .as_slice(),
-            [Position(0, 0); 4].as_slice()
+            [Position::new(0, 0); 4].as_slice()
         ]
         .concat()
         .as_slice()
@@ -311,20 +322,20 @@ fn test_example_one_steps() {
     // ..43..
     // .5....
     // 6.....  (6 covers 7, 8, 9, s)
-    rope.move_head_by(Position(1, 0));
+    rope.move_head_by(Vector::new(1, 0));
     assert_eq!(
         &rope.knots,
         [
             [
-                Position(3, 3),
-                Position(2, 4),
-                Position(3, 3),
-                Position(3, 2),
-                Position(2, 2),
-                Position(1, 1)
+                Position::new(3, 3),
+                Position::new(2, 4),
+                Position::new(3, 3),
+                Position::new(3, 2),
+                Position::new(2, 2),
+                Position::new(1, 1)
             ]
             .as_slice(),
-            [Position(0, 0); 4].as_slice()
+            [Position::new(0, 0); 4].as_slice()
         ]
         .concat()
         .as_slice()
@@ -335,20 +346,20 @@ fn test_example_one_steps() {
     // ..43..
     // .5....
     // 6.....  (6 covers 7, 8, 9, s)
-    rope.move_head_by(Position(1, 0));
+    rope.move_head_by(Vector::new(1, 0));
     assert_eq!(
         &rope.knots,
         [
             [
-                Position(4, 3),
-                Position(3, 3),
-                Position(3, 3),
-                Position(3, 2),
-                Position(2, 2),
-                Position(1, 1)
+                Position::new(4, 3),
+                Position::new(3, 3),
+                Position::new(3, 3),
+                Position::new(3, 2),
+                Position::new(2, 2),
+                Position::new(1, 1)
             ]
             .as_slice(),
-            [Position(0, 0); 4].as_slice()
+            [Position::new(0, 0); 4].as_slice()
         ]
         .concat()
         .as_slice()
@@ -359,20 +370,20 @@ fn test_example_one_steps() {
     // ..43..
     // .5....
     // 6.....  (6 covers 7, 8, 9, s)
-    rope.move_head_by(Position(1, 0));
+    rope.move_head_by(Vector::new(1, 0));
     assert_eq!(
         &rope.knots,
         [
             [
-                Position(5, 3),
-                Position(4, 3),
-                Position(3, 3),
-                Position(3, 2),
-                Position(2, 2),
-                Position(1, 1)
+                Position::new(5, 3),
+                Position::new(4, 3),
+                Position::new(3, 3),
+                Position::new(3, 2),
+                Position::new(2, 2),
+                Position::new(1, 1)
             ]
             .as_slice(),
-            [Position(0, 0); 4].as_slice()
+            [Position::new(0, 0); 4].as_slice()
         ]
         .concat()
         .as_slice()
@@ -385,20 +396,20 @@ fn test_example_one_steps() {
     // ..43.H
     // .5....
     // 6.....  (6 covers 7, 8, 9, s)
-    rope.move_head_by(Position(0, -1));
+    rope.move_head_by(Vector::new(0, -1));
     assert_eq!(
         &rope.knots,
         [
             [
-                Position(5, 2),
-                Position(4, 3),
-                Position(3, 3),
-                Position(3, 2),
-                Position(2, 2),
-                Position(1, 1)
+                Position::new(5, 2),
+                Position::new(4, 3),
+                Position::new(3, 3),
+                Position::new(3, 2),
+                Position::new(2, 2),
+                Position::new(1, 1)
             ]
             .as_slice(),
-            [Position(0, 0); 4].as_slice()
+            [Position::new(0, 0); 4].as_slice()
         ]
         .concat()
         .as_slice()
@@ -411,20 +422,20 @@ fn test_example_one_steps() {
     // ..43H.
     // .5....
     // 6.....  (6 covers 7, 8, 9, s)
-    rope.move_head_by(Position(-1, 0));
+    rope.move_head_by(Vector::new(-1, 0));
     assert_eq!(
         &rope.knots,
         [
             [
-                Position(4, 2),
-                Position(4, 3),
-                Position(3, 3),
-                Position(3, 2),
-                Position(2, 2),
-                Position(1, 1)
+                Position::new(4, 2),
+                Position::new(4, 3),
+                Position::new(3, 3),
+                Position::new(3, 2),
+                Position::new(2, 2),
+                Position::new(1, 1)
             ]
             .as_slice(),
-            [Position(0, 0); 4].as_slice()
+            [Position::new(0, 0); 4].as_slice()
         ]
         .concat()
         .as_slice()
@@ -435,20 +446,20 @@ fn test_example_one_steps() {
     // ..4H..  (H covers 3)
     // .5....
     // 6.....  (6 covers 7, 8, 9, s)
-    rope.move_head_by(Position(-1, 0));
+    rope.move_head_by(Vector::new(-1, 0));
     assert_eq!(
         &rope.knots,
         [
             [
-                Position(3, 2),
-                Position(4, 3),
-                Position(3, 3),
-                Position(3, 2),
-                Position(2, 2),
-                Position(1, 1)
+                Position::new(3, 2),
+                Position::new(4, 3),
+                Position::new(3, 3),
+                Position::new(3, 2),
+                Position::new(2, 2),
+                Position::new(1, 1)
             ]
             .as_slice(),
-            [Position(0, 0); 4].as_slice()
+            [Position::new(0, 0); 4].as_slice()
         ]
         .concat()
         .as_slice()
@@ -459,20 +470,20 @@ fn test_example_one_steps() {
     // ..H1..  (H covers 4; 1 covers 3)
     // .5....
     // 6.....  (6 covers 7, 8, 9, s)
-    rope.move_head_by(Position(-1, 0));
+    rope.move_head_by(Vector::new(-1, 0));
     assert_eq!(
         &rope.knots,
         [
             [
-                Position(2, 2),
-                Position(3, 2),
-                Position(3, 3),
-                Position(3, 2),
-                Position(2, 2),
-                Position(1, 1)
+                Position::new(2, 2),
+                Position::new(3, 2),
+                Position::new(3, 3),
+                Position::new(3, 2),
+                Position::new(2, 2),
+                Position::new(1, 1)
             ]
             .as_slice(),
-            [Position(0, 0); 4].as_slice()
+            [Position::new(0, 0); 4].as_slice()
         ]
         .concat()
         .as_slice()
@@ -483,20 +494,20 @@ fn test_example_one_steps() {
     // .H13..  (1 covers 4)
     // .5....
     // 6.....  (6 covers 7, 8, 9, s)
-    rope.move_head_by(Position(-1, 0));
+    rope.move_head_by(Vector::new(-1, 0));
     assert_eq!(
         &rope.knots,
         [
             [
-                Position(1, 2),
-                Position(2, 2),
-                Position(3, 3),
-                Position(3, 2),
-                Position(2, 2),
-                Position(1, 1)
+                Position::new(1, 2),
+                Position::new(2, 2),
+                Position::new(3, 3),
+                Position::new(3, 2),
+                Position::new(2, 2),
+                Position::new(1, 1)
             ]
             .as_slice(),
-            [Position(0, 0); 4].as_slice()
+            [Position::new(0, 0); 4].as_slice()
         ]
         .concat()
         .as_slice()
@@ -507,20 +518,20 @@ fn test_example_one_steps() {
     // H123..  (2 covers 4)
     // .5....
     // 6.....  (6 covers 7, 8, 9, s)
-    rope.move_head_by(Position(-1, 0));
+    rope.move_head_by(Vector::new(-1, 0));
     assert_eq!(
         &rope.knots,
         [
             [
-                Position(0, 2),
-                Position(1, 2),
-                Position(2, 2),
-                Position(3, 2),
-                Position(2, 2),
-                Position(1, 1)
+                Position::new(0, 2),
+                Position::new(1, 2),
+                Position::new(2, 2),
+                Position::new(3, 2),
+                Position::new(2, 2),
+                Position::new(1, 1)
             ]
             .as_slice(),
-            [Position(0, 0); 4].as_slice()
+            [Position::new(0, 0); 4].as_slice()
         ]
         .concat()
         .as_slice()
@@ -533,20 +544,20 @@ fn test_example_one_steps() {
     // .H23..  (H covers 1; 2 covers 4)
     // .5....
     // 6.....  (6 covers 7, 8, 9, s)
-    rope.move_head_by(Position(1, 0));
+    rope.move_head_by(Vector::new(1, 0));
     assert_eq!(
         &rope.knots,
         [
             [
-                Position(1, 2),
-                Position(1, 2),
-                Position(2, 2),
-                Position(3, 2),
-                Position(2, 2),
-                Position(1, 1)
+                Position::new(1, 2),
+                Position::new(1, 2),
+                Position::new(2, 2),
+                Position::new(3, 2),
+                Position::new(2, 2),
+                Position::new(1, 1)
             ]
             .as_slice(),
-            [Position(0, 0); 4].as_slice()
+            [Position::new(0, 0); 4].as_slice()
         ]
         .concat()
         .as_slice()
@@ -557,20 +568,20 @@ fn test_example_one_steps() {
     // .1H3..  (H covers 2, 4)
     // .5....
     // 6.....  (6 covers 7, 8, 9, s)
-    rope.move_head_by(Position(1, 0));
+    rope.move_head_by(Vector::new(1, 0));
     assert_eq!(
         &rope.knots,
         [
             [
-                Position(2, 2),
-                Position(1, 2),
-                Position(2, 2),
-                Position(3, 2),
-                Position(2, 2),
-                Position(1, 1)
+                Position::new(2, 2),
+                Position::new(1, 2),
+                Position::new(2, 2),
+                Position::new(3, 2),
+                Position::new(2, 2),
+                Position::new(1, 1)
             ]
             .as_slice(),
-            [Position(0, 0); 4].as_slice()
+            [Position::new(0, 0); 4].as_slice()
         ]
         .concat()
         .as_slice()
@@ -1,6 +1,9 @@
 //
 
-use crate::{position::Position, rope::Rope};
+use crate::{
+    position::{Position, Vector},
+    rope::Rope,
+};
 
 //
 
@@ -15,7 +18,7 @@ fn test_example_one_steps() {
     // ......
     // ......
     // H.....  (H covers T, s)
-    assert_eq!(&rope.knots, &[Position(0, 0); 2]);
+    assert_eq!(&rope.knots, &[Position::new(0, 0); 2]);
 
     // == R 4 ==
 
@@ -24,32 +27,32 @@ fn test_example_one_steps() {
     // ......
     // ......
     // TH....  (T covers s)
-    rope.move_head_by(Position(1, 0));
-    assert_eq!(&rope.knots, &[Position(1, 0), Position(0, 0)]);
+    rope.move_head_by(Vector::new(1, 0));
+    assert_eq!(&rope.knots, &[Position::new(1, 0), Position::new(0, 0)]);
 
     // ......
     // ......
     // ......
     // ......
     // sTH...
-    rope.move_head_by(Position(1, 0));
-    assert_eq!(&rope.knots, &[Position(2, 0), Position(1, 0)]);
+    rope.move_head_by(Vector::new(1, 0));
+    assert_eq!(&rope.knots, &[Position::new(2, 0), Position::new(1, 0)]);
 
     // ......
     // ......
     // ......
     // ......
     // s.TH..
-    rope.move_head_by(Position(1, 0));
-    assert_eq!(&rope.knots, &[Position(3, 0), Position(2, 0)]);
+    rope.move_head_by(Vector::new(1, 0));
+    assert_eq!(&rope.knots, &[Position::new(3, 0), Position::new(2, 0)]);
 
     // ......
     // ......
     // ......
     // ......
     // s..TH.
-    rope.move_head_by(Position(1, 0));
-    assert_eq!(&rope.knots, &[Position(4, 0), Position(3, 0)]);
+    rope.move_head_by(Vector::new(1, 0));
+    assert_eq!(&rope.knots, &[Position::new(4, 0), Position::new(3, 0)]);
 
     // == U 4 ==
 
@@ -58,32 +61,32 @@ fn test_example_one_steps() {
     // ......
     // ....H.
     // s..T..
-    rope.move_head_by(Position(0, 1));
-    assert_eq!(&rope.knots, &[Position(4, 1), Position(3, 0)]);
+    rope.move_head_by(Vector::new(0, 1));
+    assert_eq!(&rope.knots, &[Position::new(4, 1), Position::new(3, 0)]);
 
     // ......
     // ......
     // ....H.
     // ....T.
     // s.....
-    rope.move_head_by(Position(0, 1));
-    assert_eq!(&rope.knots, &[Position(4, 2), Position(4, 1)]);
+    rope.move_head_by(Vector::new(0, 1));
+    assert_eq!(&rope.knots, &[Position::new(4, 2), Position::new(4, 1)]);
 
     // ......
     // ....H.
     // ....T.
     // ......
     // s.....
-    rope.move_head_by(Position(0, 1));
-    assert_eq!(&rope.knots, &[Position(4, 3), Position(4, 2)]);
+    rope.move_head_by(Vector::new(0, 1));
+    assert_eq!(&rope.knots, &[Position::new(4, 3), Position::new(4, 2)]);
 
     // ....H.
     // ....T.
     // ......
     // ......
     // s.....
-    rope.move_head_by(Position(0, 1));
-    assert_eq!(&rope.knots, &[Position(4, 4), Position(4, 3)]);
+    rope.move_head_by(Vector::new(0, 1));
+    assert_eq!(&rope.knots, &[Position::new(4, 4), Position::new(4, 3)]);
 
     // == L 3 ==
 
@@ -92,24 +95,24 @@ fn test_example_one_steps() {
     // ......
     // ......
     // s.....
-    rope.move_head_by(Position(-1, 0));
-    assert_eq!(&rope.knots, &[Position(3, 4), Position(4, 3)]);
+    rope.move_head_by(Vector::new(-1, 0));
+    assert_eq!(&rope.knots, &[Position::new(3, 4), Position::new(4, 3)]);
 
     // ..HT..
     // ......
     // ......
     // ......
     // s.....
-    rope.move_head_by(Position(-1, 0));
-    assert_eq!(&rope.knots, &[Position(2, 4), Position(3, 4)]);
+    rope.move_head_by(Vector::new(-1, 0));
+    assert_eq!(&rope.knots, &[Position::new(2, 4), Position::new(3, 4)]);
 
     // .HT...
     // ......
     // ......
     // ......
     // s.....
-    rope.move_head_by(Position(-1, 0));
-    assert_eq!(&rope.knots, &[Position(1, 4), Position(2, 4)]);
+    rope.move_head_by(Vector::new(-1, 0));
+    assert_eq!(&rope.knots, &[Position::new(1, 4), Position::new(2, 4)]);
 
     // == D 1 ==
 
@@ -118,8 +121,8 @@ fn test_example_one_steps() {
     // ......
     // ......
     // s.....
-    rope.move_head_by(Position(0, -1));
-    assert_eq!(&rope.knots, &[Position(1, 3), Position(2, 4)]);
+    rope.move_head_by(Vector::new(0, -1));
+    assert_eq!(&rope.knots, &[Position::new(1, 3), Position::new(2, 4)]);
 
     // == R 4 ==
 
@@ -128,32 +131,32 @@ fn test_example_one_steps() {
     // ......
     // ......
     // s.....
-    rope.move_head_by(Position(1, 0));
-    assert_eq!(&rope.knots, &[Position(2, 3), Position(2, 4)]);
+    rope.move_head_by(Vector::new(1, 0));
+    assert_eq!(&rope.knots, &[Position::new(2, 3), Position::new(2, 4)]);
 
     // ..T...
     // ...H..
     // ......
     // ......
     // s.....
-    rope.move_head_by(Position(1, 0));
-    assert_eq!(&rope.knots, &[Position(3, 3), Position(2, 4)]);
+    rope.move_head_by(Vector::new(1, 0));
+    assert_eq!(&rope.knots, &[Position::new(3, 3), Position::new(2, 4)]);
 
     // ......
     // ...TH.
     // ......
     // ......
     // s.....
-    rope.move_head_by(Position(1, 0));
-    assert_eq!(&rope.knots, &[Position(4, 3), Position(3, 3)]);
+    rope.move_head_by(Vector::new(1, 0));
+    assert_eq!(&rope.knots, &[Position::new(4, 3), Position::new(3, 3)]);
 
     // ......
     // ....TH
     // ......
     // ......
     // s.....
-    rope.move_head_by(Position(1, 0));
-    assert_eq!(&rope.knots, &[Position(5, 3), Position(4, 3)]);
+    rope.move_head_by(Vector::new(1, 0));
+    assert_eq!(&rope.knots, &[Position::new(5, 3), Position::new(4, 3)]);
 
     // == D 1 ==
 
@@ -162,8 +165,8 @@ fn test_example_one_steps() {
     // .....H
     // ......
     // s.....
-    rope.move_head_by(Position(0, -1));
-    assert_eq!(&rope.knots, &[Position(5, 2), Position(4, 3)]);
+    rope.move_head_by(Vector::new(0, -1));
+    assert_eq!(&rope.knots, &[Position::new(5, 2), Position::new(4, 3)]);
 
     // == L 5 ==
 
@@ -172,40 +175,40 @@ fn test_example_one_steps() {
     // ....H.
     // ......
     // s.....
-    rope.move_head_by(Position(-1, 0));
-    assert_eq!(&rope.knots, &[Position(4, 2), Position(4, 3)]);
+    rope.move_head_by(Vector::new(-1, 0));
+    assert_eq!(&rope.knots, &[Position::new(4, 2), Position::new(4, 3)]);
 
     // ......
     // ....T.
     // ...H..
     // ......
     // s.....
-    rope.move_head_by(Position(-1, 0));
-    assert_eq!(&rope.knots, &[Position(3, 2), Position(4, 3)]);
+    rope.move_head_by(Vector::new(-1, 0));
+    assert_eq!(&rope.knots, &[Position::new(3, 2), Position::new(4, 3)]);
 
     // ......
     // ......
     // ..HT..
     // ......
     // s.....
-    rope.move_head_by(Position(-1, 0));
-    assert_eq!(&rope.knots, &[Position(2, 2), Position(3, 2)]);
+    rope.move_head_by(Vector::new(-1, 0));
+    assert_eq!(&rope.knots, &[Position::new(2, 2), Position::new(3, 2)]);
 
     // ......
     // ......
     // .HT...
     // ......
     // s.....
-    rope.move_head_by(Position(-1, 0));
-    assert_eq!(&rope.knots, &[Position(1, 2), Position(2, 2)]);
+    rope.move_head_by(Vector::new(-1, 0));
+    assert_eq!(&rope.knots, &[Position::new(1, 2), Position::new(2, 2)]);
 
     // ......
     // ......
     // HT....
     // ......
     // s.....
-    rope.move_head_by(Position(-1, 0));
-    assert_eq!(&rope.knots, &[Position(0, 2), Position(1, 2)]);
+    rope.move_head_by(Vector::new(-1, 0));
+    assert_eq!(&rope.knots, &[Position::new(0, 2), Position::new(1, 2)]);
 
     // == R 2 ==
 
@@ -214,14 +217,14 @@ fn test_example_one_steps() {
     // .H....  (H covers T)
     // ......
     // s.....
-    rope.move_head_by(Position(1, 0));
-    assert_eq!(&rope.knots, &[Position(1, 2), Position(1, 2)]);
+    rope.move_head_by(Vector::new(1, 0));
+    assert_eq!(&rope.knots, &[Position::new(1, 2), Position::new(1, 2)]);
 
     // ......
     // ......
     // .TH...
     // ......
     // s.....
-    rope.move_head_by(Position(1, 0));
-    assert_eq!(&rope.knots, &[Position(2, 2), Position(1, 2)]);
+    rope.move_head_by(Vector::new(1, 0));
+    assert_eq!(&rope.knots, &[Position::new(2, 2), Position::new(1, 2)]);
 }
@@ -2,10 +2,15 @@
 
 use std::{fmt::Display, ops::RangeInclusive};
 
+use advent_of_code::helpers::{
+    grid::Grid,
+    ocr::{self, OcrError},
+};
+
 #[derive(Debug)]
 pub struct Crt {
     cycle: usize,
-    screen: [bool; Self::WIDE * Self::HIGH],
+    screen: Grid<bool>,
     x: isize,
 }
 
@@ -23,11 +28,34 @@ impl Crt {
 
     pub fn render(&mut self, delta_x: Vec<isize>) {
         for delta in delta_x {
-            self.screen[self.cycle] = self.sprite_range().contains(&self.x);
+            let position = self.screen.index_to_position(self.cycle);
+            self.screen[position] = self.sprite_range().contains(&self.x);
             self.cycle += 1;
             self.x += delta;
         }
     }
+
+    /// Read the capital letters drawn on the screen.
+    pub fn letters(&self) -> Result<String, OcrError> {
+        ocr::recognize_grid(&self.screen.rows().collect::<Vec<_>>())
+    }
+}
+
+impl Default for Crt {
+    fn default() -> Self {
+        Self {
+            cycle: 0,
+            screen: Grid::filled(Self::WIDE, Self::HIGH, false),
+            x: 1,
+        }
+    }
+}
+impl Display for Crt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let screen = self.screen.display(|&pixel| if pixel { '#' } else { '.' });
+
+        write!(f, "{}", screen)
+    }
 }
 
 #[cfg(test)]
@@ -60,32 +88,35 @@ mod test_crt_render {
     }
 }
 
-impl Default for Crt {
-    fn default() -> Self {
-        Self {
-            cycle: 0,
-            screen: [false; Self::WIDE * Self::HIGH],
-            x: 1,
+#[cfg(test)]
+mod test_crt_letters {
+    use super::*;
+
+    #[test]
+    fn test_letters() {
+        let mut crt = Crt::default();
+        let picture = [
+            "####.#..#.###..####.###....##..##..#....",
+            "#....#..#.#..#....#.#..#....#.#..#.#....",
+            "###..####.#..#...#..#..#....#.#....#....",
+            "#....#..#.###...#...###.....#.#.##.#....",
+            "#....#..#.#....#....#....#..#.#..#.#....",
+            "####.#..#.#....####.#.....##...###.####.",
+        ]
+        .concat();
+        for (pixel, c) in crt.screen.iter_mut().zip(picture.chars()) {
+            *pixel = c == '#';
         }
+        assert_eq!(crt.letters(), Ok("EHPZPJGL".to_string()));
     }
-}
-impl Display for Crt {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let screen = self
-            .screen
-            .chunks(Self::WIDE)
-            .map(|row| {
-                row.iter()
-                    .map(|pixel| match pixel {
-                        true => "#",
-                        _ => ".",
-                    })
-                    .collect::<Vec<_>>()
-                    .join("")
-            })
-            .collect::<Vec<_>>()
-            .join("\n");
 
-        f.write_str(&screen)
+    #[test]
+    fn test_letters_unknown_glyph() {
+        let mut crt = Crt::default();
+        crt.render(vec![0; Crt::WIDE * Crt::HIGH]);
+        assert!(matches!(
+            crt.letters(),
+            Err(OcrError::UnknownGlyph { column: 0, .. })
+        ));
     }
 }
@@ -0,0 +1,83 @@
+//
+
+use advent_of_code::{
+    generate::{Generator, Rng},
+    helpers::ocr,
+};
+
+//
+
+const WIDE: usize = 40;
+
+/// A program drawing eight random capital letters on the CRT.
+///
+/// The screen is always 240 cycles long, so `size` is ignored.
+pub struct InputGenerator;
+
+impl Generator for InputGenerator {
+    fn generate(&self, rng: &mut Rng, _size: usize) -> String {
+        let alphabet: Vec<char> = ocr::alphabet().collect();
+        // X starts at 1, so the first two pixels are always lit.
+        let first_letters: Vec<char> = alphabet
+            .iter()
+            .copied()
+            .filter(|&letter| {
+                let rows = ocr::draw(&letter.to_string()).unwrap();
+                rows[0][0] && rows[0][1]
+            })
+            .collect();
+        let word: String = std::iter::once(*rng.choose(&first_letters))
+            .chain((1..8).map(|_| *rng.choose(&alphabet)))
+            .collect();
+
+        program(rng, &ocr::draw(&word).unwrap())
+    }
+}
+
+/// One `addx` (or two `noop`) per pair of pixels, setting X for the next pair.
+fn program(rng: &mut Rng, rows: &[Vec<bool>]) -> String {
+    let pixels: Vec<bool> = rows.iter().flatten().copied().collect();
+    let mut x = 1;
+    let mut program = String::new();
+    for pair in 1..pixels.len() / 2 {
+        let next_x = sprite_for(rng, pair * 2 % WIDE, pixels[pair * 2], pixels[pair * 2 + 1]);
+        if next_x == x && rng.chance(0.5) {
+            program += "noop\nnoop\n";
+        } else {
+            program += &format!("addx {}\n", next_x - x);
+        }
+        x = next_x;
+    }
+    program + &format!("addx {}\n", rng.signed_range(-5..=5))
+}
+
+/// A sprite position lighting `column` and `column + 1` as wanted.
+fn sprite_for(rng: &mut Rng, column: usize, left: bool, right: bool) -> isize {
+    let column = column as isize;
+    match (left, right) {
+        (true, true) => rng.signed_range(column..=column + 1),
+        (true, false) => column - 1,
+        (false, true) => column + 2,
+        (false, false) if rng.chance(0.5) => rng.signed_range(column - 8..=column - 2),
+        (false, false) => rng.signed_range(column + 3..=column + 8),
+    }
+}
+
+#[cfg(test)]
+mod test_generator {
+    use advent_of_code::generate::generate;
+
+    use super::*;
+    use crate::{part_one, part_two};
+
+    #[test]
+    fn test_generated_inputs_are_solvable() {
+        for seed in 0..20 {
+            let input = generate(&InputGenerator, 0, seed);
+            assert!(part_one(&input).is_some());
+            let letters = part_two(&input).unwrap();
+            assert_eq!(letters.len(), 8, "seed {seed}: {letters}");
+            assert!(letters.chars().all(|c| c.is_ascii_uppercase()));
+        }
+    }
+}
@@ -1,6 +1,8 @@
 //
 
-use std::{convert::Infallible, str::FromStr};
+use std::str::FromStr;
+
+use advent_of_code::helpers::parse::ParseError;
 
 //
 
@@ -22,18 +24,21 @@ impl Instruction {
     }
 }
 
-impl From<&str> for Instruction {
-    fn from(s: &str) -> Self {
-        Self::from_str(s).unwrap()
-    }
-}
 impl FromStr for Instruction {
-    type Err = Infallible;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s.trim().split_once(' ') {
-            Some((_, number)) => Ok(Instruction::Addx(number.parse().unwrap())),
-            None => Ok(Instruction::Noop),
+        match s.trim_end().split_once(' ') {
+            Some(("addx", number)) => number
+                .parse()
+                .map(Instruction::Addx)
+                .map_err(|_| ParseError::new(s, number, "invalid addx value")),
+            None if s.trim_end() == "noop" => Ok(Instruction::Noop),
+            _ => Err(ParseError::new(
+                s,
+                s,
+                "invalid instruction, expected `noop` or `addx V`",
+            )),
         }
     }
 }
@@ -56,6 +61,18 @@ mod test_instruction_from_str {
     fn test_addx_sub5() {
         assert_eq!("addx -5".parse(), Ok(Instruction::Addx(-5)))
     }
+
+    #[test]
+    fn test_addx_invalid_value() {
+        let error = "addx five".parse::<Instruction>().unwrap_err();
+        assert_eq!((error.column, error.token.as_str()), (6, "five"));
+    }
+
+    #[test]
+    fn test_unknown_instruction() {
+        let error = "jmp 2".parse::<Instruction>().unwrap_err();
+        assert_eq!((error.column, error.token.as_str()), (1, "jmp"));
+    }
 }
 
 #[cfg(test)]
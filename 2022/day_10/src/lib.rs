@@ -1,20 +1,25 @@
 //
 
 mod crt;
+mod generator;
 mod instruction;
 
 //
 
+use advent_of_code::helpers::parse::{parse_lines, report};
 use crt::Crt;
 use instruction::Instruction;
 
+use advent_of_code::compare::Registry;
+
+pub use generator::InputGenerator;
+
 //
 
 pub fn part_one(input: &str) -> Option<isize> {
     const SIGNAL_STRENGTH: [usize; 6] = [20, 60, 100, 140, 180, 220];
-    let x_cycles: Vec<isize> = input
-        .lines()
-        .map(Instruction::from)
+    let x_cycles: Vec<isize> = program(input)?
+        .iter()
         .flat_map(|instr| instr.to_x_cycles())
         .collect();
 
@@ -28,8 +33,23 @@ pub fn part_one(input: &str) -> Option<isize> {
 pub fn part_two(input: &str) -> Option<String> {
     let mut crt = Crt::default();
 
-    for instruction in input.lines().map(Instruction::from) {
+    for instruction in program(input)? {
         crt.render(instruction.to_x_cycles());
     }
-    Some(format!("{}", crt))
+    // Pictures that are not made of letters, like the example, are returned as is.
+    Some(crt.letters().unwrap_or_else(|_| crt.to_string()))
+}
+
+/// Both parts, for `cargo compare` and `cargo minimize`.
+pub fn implementations() -> Registry {
+    Registry::new()
+        .parser(|input| parse_lines::<Instruction>(input).is_ok())
+        .register(1, "solution", part_one)
+        .register(2, "solution", part_two)
+}
+
+//
+
+fn program(input: &str) -> Option<Vec<Instruction>> {
+    report("invalid program", parse_lines(input))
 }
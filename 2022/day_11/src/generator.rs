@@ -0,0 +1,141 @@
+//
+
+use advent_of_code::generate::{Generator, Rng};
+
+use crate::monkey::{Item, Monkey, Operation, Test, Worry};
+
+//
+
+const PRIMES: [u64; 8] = [2, 3, 5, 7, 11, 13, 17, 19];
+
+/// Notes about 3 to 8 monkeys juggling `size` items.
+///
+/// Tests divide by distinct primes, monkeys never throw to themselves and at most
+/// one of them squares worries. Operations are picked again until the 20 rounds
+/// of part one fit in a `u64`.
+pub struct InputGenerator;
+
+impl Generator for InputGenerator {
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        let count = rng.range(3..=PRIMES.len());
+        let mut divisors = PRIMES.to_vec();
+        rng.shuffle(&mut divisors);
+
+        let mut monkeys: Vec<Monkey> = (0..count)
+            .map(|id| {
+                let others: Vec<usize> = (0..count).filter(|&other| other != id).collect();
+                let if_true = *rng.choose(&others);
+                let others: Vec<usize> = others.into_iter().filter(|&o| o != if_true).collect();
+                Monkey {
+                    id,
+                    items: Vec::new(),
+                    operation: Operation::Add(Worry::Old, Worry::Level(1)),
+                    test: Test::DivisibleBy(divisors[id], (if_true, *rng.choose(&others))),
+                }
+            })
+            .collect();
+        for _ in 0..size {
+            monkeys[rng.below(count)]
+                .items
+                .push(Item(rng.range(50..=99) as u64));
+        }
+
+        loop {
+            let squaring = rng.below(count);
+            for monkey in monkeys.iter_mut() {
+                monkey.operation = operation(rng, monkey.id == squaring);
+            }
+            if fits_in_u64(&monkeys) {
+                break;
+            }
+        }
+
+        monkeys.iter().map(note).collect::<Vec<_>>().join("\n\n") + "\n"
+    }
+}
+
+fn operation(rng: &mut Rng, may_square: bool) -> Operation {
+    match rng.below(10) {
+        0..=3 if may_square => Operation::Multiply(Worry::Old, Worry::Old),
+        0..=4 => Operation::Multiply(Worry::Old, Worry::Level(rng.range(2..=19) as u64)),
+        _ => Operation::Add(Worry::Old, Worry::Level(rng.range(1..=8) as u64)),
+    }
+}
+
+/// Plays the 20 rounds of part one, watching for overflows.
+fn fits_in_u64(monkeys: &[Monkey]) -> bool {
+    let level = |worry: Worry, old: u64| match worry {
+        Worry::Old => old,
+        Worry::Level(level) => level,
+    };
+    let mut holdings: Vec<Vec<u64>> = monkeys
+        .iter()
+        .map(|monkey| monkey.items.iter().map(|Item(worry)| *worry).collect())
+        .collect();
+    for _ in 0..20 {
+        for monkey in monkeys {
+            for old in std::mem::take(&mut holdings[monkey.id]) {
+                let new = match monkey.operation {
+                    Operation::Add(a, b) => level(a, old).checked_add(level(b, old)),
+                    Operation::Multiply(a, b) => level(a, old).checked_mul(level(b, old)),
+                };
+                let Some(new) = new else {
+                    return false;
+                };
+                let new = new / 3;
+                holdings[monkey.test.throw_index(new)].push(new);
+            }
+        }
+    }
+    true
+}
+
+fn note(monkey: &Monkey) -> String {
+    let worry = |worry: Worry| match worry {
+        Worry::Old => "old".to_string(),
+        Worry::Level(level) => level.to_string(),
+    };
+    let operation = match monkey.operation {
+        Operation::Add(a, b) => format!("{} + {}", worry(a), worry(b)),
+        Operation::Multiply(a, b) => format!("{} * {}", worry(a), worry(b)),
+    };
+    let Test::DivisibleBy(divisor, (if_true, if_false)) = monkey.test;
+    let items: Vec<String> = monkey.items.iter().map(|Item(w)| w.to_string()).collect();
+
+    format!(
+        "Monkey {}:\n  Starting items: {}\n  Operation: new = {}\n  Test: divisible by {}\n    If true: throw to monkey {}\n    If false: throw to monkey {}",
+        monkey.id,
+        items.join(", "),
+        operation,
+        divisor,
+        if_true,
+        if_false
+    )
+}
+
+#[cfg(test)]
+mod test_generator {
+    use advent_of_code::generate::generate;
+
+    use super::*;
+    use crate::{input::Reader, part_one, part_two};
+
+    #[test]
+    fn test_notes_read_back() {
+        let input = generate(&InputGenerator, 30, 1);
+        let Reader(monkeys) = input.parse().unwrap();
+        assert_eq!(monkeys.iter().map(|m| m.items.len()).sum::<usize>(), 30);
+        assert!(monkeys.iter().all(
+            |m| matches!(m.test, Test::DivisibleBy(_, (t, f)) if t != m.id && f != m.id && t != f)
+        ));
+    }
+
+    #[test]
+    fn test_generated_inputs_are_solvable() {
+        for seed in 0..10 {
+            let input = generate(&InputGenerator, 30, seed);
+            assert!(part_one(&input).is_some(), "seed {seed}");
+            assert!(part_two(&input).is_some(), "seed {seed}");
+        }
+    }
+}
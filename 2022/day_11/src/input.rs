@@ -2,16 +2,15 @@
 
 use std::str::FromStr;
 
+use advent_of_code::helpers::parse::{blocks, parse_all, unsigned, IResult, ParseError};
 use nom::{
     branch::{alt, permutation},
     bytes::complete::tag,
-    character::complete::{digit1, multispace1, newline, space0, space1},
+    character::complete::{multispace1, newline, space0, space1},
     combinator::map,
-    error::{context, convert_error, VerboseError},
-    multi::{count, separated_list0, separated_list1},
-    number::complete::float,
+    error::context,
+    multi::separated_list0,
     sequence::{delimited, preceded, separated_pair, terminated, tuple},
-    Finish,
 };
 
 use crate::monkey::{Item, Monkey};
@@ -25,22 +24,17 @@ pub struct Reader(pub Vec<Monkey>);
 //
 
 impl FromStr for Reader {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match parse(s).finish() {
-            Ok((_, monkeys)) => Ok(Reader(monkeys)),
-            Err(e) => Err(convert_error(s, e)),
-        }
+        parse_all(parse, s).map(Reader)
     }
 }
 
 //
 
-type IResult<T, U> = nom::IResult<T, U, VerboseError<T>>;
-
 fn parse(input: &str) -> IResult<&str, Vec<Monkey>> {
-    context("notes", separated_list1(count(newline, 2), monkey))(input)
+    context("notes", blocks(monkey))(input)
 }
 
 fn monkey(input: &str) -> IResult<&str, Monkey> {
@@ -67,8 +61,8 @@ fn monkey_id(input: &str) -> IResult<&str, usize> {
     context(
         "monkey_id",
         map(
-            separated_pair(tag("Monkey"), space1, terminated(float, tag(":"))),
-            |(_, id)| id as usize,
+            separated_pair(tag("Monkey"), space1, terminated(unsigned, tag(":"))),
+            |(_, id)| id,
         ),
     )(input)
 }
@@ -78,10 +72,7 @@ fn starting_items(input: &str) -> IResult<&str, Vec<Item>> {
         "starting_items",
         preceded(
             terminated(tag("Starting items:"), space0),
-            separated_list0(
-                tag(", "),
-                map(digit1, |value| Item(u64::from_str(value).unwrap())),
-            ),
+            separated_list0(tag(", "), map(unsigned, Item)),
         ),
     )(input)
 }
@@ -110,10 +101,7 @@ fn operation(input: &str) -> IResult<&str, Operation> {
 fn worry_level(input: &str) -> IResult<&str, Worry> {
     context(
         "worry_level",
-        alt((
-            map(tag("old"), |_| Worry::Old),
-            map(digit1, |value| Worry::Level(u64::from_str(value).unwrap())),
-        )),
+        alt((map(tag("old"), |_| Worry::Old), map(unsigned, Worry::Level))),
     )(input)
 }
 
@@ -122,24 +110,18 @@ fn test(input: &str) -> IResult<&str, Test> {
         "test",
         map(
             permutation((
-                preceded(tag("Test: divisible by "), digit1),
+                preceded(tag("Test: divisible by "), unsigned),
                 preceded(
                     multispace1,
-                    preceded(tag("If true: throw to monkey "), digit1),
+                    preceded(tag("If true: throw to monkey "), unsigned),
                 ),
                 preceded(
                     multispace1,
-                    preceded(tag("If false: throw to monkey "), digit1),
+                    preceded(tag("If false: throw to monkey "), unsigned),
                 ),
             )),
             |(divisible_by, if_true_monkey_id, if_false_monkey_id)| {
-                Test::DivisibleBy(
-                    u64::from_str(divisible_by).unwrap(),
-                    (
-                        usize::from_str(if_true_monkey_id).unwrap(),
-                        usize::from_str(if_false_monkey_id).unwrap(),
-                    ),
-                )
+                Test::DivisibleBy(divisible_by, (if_true_monkey_id, if_false_monkey_id))
             },
         ),
     )(input)
@@ -150,6 +132,7 @@ mod test_notes_parse {
     type ITestResult = Result<(), VerboseError<&'static str>>;
 
     use super::*;
+    use nom::{error::VerboseError, Finish};
 
     #[test]
     fn test_parse() -> ITestResult {
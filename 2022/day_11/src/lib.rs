@@ -1,19 +1,28 @@
 //
 
+mod generator;
 mod input;
 mod monkey;
 mod round;
 
 //
 
+use advent_of_code::{
+    aoc_span,
+    helpers::{number::lcm_all, parse::report},
+};
 use monkey::Item;
 
 use crate::{input::Reader, round::Round};
 
+use advent_of_code::compare::Registry;
+
+pub use generator::InputGenerator;
+
 //
 
 pub fn part_one(input: &str) -> Option<u64> {
-    let note: Reader = input.parse().expect("Note parsing error");
+    let note: Reader = aoc_span!("parse", report("invalid notes", input.parse()))?;
     let Reader(monkeys) = note;
 
     fn human_worry(Item(worry): Item) -> Item {
@@ -30,19 +39,16 @@ pub fn part_one(input: &str) -> Option<u64> {
 }
 
 pub fn part_two(input: &str) -> Option<u64> {
-    let note: Reader = input.parse().expect("Note parsing error");
+    let note: Reader = aoc_span!("parse", report("invalid notes", input.parse()))?;
     let Reader(monkeys) = note;
 
-    let monkeys_divisor = monkeys
-        .iter()
-        .map(|monkey| match monkey.test {
-            monkey::Test::DivisibleBy(divisor, _) => divisor,
-        })
-        .product::<u64>();
-
-    let human_worry = move |Item(worry): Item| -> Item { Item(worry % monkeys_divisor) };
+    // Every test still sees the same remainders modulo a common multiple of the divisors
+    let monkeys_divisor = lcm_all(monkeys.iter().map(|monkey| match monkey.test {
+        monkey::Test::DivisibleBy(divisor, _) => divisor,
+    }))
+    .expect("divisors lcm overflows u64");
 
-    let mut round = Round::new(&monkeys, human_worry);
+    let mut round = Round::new(&monkeys, |item| item).modulo(monkeys_divisor);
 
     for _ in 0..10_000 {
         round.next();
@@ -50,3 +56,11 @@ pub fn part_two(input: &str) -> Option<u64> {
 
     Some(round.monkey_business())
 }
+
+/// Both parts, for `cargo compare` and `cargo minimize`.
+pub fn implementations() -> Registry {
+    Registry::new()
+        .parser(|input| input.parse::<Reader>().is_ok())
+        .register(1, "solution", part_one)
+        .register(2, "solution", part_two)
+}
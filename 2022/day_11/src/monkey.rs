@@ -1,5 +1,9 @@
 //
 
+use advent_of_code::helpers::number::mod_mul;
+
+//
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Monkey {
     pub id: usize,
@@ -37,10 +41,20 @@ pub enum Operation {
 }
 
 impl Operation {
-    pub fn calc(&self, old_value: WorryType) -> WorryType {
-        match self {
-            Operation::Add(left, right) => left.or(old_value) + right.or(old_value),
-            Operation::Multiply(left, right) => left.or(old_value) * right.or(old_value),
+    /// The new worry level, reduced modulo `modulus` when there is one so it never overflows.
+    pub fn calc(&self, old_value: WorryType, modulus: Option<WorryType>) -> WorryType {
+        let (left, right) = match self {
+            Operation::Add(left, right) | Operation::Multiply(left, right) => {
+                (left.or(old_value), right.or(old_value))
+            }
+        };
+        match (self, modulus) {
+            (Operation::Add(..), None) => left + right,
+            (Operation::Multiply(..), None) => left * right,
+            (Operation::Add(..), Some(modulus)) => {
+                ((left as u128 + right as u128) % modulus as u128) as WorryType
+            }
+            (Operation::Multiply(..), Some(modulus)) => mod_mul(left, right, modulus),
         }
     }
 }
@@ -52,25 +66,38 @@ mod test_operation {
     #[test]
     fn test_add_1_2() {
         let operation = Operation::Add(Worry::Level(1), Worry::Level(2));
-        assert_eq!(operation.calc(42), 3)
+        assert_eq!(operation.calc(42, None), 3)
     }
 
     #[test]
     fn test_add_old_1() {
         let operation = Operation::Add(Worry::Old, Worry::Level(1));
-        assert_eq!(operation.calc(42), 43)
+        assert_eq!(operation.calc(42, None), 43)
     }
 
     #[test]
     fn test_multiply_1_2() {
         let operation = Operation::Multiply(Worry::Level(1), Worry::Level(2));
-        assert_eq!(operation.calc(42), 2)
+        assert_eq!(operation.calc(42, None), 2)
     }
 
     #[test]
     fn test_multiply_old_2() {
         let operation = Operation::Multiply(Worry::Old, Worry::Level(2));
-        assert_eq!(operation.calc(42), 84)
+        assert_eq!(operation.calc(42, None), 84)
+    }
+
+    #[test]
+    fn test_modulus_above_u32() {
+        // lcm of three divisors above 2^16, so (modulus - 1)^2 doesn't fit in a u64
+        let modulus = 65_537 * 65_539 * 65_543;
+        let square = Operation::Multiply(Worry::Old, Worry::Old);
+        assert_eq!(square.calc(modulus - 1, Some(modulus)), 1);
+        let double = Operation::Add(Worry::Old, Worry::Old);
+        assert_eq!(double.calc(modulus - 1, Some(modulus)), modulus - 2);
+        let add = Operation::Add(Worry::Old, Worry::Level(u64::MAX));
+        let expected = ((1u128 << 64) % modulus as u128) as u64;
+        assert_eq!(add.calc(1, Some(modulus)), expected);
     }
 }
 //
@@ -84,7 +111,7 @@ impl Test {
     pub fn throw_index(&self, worry: u64) -> usize {
         match self {
             Test::DivisibleBy(divisor, (left, right)) => {
-                if worry % *divisor == 0 {
+                if worry.is_multiple_of(*divisor) {
                     *left
                 } else {
                     *right
@@ -113,7 +140,7 @@ mod test_test {
 
 //
 
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
 pub struct Item(pub WorryType);
 
 //
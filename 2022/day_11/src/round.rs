@@ -2,10 +2,17 @@
 
 use std::collections::HashMap;
 
+use advent_of_code::{aoc_span, trace};
+
 use crate::monkey::{Item, Monkey};
 
+/// Items held by each monkey, in monkey id order.
+#[cfg(test)]
+pub type Holdings = Vec<Vec<Item>>;
+
 pub struct Round {
     human_worry: Box<dyn Fn(Item) -> Item>,
+    modulus: Option<u64>,
     monkey_by_id: HashMap<usize, Monkey>,
     monkey_inspection_count: HashMap<usize, u64>,
 }
@@ -17,11 +24,18 @@ impl Round {
         let monkey_by_id = monkeys.iter().map(|m| (m.id, m.clone())).collect();
         Self {
             human_worry: Box::new(human_worry),
+            modulus: None,
             monkey_by_id,
             monkey_inspection_count: monkeys.iter().map(|m| (m.id, 0)).collect(),
         }
     }
 
+    /// Keep worry levels modulo `modulus`, which must be a multiple of every divisor.
+    pub fn modulo(mut self, modulus: u64) -> Self {
+        self.modulus = Some(modulus);
+        self
+    }
+
     pub fn monkey_business(&self) -> u64 {
         let mut monkey_inspection_count: Vec<u64> =
             self.monkey_inspection_count.values().copied().collect();
@@ -32,15 +46,39 @@ impl Round {
     }
 }
 
+/// Where the items are between rounds, for cycle detection, which the parts do not use.
+#[cfg(test)]
+impl Round {
+    pub fn holdings(&self) -> Holdings {
+        let mut monkeys: Vec<&Monkey> = self.monkey_by_id.values().collect();
+        monkeys.sort_unstable_by_key(|monkey| monkey.id);
+        monkeys
+            .into_iter()
+            .map(|monkey| monkey.items.clone())
+            .collect()
+    }
+
+    /// The holdings after each round, to feed `advent_of_code::helpers::cycle`.
+    pub fn holdings_per_round(mut self) -> impl Iterator<Item = Holdings> {
+        std::iter::from_fn(move || self.next().map(|()| self.holdings()))
+    }
+}
+
 //
 
 impl Iterator for Round {
     type Item = ();
 
     fn next(&mut self) -> Option<Self::Item> {
-        let Round { human_worry, .. } = self;
+        aoc_span!("round");
+        let Round {
+            human_worry,
+            modulus,
+            ..
+        } = self;
 
         for id in 0..self.monkey_by_id.len() {
+            aoc_span!("monkey turn");
             let monkey = self
                 .monkey_by_id
                 .get_mut(&id)
@@ -50,7 +88,7 @@ impl Iterator for Round {
                 .items
                 .drain(..)
                 .map(|item| {
-                    let item = Item(monkey.operation.calc(item.0));
+                    let item = Item(monkey.operation.calc(item.0, *modulus));
                     let item = human_worry(item);
                     let throw_to_monkey_id = monkey.test.throw_index(item.0);
                     (throw_to_monkey_id, item)
@@ -62,6 +100,12 @@ impl Iterator for Round {
                 .and_modify(|count| *count += throws_items.len() as u64);
 
             for (throw_index, item) in throws_items {
+                trace!(
+                    "monkey {} throws an item of worry {} to monkey {}",
+                    id,
+                    item.0,
+                    throw_index
+                );
                 self.monkey_by_id
                     .entry(throw_index)
                     .and_modify(|monkey| monkey.items.push(item));
@@ -90,6 +134,33 @@ mod test_round {
         assert_eq!(round.monkey_inspection_count.get(&3), Some(&5));
     }
 
+    #[test]
+    fn test_rounds_cycle() {
+        use advent_of_code::helpers::cycle::find_cycle_in;
+
+        // A single item bouncing between two monkeys: 1 -> 2 -> 4, 4 -> 0 -> 0, 0 -> 1 -> 2, 2 -> 3 -> 1
+        let monkeys = [
+            Monkey {
+                id: 0,
+                items: vec![Item(1)],
+                operation: Operation::Add(Worry::Old, Worry::Level(1)),
+                test: Test::DivisibleBy(2, (1, 1)),
+            },
+            Monkey {
+                id: 1,
+                items: vec![],
+                operation: Operation::Multiply(Worry::Old, Worry::Level(2)),
+                test: Test::DivisibleBy(3, (0, 0)),
+            },
+        ];
+        let rounds = || Round::new(&monkeys, |Item(worry)| Item(worry % 5)).holdings_per_round();
+
+        let cycle = find_cycle_in(rounds()).unwrap();
+        assert_eq!((cycle.start, cycle.length), (0, 4));
+        assert_eq!(cycle.nth_state(9_999), &rounds().nth(9_999).unwrap());
+        assert_eq!(cycle.nth_state(9_999), &vec![vec![Item(1)], vec![]]);
+    }
+
     //
 
     fn monkey_fixtures() -> Vec<Monkey> {
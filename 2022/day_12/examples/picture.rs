@@ -0,0 +1,3 @@
+fn main() {
+    advent_of_code::picture::main(puzzles::heightmap_image);
+}
@@ -1,7 +1,9 @@
 //
 
-use grid::Grid;
-use pathfinding::prelude::astar;
+use advent_of_code::{
+    aoc_span,
+    helpers::{grid::Grid, search::bfs},
+};
 
 use crate::position::Pos;
 pub struct ElevationPathFinder {
@@ -18,47 +20,54 @@ impl ElevationPathFinder {
     }
 
     pub fn shortest(&self, start: Pos, end: Pos) -> Option<Vec<Pos>> {
-        let is_in_grid = |Pos(x, y): &&Pos| self.elevation_grid.get(*x, *y).is_some();
-        let is_in_elevation_range = |current: Pos| {
-            move |p: &&Pos| {
-                let e = current.elevation(&self.elevation_grid).unwrap_or_default();
-                let range = 0..=e + 1;
-                range.contains(&p.elevation(&self.elevation_grid).unwrap_or_default())
-            }
-        };
-
-        let successors = |position: &Pos| -> Vec<(Pos, u32)> {
-            let Pos(row, col) = position.to_owned();
-
-            let possibilities = [
-                Pos(row, col + 1),
-                Pos(row + 1, col),
-                Pos(row, col.saturating_sub(1)),
-                Pos(row.saturating_sub(1), col),
-            ];
-            possibilities
-                .iter()
-                .filter(is_in_grid)
-                .filter(is_in_elevation_range(position.to_owned()))
-                .map(|p: &Pos| (p.to_owned(), 1))
-                .collect()
-        };
-
-        let result = astar(&start, successors, |p| p.distance(&end), |p| *p == end);
-
-        result.map(|(shortest_path, _)| shortest_path)
+        aoc_span!("shortest");
+        bfs(&start, |p| self.climbable_from(p), |p| *p == end)
+    }
+
+    /// Shortest path from any lowest square to `end`, searching downhill from `end`.
+    pub fn shortest_from_lowest(&self, end: Pos) -> Option<Vec<Pos>> {
+        aoc_span!("shortest_from_lowest");
+        let lowest = |p: &Pos| p.elevation(&self.elevation_grid) == Some(0);
+        let mut path = bfs(&end, |p| self.descendable_from(p), lowest)?;
+        path.reverse();
+        Some(path)
+    }
+
+    fn climbable_from(&self, position: &Pos) -> Vec<Pos> {
+        let elevation = self.elevation_of(position);
+        self.neighbours(position)
+            .filter(|next| self.elevation_of(next) <= elevation + 1)
+            .collect()
+    }
+
+    fn descendable_from(&self, position: &Pos) -> Vec<Pos> {
+        let elevation = self.elevation_of(position);
+        self.neighbours(position)
+            .filter(|next| self.elevation_of(next) + 1 >= elevation)
+            .collect()
+    }
+
+    fn neighbours(&self, &Pos(row, col): &Pos) -> impl Iterator<Item = Pos> + '_ {
+        self.elevation_grid
+            .neighbours4((col, row))
+            .map(|(col, row)| Pos(row, col))
+    }
+
+    fn elevation_of(&self, position: &Pos) -> u8 {
+        position.elevation(&self.elevation_grid).unwrap_or_default()
     }
 }
 
 //
 
 #[cfg(test)]
+#[allow(clippy::map_identity)]
 mod test_shortest {
     use super::*;
 
     #[test]
     fn test_shortest_same_start_end() {
-        let grid = Grid::from_vec(vec!['a'; 9], 3);
+        let grid = Grid::filled(3, 3, 'a');
         let pathfinder = ElevationPathFinder::new(grid);
         let path = pathfinder.shortest(Pos(1, 1), Pos(1, 1)).unwrap();
         assert_eq!(path, vec![Pos(1, 1)]);
@@ -66,12 +75,20 @@ mod test_shortest {
 
     #[test]
     fn test_shortest_zero_to_center() {
-        let grid = Grid::from_vec(vec!['a'; 9], 3);
+        let grid = Grid::filled(3, 3, 'a');
         let pathfinder = ElevationPathFinder::new(grid);
         let path = pathfinder.shortest(Pos(0, 0), Pos(1, 1)).unwrap();
         assert_eq!(path, vec![Pos(0, 0), Pos(0, 1), Pos(1, 1)]);
     }
 
+    #[test]
+    fn test_shortest_from_lowest() {
+        let grid = Grid::new(4, 1, vec!['a', 'b', 'c', 'a']);
+        let pathfinder = ElevationPathFinder::new(grid);
+        let path = pathfinder.shortest_from_lowest(Pos(0, 2)).unwrap();
+        assert_eq!(path, vec![Pos(0, 0), Pos(0, 1), Pos(0, 2)]);
+    }
+
     #[test]
     fn test_shortest_zero_to_bottom_right() {
         let range = std::iter::empty()
@@ -80,7 +97,7 @@ mod test_shortest {
             .chain('g'..='i')
             .map(|c| c)
             .collect();
-        let grid = Grid::from_vec(range, 3);
+        let grid = Grid::new(3, 3, range);
 
         let pathfinder = ElevationPathFinder::new(grid);
         let path = pathfinder.shortest(Pos(0, 0), Pos(2, 2)).unwrap();
@@ -0,0 +1,85 @@
+//
+
+use advent_of_code::{
+    generate::{Generator, Rng},
+    helpers::grid::Grid,
+};
+
+use crate::{elevation_path_finder::ElevationPathFinder, position::Pos};
+
+//
+
+/// A heightmap `size` squares wide (at least 26) where `E` can be reached from `S`.
+///
+/// Elevation rises by one square at a time towards `E`, so there is always a way
+/// up. Random bumps are then added, fewer each time they block every path.
+pub struct InputGenerator;
+
+impl Generator for InputGenerator {
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        let width = size.max(26);
+        let height = (width / 4).max(5);
+        // `E` leaves room for squares 25 steps away, down at `a`.
+        let end = (rng.below(width - 25), rng.below(height));
+        let base = Grid::new(
+            width,
+            height,
+            (0..width * height)
+                .map(|index| {
+                    let (x, y) = (index % width, index / width);
+                    25usize.saturating_sub(x.abs_diff(end.0) + y.abs_diff(end.1)) as u8
+                })
+                .collect(),
+        );
+        let lowest: Vec<(usize, usize)> = base
+            .positions()
+            .filter(|(_, &elevation)| elevation == 0)
+            .map(|(position, _)| position)
+            .collect();
+        let start = *rng.choose(&lowest);
+
+        let mut bumps = width * height / 4;
+        loop {
+            let mut elevations = base.clone();
+            for _ in 0..bumps {
+                let position = (rng.below(width), rng.below(height));
+                if position != start && position != end {
+                    let bumped = elevations[position] as usize + rng.range(1..=4);
+                    elevations[position] = bumped.min(24) as u8;
+                }
+            }
+            let mut heightmap = elevations.map(|&elevation| (b'a' + elevation) as char);
+            heightmap[start] = 'S';
+            heightmap[end] = 'E';
+
+            if bumps == 0 || reaches_end(&heightmap, start, end) {
+                return heightmap.display(|&c| c).to_string() + "\n";
+            }
+            bumps /= 2;
+        }
+    }
+}
+
+fn reaches_end(heightmap: &Grid<char>, (sx, sy): (usize, usize), (ex, ey): (usize, usize)) -> bool {
+    ElevationPathFinder::new(heightmap.clone())
+        .shortest(Pos(sy, sx), Pos(ey, ex))
+        .is_some()
+}
+
+#[cfg(test)]
+mod test_generator {
+    use advent_of_code::generate::generate;
+
+    use super::*;
+    use crate::{part_one, part_two};
+
+    #[test]
+    fn test_generated_inputs_are_solvable() {
+        for seed in 0..10 {
+            let input = generate(&InputGenerator, 40, seed);
+            assert_eq!(input.lines().next().map(str::len), Some(40));
+            let (from_start, from_lowest) = (part_one(&input).unwrap(), part_two(&input).unwrap());
+            assert!(from_lowest <= from_start && from_start >= 25, "seed {seed}");
+        }
+    }
+}
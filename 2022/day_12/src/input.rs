@@ -1,7 +1,7 @@
 //
 
-use grid::Grid;
-use std::{convert::Infallible, str::FromStr};
+use advent_of_code::helpers::{grid::Grid, parse::ParseError};
+use std::str::FromStr;
 
 use crate::position::Pos;
 
@@ -12,30 +12,22 @@ pub struct Input {
 }
 
 impl FromStr for Input {
-    type Err = Infallible;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let lines: Vec<_> = s.lines().collect();
-
-        let heightmap = Grid::from_vec(
-            lines
-                .iter()
-                .flat_map(|line| line.chars().collect::<Vec<_>>())
-                .collect(),
-            lines[0].len(),
-        );
+        let heightmap = Grid::parse(s, |c| {
+            (c.is_ascii_lowercase() || c == 'S' || c == 'E').then_some(c)
+        })?;
 
         let start = heightmap
-            .iter()
-            .position(|elevation| elevation == &'S')
-            .map(|index| Pos::from_grid_position(&heightmap, index))
-            .expect("Can't find start position");
+            .position(|&elevation| elevation == 'S')
+            .map(|(col, row)| Pos(row, col))
+            .ok_or_else(|| ParseError::new(s, s, "missing start position `S`"))?;
 
         let end = heightmap
-            .iter()
-            .position(|elevation| elevation == &'E')
-            .map(|index| Pos::from_grid_position(&heightmap, index))
-            .expect("Can't find end position");
+            .position(|&elevation| elevation == 'E')
+            .map(|(col, row)| Pos(row, col))
+            .ok_or_else(|| ParseError::new(s, s, "missing end position `E`"))?;
 
         Ok(Input {
             height_map: heightmap,
@@ -1,13 +1,20 @@
 //
 
 mod elevation_path_finder;
+mod generator;
 mod input;
+mod picture;
 mod position;
 
 //
 
+use advent_of_code::{aoc_span, compare::Registry, helpers::parse::report};
+
 use crate::{elevation_path_finder::ElevationPathFinder, input::Input, position::Pos};
 
+pub use generator::InputGenerator;
+pub use picture::heightmap_image;
+
 //
 
 pub fn part_one(input: &str) -> Option<usize> {
@@ -15,7 +22,7 @@ pub fn part_one(input: &str) -> Option<usize> {
         height_map,
         start,
         end,
-    } = input.parse().expect("Heightmap parsing error");
+    } = aoc_span!("parse", report("invalid heightmap", input.parse()))?;
 
     let pathfinder = ElevationPathFinder::new(height_map);
 
@@ -25,20 +32,57 @@ pub fn part_one(input: &str) -> Option<usize> {
 pub fn part_two(input: &str) -> Option<usize> {
     let Input {
         height_map, end, ..
-    } = input.parse().expect("Heightmap parsing error");
+    } = aoc_span!("parse", report("invalid heightmap", input.parse()))?;
 
-    let starting_points = height_map
-        .iter()
-        .enumerate()
-        .filter_map(|(index, elevation)| match elevation {
-            'S' | 'a' => Some(Pos::from_grid_position(&height_map, index)),
-            _ => None,
-        });
+    let pathfinder = ElevationPathFinder::new(height_map);
 
-    let pathfinder = ElevationPathFinder::new(height_map.clone());
+    pathfinder.shortest_from_lowest(end).map(|p| p.len() - 1)
+}
 
-    starting_points
-        .flat_map(|start| pathfinder.shortest(start, end.clone()))
-        .min_by(|a, b| a.len().cmp(&b.len()))
+/// Every implementation of both parts, the naive one first.
+pub fn implementations() -> Registry {
+    Registry::new()
+        .parser(|input| input.parse::<Input>().is_ok())
+        .register(1, "bfs", part_one)
+        .register(2, "every_start", part_two_from_every_start)
+        .register(2, "downhill", part_two)
+}
+
+//
+
+/// Climbs from every lowest square in turn.
+fn part_two_from_every_start(input: &str) -> Option<usize> {
+    let Input {
+        height_map, end, ..
+    } = aoc_span!("parse", report("invalid heightmap", input.parse()))?;
+
+    let lowest: Vec<Pos> = height_map
+        .positions()
+        .filter(|(_, &elevation)| elevation == 'a' || elevation == 'S')
+        .map(|((col, row), _)| Pos(row, col))
+        .collect();
+    let pathfinder = ElevationPathFinder::new(height_map);
+
+    lowest
+        .into_iter()
+        .filter_map(|start| pathfinder.shortest(start, end.clone()))
         .map(|p| p.len() - 1)
+        .min()
+}
+
+#[cfg(test)]
+mod test_implementations {
+    use advent_of_code::compare::{generated, Input};
+
+    use super::*;
+
+    #[test]
+    fn test_implementations_agree() {
+        let example = Input::new("example.txt", advent_of_code::read_example());
+        let inputs = std::iter::once(example).chain(generated(&InputGenerator, 30, 10));
+        assert_eq!(
+            implementations().check(inputs).map_err(|d| d.to_string()),
+            Ok(11)
+        );
+    }
 }
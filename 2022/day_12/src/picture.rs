@@ -0,0 +1,50 @@
+//
+
+use advent_of_code::helpers::{
+    image::{gradient, Image, Rgb},
+    parse::report,
+};
+
+use crate::{
+    elevation_path_finder::ElevationPathFinder,
+    input::Input,
+    position::{char_to_elevation, Pos},
+};
+
+const PATH: Rgb = [220, 50, 47];
+
+//
+
+/// The heightmap, higher squares in darker blue, with the shortest path from `S` to `E` in red.
+pub fn heightmap_image(input: &str) -> Option<Image> {
+    let Input {
+        height_map,
+        start,
+        end,
+    } = report("invalid heightmap", input.parse())?;
+
+    let mut image = Image::from_grid(&height_map, |&c| {
+        gradient(char_to_elevation(c) as usize, 25)
+    });
+
+    let pathfinder = ElevationPathFinder::new(height_map);
+    for Pos(row, col) in pathfinder.shortest(start, end).unwrap_or_default() {
+        image.set_pixel(col, row, PATH);
+    }
+
+    Some(image)
+}
+
+#[cfg(test)]
+mod test_heightmap_image {
+    use super::*;
+
+    #[test]
+    fn test_path_is_drawn() {
+        let climb: String = ('b'..='y').collect();
+        let image = heightmap_image(&format!("S{climb}E\n{}", "a".repeat(26))).unwrap();
+        assert_eq!((image.width(), image.height()), (26, 2));
+        assert!((0..26).all(|x| image.pixel(x, 0) == PATH));
+        assert_eq!(image.pixel(0, 1), gradient(0, 25));
+    }
+}
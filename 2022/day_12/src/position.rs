@@ -1,27 +1,18 @@
 //
 
-use grid::Grid;
+use advent_of_code::helpers::grid::Grid;
 
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct Pos(pub usize, pub usize);
 
 impl Pos {
-    pub fn from_grid_position<T>(grid: &Grid<T>, index: usize) -> Self {
-        let grid_cols = grid.cols();
-        Pos(index / grid_cols, index % grid_cols)
-    }
-
     pub fn elevation(&self, grid: &Grid<char>) -> Option<u8> {
         let Self(row, col) = *self;
-        grid.get(row, col).map(|c| char_to_elevation(*c))
-    }
-
-    pub fn distance(&self, other: &Pos) -> u32 {
-        (self.0.abs_diff(other.0) + self.1.abs_diff(other.1)) as u32
+        grid.get_rc(row, col).map(|c| char_to_elevation(*c))
     }
 }
 
-fn char_to_elevation(elevation_char: char) -> u8 {
+pub fn char_to_elevation(elevation_char: char) -> u8 {
     const TO_DIGIT_A: u8 = 10; // 'a'.to_digit(36).unwrap()
     const TO_DIGIT_Z: u8 = 35; // 'z'.to_digit(36).unwrap()
 
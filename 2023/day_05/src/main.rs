@@ -2,6 +2,9 @@
 
 fn main() {
     use puzzles::{part_one, part_two};
+    if advent_of_code::solve::all_inputs_requested() {
+        return advent_of_code::solve::all_inputs(part_one, part_two);
+    }
     let input = &advent_of_code::read_input();
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
@@ -0,0 +1,245 @@
+//! Allocation counters, kept by a counting global allocator installed with the `alloc-stats` feature.
+//!
+//! Without the feature nothing is counted and `stats` returns `None`, so callers can always
+//! `reset` before a part and print the stats after it.
+
+use std::fmt::Display;
+
+/// Allocations made since the last `reset`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    pub allocations: usize,
+    /// Total of the sizes requested, a grown `Vec` counts each of its buffers.
+    pub bytes: usize,
+    /// Most bytes live at once, above what was live at the `reset`.
+    pub peak: usize,
+}
+
+impl AllocStats {
+    /// Stats of running `self` then `next`: counts add up, the peak is the highest.
+    pub fn followed_by(self, next: AllocStats) -> AllocStats {
+        AllocStats {
+            allocations: self.allocations + next.allocations,
+            bytes: self.bytes + next.bytes,
+            peak: self.peak.max(next.peak),
+        }
+    }
+}
+
+#[cfg(feature = "alloc-stats")]
+mod counting {
+    use std::{
+        alloc::{GlobalAlloc, Layout, System},
+        sync::atomic::{AtomicUsize, Ordering::Relaxed},
+    };
+
+    pub static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+    pub static BYTES: AtomicUsize = AtomicUsize::new(0);
+    pub static LIVE: AtomicUsize = AtomicUsize::new(0);
+    pub static PEAK: AtomicUsize = AtomicUsize::new(0);
+    /// Bytes live at the last reset.
+    pub static BASELINE: AtomicUsize = AtomicUsize::new(0);
+
+    struct CountingAllocator;
+
+    fn record_alloc(size: usize) {
+        ALLOCATIONS.fetch_add(1, Relaxed);
+        BYTES.fetch_add(size, Relaxed);
+        let live = LIVE.fetch_add(size, Relaxed) + size;
+        PEAK.fetch_max(live, Relaxed);
+    }
+
+    fn record_dealloc(size: usize) {
+        LIVE.fetch_sub(size, Relaxed);
+    }
+
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc(layout);
+            if !ptr.is_null() {
+                record_alloc(layout.size());
+            }
+            ptr
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc_zeroed(layout);
+            if !ptr.is_null() {
+                record_alloc(layout.size());
+            }
+            ptr
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout);
+            record_dealloc(layout.size());
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            let new_ptr = System.realloc(ptr, layout, new_size);
+            if !new_ptr.is_null() {
+                record_dealloc(layout.size());
+                record_alloc(new_size);
+            }
+            new_ptr
+        }
+    }
+
+    #[global_allocator]
+    static GLOBAL: CountingAllocator = CountingAllocator;
+}
+
+/// Whether allocations are counted, i.e. the `alloc-stats` feature is on.
+pub fn enabled() -> bool {
+    cfg!(feature = "alloc-stats")
+}
+
+/// Count from zero again, measuring the peak from the bytes live now.
+pub fn reset() {
+    #[cfg(feature = "alloc-stats")]
+    {
+        use counting::*;
+        use std::sync::atomic::Ordering::Relaxed;
+
+        let live = LIVE.load(Relaxed);
+        ALLOCATIONS.store(0, Relaxed);
+        BYTES.store(0, Relaxed);
+        BASELINE.store(live, Relaxed);
+        PEAK.store(live, Relaxed);
+    }
+}
+
+/// Allocations since the last `reset`, `None` when they are not counted.
+pub fn stats() -> Option<AllocStats> {
+    #[cfg(feature = "alloc-stats")]
+    {
+        use counting::*;
+        use std::sync::atomic::Ordering::Relaxed;
+
+        Some(AllocStats {
+            allocations: ALLOCATIONS.load(Relaxed),
+            bytes: BYTES.load(Relaxed),
+            peak: PEAK.load(Relaxed).saturating_sub(BASELINE.load(Relaxed)),
+        })
+    }
+    #[cfg(not(feature = "alloc-stats"))]
+    None
+}
+
+/// Stats of running `f` once.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<AllocStats>) {
+    reset();
+    let result = f();
+    (result, stats())
+}
+
+//
+
+const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+
+/// `1536` as `1.50 KiB`.
+pub fn format_bytes(bytes: usize) -> String {
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} B", bytes)
+    } else {
+        format!("{:.2} {}", value, UNITS[unit])
+    }
+}
+
+/// Bytes written by `format_bytes`, rounded.
+pub fn parse_bytes(text: &str) -> Option<usize> {
+    let (value, unit) = text.trim().split_once(' ')?;
+    let power = UNITS.iter().position(|&u| u == unit)?;
+    let value: f64 = value.parse().ok()?;
+    Some((value * 1024_f64.powi(power as i32)).round() as usize)
+}
+
+impl Display for AllocStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "allocs: {}, allocated: {}, peak: {}",
+            self.allocations,
+            format_bytes(self.bytes),
+            format_bytes(self.peak)
+        )
+    }
+}
+
+/// Stats printed by `solve!`, summed over the parts found in `output`.
+pub fn parse_alloc_stats(output: &str) -> Option<AllocStats> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let line = line.trim().strip_prefix("allocs: ")?;
+            let mut fields = line.split(", ");
+            let allocations = fields.next()?.parse().ok()?;
+            let bytes = parse_bytes(fields.next()?.strip_prefix("allocated: ")?)?;
+            let peak = parse_bytes(fields.next()?.strip_prefix("peak: ")?)?;
+            Some(AllocStats {
+                allocations,
+                bytes,
+                peak,
+            })
+        })
+        .reduce(AllocStats::followed_by)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(800), "800 B");
+        assert_eq!(format_bytes(1536), "1.50 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.00 MiB");
+        assert_eq!(parse_bytes("1.50 KiB"), Some(1536));
+        assert_eq!(parse_bytes("800 B"), Some(800));
+    }
+
+    #[test]
+    fn test_parse_alloc_stats() {
+        let stats = |allocations, bytes, peak| AllocStats {
+            allocations,
+            bytes,
+            peak,
+        };
+        let output = format!(
+            "🎄 Part 1 🎄\n21 (elapsed: 35.20µs)\n  {}\n🎄 Part 2 🎄\n8 (elapsed: 1.2ms)\n  {}\n",
+            stats(10, 1536, 1024),
+            stats(2, 100, 2048)
+        );
+        assert_eq!(parse_alloc_stats(&output), Some(stats(12, 1636, 2048)));
+        assert_eq!(parse_alloc_stats("21 (elapsed: 35.20µs)"), None);
+    }
+
+    #[cfg(not(feature = "alloc-stats"))]
+    #[test]
+    fn test_not_counted_without_the_feature() {
+        assert!(!enabled());
+        assert_eq!(measure(|| vec![0u8; 64]).1, None);
+    }
+
+    #[cfg(feature = "alloc-stats")]
+    #[test]
+    fn test_counts_allocations() {
+        // Other tests allocate concurrently, only lower bounds hold.
+        let (buffer, stats) = measure(|| {
+            let mut buffer = Vec::with_capacity(4096);
+            buffer.extend(0..4096u32);
+            buffer
+        });
+        let stats = stats.unwrap();
+        assert!(stats.allocations >= 1);
+        assert!(stats.bytes >= 4 * 4096);
+        assert!(stats.peak >= 4 * 4096);
+        drop(buffer);
+    }
+}
@@ -0,0 +1,56 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::process;
+
+struct Args {
+    year: u16,
+    day: u8,
+    alloc_stats: bool,
+    forwarded: Vec<String>,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let mut forwarded = Vec::new();
+    if args.contains("--bench") {
+        forwarded.push("--bench".to_string());
+    }
+    for option in ["--size", "--generated", "--runs"] {
+        if let Some(value) = args.opt_value_from_str::<_, usize>(option)? {
+            forwarded.extend([option.to_string(), value.to_string()]);
+        }
+    }
+    Ok(Args {
+        alloc_stats: args.contains("--alloc-stats"),
+        year: args.free_from_str()?,
+        day: args.free_from_str()?,
+        forwarded,
+    })
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let features: &[&str] = if args.alloc_stats {
+        &[advent_of_code::ALLOC_STATS_FEATURE]
+    } else {
+        &[]
+    };
+    match advent_of_code::run_day_example(args.year, args.day, "compare", features, &args.forwarded)
+    {
+        Ok(status) if status.success() => {}
+        Ok(_) => process::exit(1),
+        Err(e) => {
+            eprintln!("failed to spawn cargo: {}", e);
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,66 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{
+    process,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+struct Args {
+    year: u16,
+    day: u8,
+    size: usize,
+    seed: Option<u64>,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        size: args.opt_value_from_str("--size")?.unwrap_or(100),
+        seed: args.opt_value_from_str("--seed")?,
+        year: args.free_from_str()?,
+        day: args.free_from_str()?,
+    })
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let seed = args.seed.unwrap_or_else(|| {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+        now.as_nanos() as u64
+    });
+    // The input goes to stdout, so the seed to reproduce it goes to stderr.
+    eprintln!("seed: {}", seed);
+
+    let generator_args = [
+        "--size".to_string(),
+        args.size.to_string(),
+        "--seed".to_string(),
+        seed.to_string(),
+    ];
+    let status =
+        advent_of_code::run_day_example(args.year, args.day, "generate", &[], &generator_args);
+
+    match status {
+        Ok(status) if status.success() => {}
+        Ok(_) => {
+            eprintln!(
+                "no generator for {} day {}, or it failed",
+                args.year, args.day
+            );
+            process::exit(1);
+        }
+        Err(e) => {
+            eprintln!("failed to spawn cargo: {}", e);
+            process::exit(1);
+        }
+    }
+}
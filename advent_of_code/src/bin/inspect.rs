@@ -0,0 +1,54 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{env, fs, path::PathBuf, process};
+
+use advent_of_code::{inspect, status};
+
+struct Args {
+    year: u16,
+    day: u8,
+    example: bool,
+    file: Option<PathBuf>,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        example: args.contains("--example"),
+        file: args.opt_value_from_str("--file")?,
+        year: args.free_from_str()?,
+        day: args.free_from_str()?,
+    })
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let root = env::current_dir().unwrap();
+    let path = status::day_path(&root, args.year, args.day);
+    let file = args.file.unwrap_or_else(|| {
+        path.join(if args.example {
+            "example.txt"
+        } else {
+            "input.txt"
+        })
+    });
+    let input = match fs::read_to_string(&file) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("Could not read {}: {}", file.display(), e);
+            process::exit(1);
+        }
+    };
+
+    println!("{}", file.display());
+    print!("{}", inspect::inspect(&input));
+}
@@ -0,0 +1,69 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{path::PathBuf, process};
+
+use advent_of_code::{
+    helpers::LATEST_AOC_YEAR,
+    leaderboard::{self, Leaderboard},
+};
+
+struct Args {
+    id: Option<u64>,
+    year: Option<u16>,
+    file: Option<PathBuf>,
+    day: Option<u8>,
+    me: Option<u64>,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        year: args.opt_value_from_str(["-y", "--year"])?,
+        file: args.opt_value_from_str(["-f", "--file"])?,
+        day: args.opt_value_from_str(["-d", "--day"])?,
+        me: args.opt_value_from_str("--me")?,
+        id: args.opt_free_from_str()?,
+    })
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let me = args
+        .me
+        .or_else(|| std::env::var("AOC_USER_ID").ok()?.parse().ok());
+
+    let loaded = match (&args.file, args.id) {
+        (Some(file), _) => Leaderboard::from_file(file),
+        (None, Some(id)) => Leaderboard::fetch(
+            &leaderboard::base_url(),
+            args.year.unwrap_or(LATEST_AOC_YEAR),
+            id,
+        ),
+        (None, None) => {
+            eprintln!("Either a leaderboard id or `--file <path>` is required.");
+            process::exit(1);
+        }
+    };
+
+    let board = match loaded {
+        Ok(board) => board,
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    };
+
+    match args.day {
+        Some(day) => println!("{}", leaderboard::render_day(&board, day, me)),
+        None => println!("{}", leaderboard::render_ranking(&board, me)),
+    }
+}
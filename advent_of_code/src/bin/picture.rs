@@ -0,0 +1,56 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::process;
+
+struct Args {
+    year: u16,
+    day: u8,
+    forwarded: Vec<String>,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let mut forwarded = Vec::new();
+    if args.contains("--example") {
+        forwarded.push("--example".to_string());
+    }
+    for option in ["--input", "--scale"] {
+        if let Some(value) = args.opt_value_from_str::<_, String>(option)? {
+            forwarded.extend([option.to_string(), value]);
+        }
+    }
+    let year = args.free_from_str()?;
+    let day = args.free_from_str()?;
+    // Relative to where `cargo picture` runs, not to the day's directory.
+    let output: std::path::PathBuf = args.free_from_str()?;
+    let output = std::env::current_dir()
+        .map(|cwd| cwd.join(&output))
+        .unwrap_or(output);
+    forwarded.push(output.to_string_lossy().into_owned());
+    Ok(Args {
+        year,
+        day,
+        forwarded,
+    })
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    match advent_of_code::run_day_example(args.year, args.day, "picture", &[], &args.forwarded) {
+        Ok(status) if status.success() => {}
+        Ok(_) => process::exit(1),
+        Err(e) => {
+            eprintln!("failed to spawn cargo: {}", e);
+            process::exit(1);
+        }
+    }
+}
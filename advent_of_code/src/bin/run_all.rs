@@ -2,47 +2,227 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-use std::process::Command;
+use advent_of_code::{
+    alloc_stats::{self, AllocStats},
+    sandbox::{self, Exit, Limits},
+    status, ANSI_BOLD, ANSI_DIM, ANSI_ITALIC, ANSI_RED, ANSI_RESET,
+};
+use std::{
+    env,
+    path::{Path, PathBuf},
+    process,
+    process::Command,
+    str::FromStr,
+    time::Duration,
+};
 
-fn main() {
-    let total: f64 = (1..=25)
-        .map(|day| {
-            let day = format!("{:02}", day);
+/// What to sort the days by, the largest first.
+#[derive(Clone, Copy, PartialEq)]
+enum SortKey {
+    Time,
+    Allocations,
+    Bytes,
+    Peak,
+}
 
-            let mut args = vec!["run", "--bin", &day];
-            if cfg!(not(debug_assertions)) {
-                args.push("--release");
-            }
+impl FromStr for SortKey {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "time" => Ok(SortKey::Time),
+            "allocs" => Ok(SortKey::Allocations),
+            "bytes" => Ok(SortKey::Bytes),
+            "peak" => Ok(SortKey::Peak),
+            _ => Err(format!(
+                "unknown sort key `{s}`, expected time, allocs, bytes or peak"
+            )),
+        }
+    }
+}
 
-            let cmd = Command::new("cargo").args(&args).output().unwrap();
+struct Args {
+    sort: Option<SortKey>,
+    alloc_stats: bool,
+    limits: Limits,
+}
 
-            println!("----------");
-            println!("{}| Day {} |{}", ANSI_BOLD, day, ANSI_RESET);
-            println!("----------");
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let sort: Option<SortKey> = args.opt_value_from_str("--sort")?;
+    let cpu: Option<u64> = args.opt_value_from_str("--cpu")?;
+    let memory: Option<u64> = args.opt_value_from_str("--memory")?;
+    let timeout: u64 = args.opt_value_from_str("--timeout")?.unwrap_or(60);
+    Ok(Args {
+        limits: Limits {
+            cpu: cpu.map(Duration::from_secs),
+            memory: memory.map(|mib| mib * 1024 * 1024),
+            // A timeout of 0 disables it.
+            timeout: (timeout > 0).then(|| Duration::from_secs(timeout)),
+        },
+        // Sorting by allocations needs them counted.
+        alloc_stats: args.contains("--alloc-stats") || sort.is_some_and(|key| key != SortKey::Time),
+        sort,
+    })
+}
 
-            let output = String::from_utf8(cmd.stdout).unwrap();
-            let is_empty = output.is_empty();
+enum Status {
+    BuildFailed,
+    NoInput,
+    Ran(Exit),
+}
 
-            println!(
-                "{}",
-                if is_empty {
-                    "Not solved."
-                } else {
-                    output.trim()
-                }
-            );
+impl Status {
+    fn is_failure(&self) -> bool {
+        !matches!(self, Status::NoInput | Status::Ran(Exit::Success))
+    }
+}
+
+struct DayRun {
+    label: String,
+    output: String,
+    time: f64,
+    allocations: Option<AllocStats>,
+    status: Status,
+    elapsed: Duration,
+}
+
+impl DayRun {
+    fn key(&self, key: SortKey) -> f64 {
+        let allocations = self.allocations.unwrap_or_default();
+        match key {
+            SortKey::Time => self.time,
+            SortKey::Allocations => allocations.allocations as f64,
+            SortKey::Bytes => allocations.bytes as f64,
+            SortKey::Peak => allocations.peak as f64,
+        }
+    }
+}
+
+fn target_dir(root: &Path) -> PathBuf {
+    env::var_os("CARGO_TARGET_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| root.join("target"))
+}
 
-            if is_empty {
-                0_f64
+/// Build the day, then run its binary on its own, as the limits would apply to cargo otherwise.
+fn run_day(root: &Path, year: u16, day: u8, args: &Args) -> DayRun {
+    let package = status::package_name(year, day);
+    let label = format!("{} Day {:02}", year, day);
+
+    let mut build = vec!["build", "--quiet", "-p", &package];
+    if cfg!(not(debug_assertions)) {
+        build.push("--release");
+    }
+    if args.alloc_stats {
+        build.extend(["--features", advent_of_code::ALLOC_STATS_FEATURE]);
+    }
+    let built = Command::new("cargo").args(&build).status().unwrap();
+    let path = status::day_path(root, year, day);
+    let skipped = if !built.success() {
+        Some(Status::BuildFailed)
+    } else if !path.join("input.txt").is_file() {
+        Some(Status::NoInput)
+    } else {
+        None
+    };
+    if let Some(status) = skipped {
+        return DayRun {
+            label,
+            output: String::new(),
+            time: 0.0,
+            allocations: None,
+            status,
+            elapsed: Duration::ZERO,
+        };
+    }
+
+    let profile = if cfg!(debug_assertions) {
+        "debug"
+    } else {
+        "release"
+    };
+    let mut command = Command::new(target_dir(root).join(profile).join(&package));
+    command.env("CARGO_MANIFEST_DIR", path);
+    let outcome = sandbox::run(command, &args.limits).unwrap();
+    if outcome.exit != Exit::Success {
+        eprint!("{}", outcome.stderr);
+    }
+
+    DayRun {
+        label,
+        time: advent_of_code::parse_exec_time(&outcome.stdout),
+        allocations: alloc_stats::parse_alloc_stats(&outcome.stdout),
+        output: outcome.stdout,
+        status: Status::Ran(outcome.exit),
+        elapsed: outcome.elapsed,
+    }
+}
+
+/// How every day ended, failures in red.
+fn print_summary(runs: &[DayRun]) {
+    println!();
+    println!(
+        "{}{:<12} {:<16} {:>10}{}",
+        ANSI_BOLD, "Day", "Exit", "Wall time", ANSI_RESET
+    );
+    for run in runs {
+        let (exit, color) = match run.status {
+            Status::BuildFailed => ("build failed".to_string(), ANSI_RED),
+            Status::NoInput => ("no input".to_string(), ANSI_DIM),
+            Status::Ran(exit) if exit == Exit::Success => (exit.label(), ""),
+            Status::Ran(exit) => (exit.label(), ANSI_RED),
+        };
+        println!(
+            "{:<12} {}{:<16}{} {:>10.2?}",
+            run.label, color, exit, ANSI_RESET, run.elapsed
+        );
+    }
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let root = env::current_dir().unwrap();
+    let mut runs: Vec<DayRun> = status::scaffolded_days(&root)
+        .into_iter()
+        .map(|(year, day)| run_day(&root, year, day, &args))
+        .collect();
+
+    if let Some(key) = args.sort {
+        runs.sort_by(|a, b| b.key(key).total_cmp(&a.key(key)));
+    }
+
+    for run in &runs {
+        advent_of_code::solve::print_day_header(&run.label);
+        println!(
+            "{}",
+            if run.output.is_empty() {
+                "Not solved."
             } else {
-                advent_of_code::parse_exec_time(&output)
+                run.output.trim()
             }
-        })
-        .sum();
+        );
+    }
+
+    print_summary(&runs);
 
+    let total: f64 = runs.iter().map(|run| run.time).sum();
     println!(
         "{}Total:{} {}{:.2}ms{}",
         ANSI_BOLD, ANSI_RESET, ANSI_ITALIC, total, ANSI_RESET
     );
+    let allocations = runs.iter().filter_map(|run| run.allocations);
+    if let Some(allocations) = allocations.reduce(AllocStats::followed_by) {
+        println!("{}Total:{} {}", ANSI_BOLD, ANSI_RESET, allocations);
+    }
+    if runs.iter().any(|run| run.status.is_failure()) {
+        process::exit(1);
+    }
 }
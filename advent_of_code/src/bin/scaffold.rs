@@ -51,6 +51,26 @@ fn replace_module_name(path: &str, name: &str) {
     }
 }
 
+/// List the day in the dependencies of `aoc_all`, so that `aoc-all` runs it too.
+fn add_to_aoc_all(year: &str, day: u8) {
+    let path = "aoc_all/Cargo.toml";
+    let Ok(manifest) = std::fs::read_to_string(path) else {
+        return;
+    };
+    let dependency = format!(
+        "y{year}_d{day:02} = {{ package = \"day_{year}_{day:02}\", path = \"../{year}/day_{day:02}\" }}\n"
+    );
+    let manifest = manifest.replacen(
+        "\n[build-dependencies]",
+        &format!("{dependency}\n[build-dependencies]"),
+        1,
+    );
+    match std::fs::write(path, manifest) {
+        Ok(()) => println!("Edited {path}"),
+        Err(_e) => eprintln!("Failed to edit : {path}"),
+    }
+}
+
 fn main() {
     let args = match parse_args() {
         Ok(args) => args,
@@ -64,7 +84,7 @@ fn main() {
     let day = format!("day_{:02}", args.day);
     let module_name = format!("day_{}_{:02}", year, args.day);
 
-    let cwd = PathBuf::from(year);
+    let cwd = PathBuf::from(&year);
     let module_path = cwd.join(day);
 
     //
@@ -106,6 +126,10 @@ fn main() {
 
     //
 
+    add_to_aoc_all(&year, args.day);
+
+    //
+
     println!("Created workspace \"{}\"", &module_path.to_string_lossy());
 
     println!("---");
@@ -0,0 +1,54 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{env, process};
+
+use advent_of_code::status::{self, YearStatus};
+
+struct Args {
+    year: Option<u16>,
+    json: bool,
+    skip_run: bool,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        year: args.opt_value_from_str(["-y", "--year"])?,
+        json: args.contains("--json"),
+        skip_run: args.contains("--skip-run"),
+    })
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let root = env::current_dir().unwrap();
+    let years = match args.year {
+        Some(year) => vec![year],
+        None => status::find_years(&root),
+    };
+
+    let statuses: Vec<YearStatus> = years
+        .into_iter()
+        .map(|year| status::scan_year(&root, year, !args.skip_run))
+        .collect();
+
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&statuses).unwrap());
+        return;
+    }
+
+    for year in &statuses {
+        println!("{}", year.render());
+        println!();
+    }
+    println!("{}", status::legend());
+}
@@ -0,0 +1,122 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{
+    env,
+    path::Path,
+    process::{self, Command},
+    thread,
+    time::Duration,
+};
+
+use advent_of_code::{
+    status,
+    watch::{self, PartResult, Snapshot},
+    ANSI_BOLD, ANSI_DIM, ANSI_GREEN, ANSI_RED, ANSI_RESET,
+};
+
+struct Args {
+    year: u16,
+    day: u8,
+    interval: u64,
+    debounce: u64,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        interval: args.opt_value_from_str("--interval")?.unwrap_or(500),
+        debounce: args.opt_value_from_str("--debounce")?.unwrap_or(200),
+        year: args.free_from_str()?,
+        day: args.free_from_str()?,
+    })
+}
+
+/// Run the example tests, then the real input when they pass.
+fn run(package: &str, path: &Path, previous: Option<&[PartResult]>) -> Option<Vec<PartResult>> {
+    let tests = Command::new("cargo")
+        .args(["test", "--quiet", "-p", package])
+        .output()
+        .unwrap();
+    if !tests.status.success() {
+        print!("{}", String::from_utf8_lossy(&tests.stdout));
+        eprint!("{}", String::from_utf8_lossy(&tests.stderr));
+        println!("{}✗ tests fail{}", ANSI_RED, ANSI_RESET);
+        return None;
+    }
+    println!("{}✓ tests pass{}", ANSI_GREEN, ANSI_RESET);
+
+    if !path.join("input.txt").is_file() {
+        println!("{}no input.txt to run{}", ANSI_DIM, ANSI_RESET);
+        return None;
+    }
+    let solve = Command::new("cargo")
+        .args(["run", "--quiet", "--release", "-p", package])
+        .output()
+        .unwrap();
+    if !solve.status.success() {
+        eprint!("{}", String::from_utf8_lossy(&solve.stderr));
+        println!("{}✗ input.txt fails{}", ANSI_RED, ANSI_RESET);
+        return None;
+    }
+    let results = watch::parse_results(&String::from_utf8_lossy(&solve.stdout));
+    for line in watch::diff(previous, &results) {
+        println!("{}", line);
+    }
+    Some(results)
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let root = env::current_dir().unwrap();
+    let path = status::day_path(&root, args.year, args.day);
+    if !path.join("Cargo.toml").is_file() {
+        eprintln!("{} is not scaffolded", path.display());
+        process::exit(1);
+    }
+    let package = status::package_name(args.year, args.day);
+
+    let mut seen = Snapshot::new();
+    let mut previous: Option<Vec<PartResult>> = None;
+    loop {
+        let current = watch::snapshot(&path);
+        if current != seen {
+            let current = watch::settle(&path, current, Duration::from_millis(args.debounce));
+            let changed = watch::changes(&seen, &current);
+            if !seen.is_empty() {
+                let names: Vec<String> = changed
+                    .iter()
+                    .map(|file| {
+                        file.strip_prefix(&path)
+                            .unwrap_or(file)
+                            .display()
+                            .to_string()
+                    })
+                    .collect();
+                println!();
+                println!("{}changed: {}{}", ANSI_BOLD, names.join(", "), ANSI_RESET);
+            }
+            seen = current;
+
+            // A failing run keeps the last answers, to compare the next one against them.
+            if let Some(results) = run(&package, &path, previous.as_deref()) {
+                previous = Some(results);
+            }
+            println!(
+                "{}watching {} for changes...{}",
+                ANSI_DIM,
+                path.display(),
+                ANSI_RESET
+            );
+        }
+        thread::sleep(Duration::from_millis(args.interval));
+    }
+}
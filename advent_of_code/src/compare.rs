@@ -0,0 +1,416 @@
+//! Run several implementations of a part on the same inputs.
+//!
+//! An optimized solution keeps its naive version registered next to it as an
+//! oracle: `check` reports the first input on which they disagree, and `bench`
+//! times them side by side. Parsers can be registered too, to be timed on their own.
+
+use std::{
+    env,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+    process,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    alloc_stats::{self, AllocStats},
+    generate::{self, Generator},
+    solve::{self, Verdict},
+    ANSI_BOLD, ANSI_GREEN, ANSI_ITALIC, ANSI_RED, ANSI_RESET,
+};
+
+type Solver = Box<dyn Fn(&str) -> Option<String>>;
+type Parser = Box<dyn Fn(&str) -> bool>;
+
+pub struct Implementation {
+    pub name: &'static str,
+    solver: Solver,
+}
+
+impl Implementation {
+    pub fn solve(&self, input: &str) -> Option<String> {
+        (self.solver)(input)
+    }
+
+    /// The answer, or the message of the panic that cut it short.
+    pub fn try_solve(&self, input: &str) -> Result<Option<String>, String> {
+        let cell = solve::check_part(|input| self.solve(input), input, None);
+        match cell.verdict {
+            Verdict::Panicked(message) => Err(message),
+            _ => Ok(cell.answer),
+        }
+    }
+}
+
+/// Named implementations of both parts of a puzzle, the oracle first.
+#[derive(Default)]
+pub struct Registry {
+    parts: [Vec<Implementation>; 2],
+    parser: Option<Parser>,
+    parsers: Vec<Implementation>,
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an implementation of `part`, 1 or 2.
+    pub fn register<T, F>(mut self, part: u8, name: &'static str, solver: F) -> Self
+    where
+        T: Display,
+        F: Fn(&str) -> Option<T> + 'static,
+    {
+        let solver = Box::new(move |input: &str| solver(input).map(|answer| answer.to_string()));
+        self.parts[part_index(part)].push(Implementation { name, solver });
+        self
+    }
+
+    /// Add a parser of the input, only timed by `bench`, as part `0`.
+    pub fn register_parser<T, F>(mut self, name: &'static str, parse: F) -> Self
+    where
+        F: Fn(&str) -> T + 'static,
+    {
+        let solver = Box::new(move |input: &str| {
+            std::hint::black_box(parse(input));
+            None
+        });
+        self.parsers.push(Implementation { name, solver });
+        self
+    }
+
+    /// Tell valid inputs apart, so that `minimize` only tries inputs the day could be given.
+    pub fn parser(mut self, parses: impl Fn(&str) -> bool + 'static) -> Self {
+        self.parser = Some(Box::new(parses));
+        self
+    }
+
+    pub fn parses(&self, input: &str) -> bool {
+        self.parser.as_ref().is_none_or(|parses| parses(input))
+    }
+
+    pub fn part(&self, part: u8) -> &[Implementation] {
+        &self.parts[part_index(part)]
+    }
+
+    /// The first part whose implementations do not all give the same answer, or where one panics.
+    pub fn disagreement(&self, input: &Input) -> Option<Disagreement> {
+        [1, 2].into_iter().find_map(|part| {
+            let answers: Vec<(&'static str, Answer)> = self
+                .part(part)
+                .iter()
+                .map(|implementation| (implementation.name, implementation.try_solve(&input.text)))
+                .collect();
+            let agree = answers.iter().all(|(_, answer)| answer.is_ok())
+                && answers.windows(2).all(|pair| pair[0].1 == pair[1].1);
+            (!agree).then(|| Disagreement {
+                part,
+                input: input.clone(),
+                answers,
+            })
+        })
+    }
+
+    /// Number of inputs checked, or the first disagreement.
+    pub fn check(&self, inputs: impl IntoIterator<Item = Input>) -> Result<usize, Disagreement> {
+        let mut checked = 0;
+        for input in inputs {
+            if let Some(disagreement) = self.disagreement(&input) {
+                return Err(disagreement);
+            }
+            checked += 1;
+        }
+        Ok(checked)
+    }
+
+    /// Median time of every implementation over `runs` runs on `input`, and what
+    /// one more run allocates when allocations are counted.
+    pub fn bench(&self, input: &str, runs: usize) -> Vec<Timing> {
+        let parsers = self.parsers.iter().map(|parser| (0, parser));
+        let parts = [1, 2]
+            .into_iter()
+            .flat_map(|part| self.part(part).iter().map(move |i| (part, i)));
+        parsers
+            .chain(parts)
+            .map(|(part, implementation)| {
+                let mut durations: Vec<Duration> = (0..runs.max(1))
+                    .map(|_| {
+                        let timer = Instant::now();
+                        std::hint::black_box(implementation.solve(input));
+                        timer.elapsed()
+                    })
+                    .collect();
+                durations.sort();
+                let (_, allocations) = alloc_stats::measure(|| implementation.solve(input));
+                Timing {
+                    part,
+                    name: implementation.name,
+                    median: durations[durations.len() / 2],
+                    allocations,
+                }
+            })
+            .collect()
+    }
+}
+
+fn part_index(part: u8) -> usize {
+    match part {
+        1 | 2 => part as usize - 1,
+        _ => panic!("puzzles have parts 1 and 2, not {part}"),
+    }
+}
+
+//
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input {
+    /// Where the input comes from, e.g. `example.txt` or `generated (size 100, seed 7)`.
+    pub label: String,
+    pub text: String,
+}
+
+impl Input {
+    pub fn new(label: impl Into<String>, text: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            text: text.into(),
+        }
+    }
+}
+
+/// The examples and real input found in `directory`, sorted by file name.
+pub fn files(directory: &Path) -> io::Result<Vec<Input>> {
+    let mut paths: Vec<PathBuf> = fs::read_dir(directory)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<io::Result<_>>()?;
+    paths.retain(|path| {
+        let name = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or("");
+        name.ends_with(".txt") && (name.starts_with("example") || name == "input.txt")
+    });
+    paths.sort();
+    paths
+        .into_iter()
+        .map(|path| {
+            let label = path.file_name().unwrap().to_string_lossy().into_owned();
+            Ok(Input::new(label, fs::read_to_string(&path)?))
+        })
+        .collect()
+}
+
+/// `count` inputs of about `size` from `generator`, seeded 0, 1, 2...
+pub fn generated(
+    generator: &impl Generator,
+    size: usize,
+    count: usize,
+) -> impl Iterator<Item = Input> + '_ {
+    (0..count as u64).map(move |seed| {
+        Input::new(
+            format!("generated (size {}, seed {})", size, seed),
+            generate::generate(generator, size, seed),
+        )
+    })
+}
+
+/// What an implementation answered, `Err` with the message of its panic.
+pub type Answer = Result<Option<String>, String>;
+
+#[derive(Debug)]
+pub struct Disagreement {
+    pub part: u8,
+    pub input: Input,
+    pub answers: Vec<(&'static str, Answer)>,
+}
+
+impl Display for Disagreement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "part {} disagrees on {}:", self.part, self.input.label)?;
+        for (name, answer) in &self.answers {
+            match answer {
+                Ok(answer) => write!(
+                    f,
+                    "\n  {}: {}",
+                    name,
+                    answer.as_deref().unwrap_or("not solved.")
+                )?,
+                Err(message) => write!(f, "\n  {}: panicked: {}", name, message)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Timing {
+    /// `0` for a parser.
+    pub part: u8,
+    pub name: &'static str,
+    pub median: Duration,
+    pub allocations: Option<AllocStats>,
+}
+
+//
+
+/// Check the implementations on the examples, the real input and generated inputs,
+/// or time them with `--bench`.
+pub fn main(registry: Registry, generator: impl Generator) {
+    let mut args = pico_args::Arguments::from_env();
+    let parsed = (|| -> Result<_, pico_args::Error> {
+        Ok((
+            args.contains("--bench"),
+            args.opt_value_from_str("--size")?.unwrap_or(100),
+            args.opt_value_from_str("--generated")?.unwrap_or(20),
+            args.opt_value_from_str("--runs")?.unwrap_or(10),
+        ))
+    })();
+    let (bench, size, count, runs) = match parsed {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let cwd = env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR not found in env");
+    let files = files(Path::new(&cwd)).unwrap_or_else(|err| {
+        eprintln!("Could not read inputs in {}: {}", cwd, err);
+        process::exit(1);
+    });
+
+    if bench {
+        let input = files
+            .iter()
+            .find(|input| input.label == "input.txt")
+            .cloned()
+            .unwrap_or_else(|| generated(&generator, size, 1).next().unwrap());
+        print_timings(&input.label, &registry.bench(&input.text, runs));
+        return;
+    }
+
+    solve::silence_panics();
+    match registry.check(files.into_iter().chain(generated(&generator, size, count))) {
+        Ok(checked) => println!(
+            "{}✓{} {} and {} implementations agree on {} inputs",
+            ANSI_GREEN,
+            ANSI_RESET,
+            registry.part(1).len(),
+            registry.part(2).len(),
+            checked
+        ),
+        Err(disagreement) => {
+            let path = env::temp_dir().join("disagreement.txt");
+            let _ = fs::write(&path, &disagreement.input.text);
+            println!("{}✗{} {}", ANSI_RED, ANSI_RESET, disagreement);
+            println!("input saved to {}", path.display());
+            process::exit(1);
+        }
+    }
+}
+
+fn print_timings(label: &str, timings: &[Timing]) {
+    println!("{}Timings on {}{}", ANSI_BOLD, label, ANSI_RESET);
+    for part in [0, 1, 2] {
+        let part_timings: Vec<&Timing> = timings.iter().filter(|t| t.part == part).collect();
+        let Some(oracle) = part_timings.first() else {
+            continue;
+        };
+        match part {
+            0 => println!("🎄 {}Parsing{} 🎄", ANSI_BOLD, ANSI_RESET),
+            _ => println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, part, ANSI_RESET),
+        }
+        for timing in &part_timings {
+            let speedup = oracle.median.as_secs_f64() / timing.median.as_secs_f64().max(1e-9);
+            print!(
+                "  {:<16} {}{:>10.2?}{}  x{:<8.1}",
+                timing.name, ANSI_ITALIC, timing.median, ANSI_RESET, speedup
+            );
+            match timing.allocations {
+                Some(allocations) => println!("{}", allocations),
+                None => println!(),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn registry() -> Registry {
+        Registry::new()
+            .register(1, "count", |input: &str| Some(input.len()))
+            .register(1, "chars", |input: &str| Some(input.chars().count()))
+            .register(2, "first", |input: &str| input.chars().next())
+            .register_parser("lines", |input: &str| input.lines().count())
+    }
+
+    #[test]
+    fn test_check_agreeing() {
+        let inputs = [Input::new("a", "abc"), Input::new("b", "")];
+        assert_eq!(registry().check(inputs).unwrap(), 2);
+    }
+
+    #[test]
+    fn test_first_disagreement() {
+        let inputs = [
+            Input::new("ascii", "abc"),
+            Input::new("accent", "é"),
+            Input::new("emoji", "🎄"),
+        ];
+        let disagreement = registry().check(inputs).unwrap_err();
+        assert_eq!(
+            (disagreement.part, disagreement.input.label.as_str()),
+            (1, "accent")
+        );
+        assert_eq!(
+            disagreement.to_string(),
+            "part 1 disagrees on accent:\n  count: 2\n  chars: 1"
+        );
+    }
+
+    #[test]
+    fn test_panic_disagrees() {
+        let registry = Registry::new()
+            .register(1, "len", |input: &str| Some(input.len()))
+            .register(1, "non_empty", |input: &str| {
+                assert!(!input.is_empty(), "empty input");
+                Some(input.len())
+            });
+        let inputs = [Input::new("some", "abc"), Input::new("empty", "")];
+        let disagreement = registry.check(inputs).unwrap_err();
+        assert_eq!(disagreement.input.label, "empty");
+        assert_eq!(
+            disagreement.to_string(),
+            "part 1 disagrees on empty:\n  len: 0\n  non_empty: panicked: empty input"
+        );
+    }
+
+    #[test]
+    fn test_bench() {
+        let timings = registry().bench("abc", 3);
+        let names: Vec<_> = timings.iter().map(|t| (t.part, t.name)).collect();
+        assert_eq!(
+            names,
+            [(0, "lines"), (1, "count"), (1, "chars"), (2, "first")]
+        );
+    }
+
+    #[test]
+    fn test_files() {
+        let directory = env::temp_dir().join(format!("compare-files-{}", process::id()));
+        fs::create_dir_all(&directory).unwrap();
+        for name in ["input.txt", "example_two.txt", "example.txt", "notes.md"] {
+            fs::write(directory.join(name), name).unwrap();
+        }
+        let labels: Vec<String> = files(&directory)
+            .unwrap()
+            .into_iter()
+            .map(|input| input.label)
+            .collect();
+        fs::remove_dir_all(&directory).unwrap();
+        assert_eq!(labels, ["example.txt", "example_two.txt", "input.txt"]);
+    }
+}
@@ -0,0 +1,135 @@
+//! Random puzzle inputs, for stress and property tests.
+//!
+//! Each day implements `Generator` next to its solution and exposes it through
+//! an `examples/generate.rs` calling `generate::main`, which `cargo gen` runs.
+
+use std::{ops::RangeInclusive, process};
+
+/// Produces valid inputs for one puzzle, about `size` lines, elves or cells big.
+///
+/// Generated inputs must keep the invariants of real inputs: whatever the seed,
+/// both parts have an answer.
+pub trait Generator {
+    fn generate(&self, rng: &mut Rng, size: usize) -> String;
+}
+
+/// The same input for the same `size` and `seed`.
+pub fn generate(generator: &impl Generator, size: usize, seed: u64) -> String {
+    generator.generate(&mut Rng::new(seed), size)
+}
+
+/// Print an input picked by the `--size` and `--seed` arguments.
+pub fn main(generator: impl Generator) {
+    let mut args = pico_args::Arguments::from_env();
+    let parsed = args
+        .opt_value_from_str("--size")
+        .and_then(|size| Ok((size, args.opt_value_from_str("--seed")?)));
+    let (size, seed) = match parsed {
+        Ok((size, seed)) => (size.unwrap_or(100), seed.unwrap_or(0)),
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+    print!("{}", generate(&generator, size, seed));
+}
+
+//
+
+/// SplitMix64: small, fast and good enough to shuffle puzzle inputs.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..n`, `n` must not be zero.
+    pub fn below(&mut self, n: usize) -> usize {
+        assert!(n > 0, "cannot pick a number below 0");
+        ((self.next_u64() as u128 * n as u128) >> 64) as usize
+    }
+
+    pub fn range(&mut self, range: RangeInclusive<usize>) -> usize {
+        let (start, end) = range.into_inner();
+        start + self.below(end - start + 1)
+    }
+
+    pub fn signed_range(&mut self, range: RangeInclusive<isize>) -> isize {
+        let (start, end) = range.into_inner();
+        start + self.below(end.abs_diff(start) + 1) as isize
+    }
+
+    /// `true` with probability `p`.
+    pub fn chance(&mut self, p: f64) -> bool {
+        let unit = (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64;
+        unit < p
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+
+    /// `len` characters picked from `alphabet`.
+    pub fn string(&mut self, alphabet: &[u8], len: usize) -> String {
+        (0..len).map(|_| *self.choose(alphabet) as char).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Digits;
+
+    impl Generator for Digits {
+        fn generate(&self, rng: &mut Rng, size: usize) -> String {
+            rng.string(b"0123456789", size)
+        }
+    }
+
+    #[test]
+    fn test_same_seed_same_input() {
+        assert_eq!(generate(&Digits, 20, 7), generate(&Digits, 20, 7));
+        assert_ne!(generate(&Digits, 20, 7), generate(&Digits, 20, 8));
+        assert_eq!(generate(&Digits, 20, 7).len(), 20);
+    }
+
+    #[test]
+    fn test_ranges() {
+        let mut rng = Rng::new(1);
+        for _ in 0..1000 {
+            assert!((3..=5).contains(&rng.range(3..=5)));
+            assert!((-2..=2).contains(&rng.signed_range(-2..=2)));
+            assert!(rng.below(1) == 0);
+        }
+        assert!((0..1000).all(|_| !rng.chance(0.0) && rng.chance(1.0)));
+    }
+
+    #[test]
+    fn test_shuffle_keeps_items() {
+        let mut rng = Rng::new(2);
+        let mut items: Vec<usize> = (0..50).collect();
+        rng.shuffle(&mut items);
+        assert_ne!(items, (0..50).collect::<Vec<_>>());
+        items.sort();
+        assert_eq!(items, (0..50).collect::<Vec<_>>());
+    }
+}
@@ -1 +1,13 @@
+pub mod animate;
+pub mod cycle;
+pub mod geometry;
+pub mod grid;
+pub mod image;
+pub mod number;
+pub mod ocr;
+pub mod parse;
+pub mod range;
+pub mod scan;
+pub mod search;
+
 pub const LATEST_AOC_YEAR: u16 = 2023;
@@ -0,0 +1,403 @@
+//! Watch a simulation frame by frame in the terminal.
+//!
+//! Collect frames from anything `Display` (or a `Grid` and a cell renderer), then
+//! `play` them. While playing:
+//!
+//! - `space` pauses and resumes
+//! - `←`/`→` (or `h`/`l`) step one frame back or forward
+//! - `g`/`G` seek to the first or last frame
+//! - `+`/`-` double or halve the speed
+//! - `q` quits
+//!
+//! A headless animator writes the frames to a file instead, which is what tests use.
+
+use std::{
+    fmt::Display,
+    fs,
+    io::{self, IsTerminal, Read, Write},
+    path::PathBuf,
+    process::{Command, Stdio},
+    sync::{
+        atomic::{AtomicBool, Ordering::Relaxed},
+        mpsc::{self, Receiver},
+        Arc,
+    },
+    thread::{self, JoinHandle},
+    time::{Duration, Instant},
+};
+
+use super::grid::Grid;
+use crate::{ANSI_DIM, ANSI_RESET};
+
+const ANSI_HIDE_CURSOR: &str = "\x1b[?25l";
+const ANSI_SHOW_CURSOR: &str = "\x1b[?25h";
+const ANSI_HOME: &str = "\x1b[H";
+const ANSI_CLEAR: &str = "\x1b[2J";
+const ANSI_CLEAR_TO_END: &str = "\x1b[J";
+
+pub struct Animator {
+    frames: Vec<String>,
+    fps: f64,
+    headless: Option<PathBuf>,
+}
+
+impl Default for Animator {
+    fn default() -> Self {
+        Self {
+            frames: Vec::new(),
+            fps: 10.0,
+            headless: None,
+        }
+    }
+}
+
+impl Animator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Frames per second when playing, 10 by default.
+    pub fn fps(mut self, fps: f64) -> Self {
+        self.fps = fps;
+        self
+    }
+
+    /// Write the frames to `path` instead of playing them.
+    pub fn headless(mut self, path: impl Into<PathBuf>) -> Self {
+        self.headless = Some(path.into());
+        self
+    }
+
+    pub fn push(&mut self, frame: impl Display) {
+        self.frames.push(frame.to_string());
+    }
+
+    pub fn push_grid<T, F, D>(&mut self, grid: &Grid<T>, render: F)
+    where
+        F: Fn(&T) -> D,
+        D: Display,
+    {
+        self.push(grid.display(render));
+    }
+
+    pub fn frames(&self) -> &[String] {
+        &self.frames
+    }
+
+    /// Play the frames, or write them out when headless.
+    ///
+    /// Keys are only read when both stdin and stdout are terminals, otherwise
+    /// the frames play once from start to end.
+    pub fn play(&self) -> io::Result<()> {
+        if let Some(path) = &self.headless {
+            return fs::write(path, self.dump());
+        }
+        if self.frames.is_empty() {
+            return Ok(());
+        }
+
+        let interactive = io::stdin().is_terminal() && io::stdout().is_terminal();
+        let _raw_mode = interactive.then(RawMode::enable).transpose()?;
+        // Dropped before the raw mode, which its reads rely on to time out.
+        let key_reader = interactive.then(KeyReader::spawn);
+
+        let mut stdout = io::stdout().lock();
+        write!(stdout, "{ANSI_HIDE_CURSOR}{ANSI_CLEAR}")?;
+        let result = self.run(&mut stdout, key_reader.as_ref().map(|reader| &reader.keys));
+        writeln!(stdout, "{ANSI_SHOW_CURSOR}")?;
+        result
+    }
+
+    /// Every frame under a `--- frame N ---` header.
+    pub fn dump(&self) -> String {
+        self.frames
+            .iter()
+            .enumerate()
+            .map(|(index, frame)| format!("--- frame {} ---\n{}\n", index, frame))
+            .collect()
+    }
+
+    fn run(&self, out: &mut impl Write, keys: Option<&Receiver<Key>>) -> io::Result<()> {
+        let mut player = Player::new(self.frames.len(), self.fps);
+        loop {
+            write!(
+                out,
+                "{ANSI_HOME}{}{ANSI_CLEAR_TO_END}\n\n{ANSI_DIM}{}{ANSI_RESET}",
+                self.frames[player.index],
+                player.status()
+            )?;
+            out.flush()?;
+
+            let deadline = Instant::now() + player.frame_duration();
+            let key = match keys {
+                Some(keys) => keys
+                    .recv_timeout(deadline.saturating_duration_since(Instant::now()))
+                    .ok(),
+                None => {
+                    thread::sleep(player.frame_duration());
+                    None
+                }
+            };
+            let keep_going = match key {
+                Some(key) => player.press(key),
+                None => player.tick(),
+            };
+            if !keep_going {
+                return Ok(());
+            }
+        }
+    }
+}
+
+impl<D: Display> Extend<D> for Animator {
+    fn extend<I: IntoIterator<Item = D>>(&mut self, frames: I) {
+        for frame in frames {
+            self.push(frame);
+        }
+    }
+}
+
+impl<D: Display> FromIterator<D> for Animator {
+    fn from_iter<I: IntoIterator<Item = D>>(frames: I) -> Self {
+        let mut animator = Self::new();
+        animator.extend(frames);
+        animator
+    }
+}
+
+//
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Key {
+    Pause,
+    Next,
+    Previous,
+    First,
+    Last,
+    Faster,
+    Slower,
+    Quit,
+}
+
+impl Key {
+    /// Decode the bytes of one key press.
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        match bytes {
+            b" " => Some(Key::Pause),
+            b"l" | b"\x1b[C" => Some(Key::Next),
+            b"h" | b"\x1b[D" => Some(Key::Previous),
+            b"g" => Some(Key::First),
+            b"G" => Some(Key::Last),
+            b"+" | b"=" => Some(Key::Faster),
+            b"-" => Some(Key::Slower),
+            b"q" | b"\x1b" | b"\x03" => Some(Key::Quit),
+            _ => None,
+        }
+    }
+}
+
+/// Which frame is shown, and whether it moves on by itself.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Player {
+    pub index: usize,
+    pub playing: bool,
+    pub fps: f64,
+    frames: usize,
+}
+
+impl Player {
+    pub fn new(frames: usize, fps: f64) -> Self {
+        Self {
+            index: 0,
+            playing: true,
+            fps,
+            frames,
+        }
+    }
+
+    pub fn frame_duration(&self) -> Duration {
+        Duration::from_secs_f64(1.0 / self.fps.max(0.1))
+    }
+
+    /// Move on after a frame was shown, `false` once the last frame played.
+    pub fn tick(&mut self) -> bool {
+        if !self.playing {
+            return true;
+        }
+        if self.index + 1 >= self.frames {
+            return false;
+        }
+        self.index += 1;
+        true
+    }
+
+    /// Handle a key press, `false` when asked to quit.
+    pub fn press(&mut self, key: Key) -> bool {
+        let last = self.frames.saturating_sub(1);
+        match key {
+            Key::Pause => self.playing = !self.playing,
+            Key::Next => {
+                self.playing = false;
+                self.index = (self.index + 1).min(last);
+            }
+            Key::Previous => {
+                self.playing = false;
+                self.index = self.index.saturating_sub(1);
+            }
+            Key::First => self.index = 0,
+            Key::Last => self.index = last,
+            Key::Faster => self.fps *= 2.0,
+            Key::Slower => self.fps /= 2.0,
+            Key::Quit => return false,
+        }
+        true
+    }
+
+    pub fn status(&self) -> String {
+        format!(
+            "frame {}/{}  {} fps  {}  space pause · ←/→ step · g/G seek · +/- speed · q quit",
+            self.index + 1,
+            self.frames,
+            self.fps,
+            if self.playing { "▶" } else { "⏸" }
+        )
+    }
+}
+
+//
+
+/// Keeps the terminal unbuffered and silent until dropped.
+///
+/// Reads wait a tenth of a second at most, so that the key reader can notice it
+/// should stop.
+struct RawMode {
+    saved: String,
+}
+
+impl RawMode {
+    fn enable() -> io::Result<Self> {
+        let saved = stty(&["-g"])?;
+        stty(&["-icanon", "-echo", "min", "0", "time", "1"])?;
+        Ok(Self {
+            saved: saved.trim().to_string(),
+        })
+    }
+}
+
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        let _ = stty(&[&self.saved]);
+    }
+}
+
+fn stty(args: &[&str]) -> io::Result<String> {
+    let output = Command::new("stty")
+        .args(args)
+        .stdin(Stdio::inherit())
+        .output()?;
+    if !output.status.success() {
+        return Err(io::Error::other(format!("stty {} failed", args.join(" "))));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Reads key presses from stdin until dropped, so that no keystroke is taken from
+/// whatever reads stdin after playing.
+struct KeyReader {
+    keys: Receiver<Key>,
+    stop: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl KeyReader {
+    fn spawn() -> Self {
+        let (sender, keys) = mpsc::channel();
+        let stop = Arc::new(AtomicBool::new(false));
+        let stopped = Arc::clone(&stop);
+        let thread = thread::spawn(move || {
+            let mut buffer = [0; 8];
+            let mut stdin = io::stdin();
+            while !stopped.load(Relaxed) {
+                // Reads return nothing once the raw mode's timeout is up.
+                let read = match stdin.read(&mut buffer) {
+                    Ok(read) => read,
+                    Err(_) => break,
+                };
+                if let Some(key) = Key::from_bytes(&buffer[..read]) {
+                    if sender.send(key).is_err() {
+                        break;
+                    }
+                }
+            }
+        });
+        Self {
+            keys,
+            stop,
+            thread: Some(thread),
+        }
+    }
+}
+
+impl Drop for KeyReader {
+    fn drop(&mut self) {
+        self.stop.store(true, Relaxed);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_headless_dump() {
+        let path = std::env::temp_dir().join(format!("animate-{}.txt", std::process::id()));
+        let mut animator = Animator::new().headless(&path);
+        animator.extend(["a", "b"]);
+        animator.push_grid(&Grid::filled(2, 2, true), |_| '#');
+        animator.play().unwrap();
+
+        let dump = fs::read_to_string(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(
+            dump,
+            "--- frame 0 ---\na\n--- frame 1 ---\nb\n--- frame 2 ---\n##\n##\n"
+        );
+    }
+
+    #[test]
+    fn test_keys() {
+        assert_eq!(Key::from_bytes(b" "), Some(Key::Pause));
+        assert_eq!(Key::from_bytes(b"\x1b[C"), Some(Key::Next));
+        assert_eq!(Key::from_bytes(b"\x1b[D"), Some(Key::Previous));
+        assert_eq!(Key::from_bytes(b"x"), None);
+    }
+
+    #[test]
+    fn test_player() {
+        let mut player = Player::new(3, 10.0);
+        assert!(player.tick());
+        assert_eq!(player.index, 1);
+
+        assert!(player.press(Key::Pause));
+        assert!(player.tick());
+        assert_eq!(player.index, 1);
+
+        assert!(player.press(Key::Last));
+        assert!(player.press(Key::Next));
+        assert_eq!(player.index, 2);
+        assert!(player.press(Key::First));
+        assert!(player.press(Key::Previous));
+        assert_eq!(player.index, 0);
+
+        assert!(player.press(Key::Faster));
+        assert_eq!(player.frame_duration(), Duration::from_millis(50));
+
+        assert!(player.press(Key::Pause));
+        assert!(player.tick() && player.tick());
+        assert!(!player.tick(), "stops after the last frame");
+        assert!(!player.press(Key::Quit));
+    }
+}
@@ -0,0 +1,191 @@
+//! Detect when a simulation starts repeating itself, to jump to its billionth state.
+
+use std::{collections::HashMap, hash::Hash};
+
+/// States of a sequence until it repeats: `states[start..]` loops forever.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle<S> {
+    /// Index of the first state in the loop.
+    pub start: usize,
+    pub length: usize,
+    states: Vec<S>,
+}
+
+impl<S> Cycle<S> {
+    /// Index in `states()` of the state equal to the `n`th one.
+    pub fn index_of(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.length
+        }
+    }
+
+    /// The `n`th state, counting from 0, without running the sequence that far.
+    pub fn nth_state(&self, n: usize) -> &S {
+        &self.states[self.index_of(n)]
+    }
+
+    /// Every distinct state, in order.
+    pub fn states(&self) -> &[S] {
+        &self.states
+    }
+}
+
+/// Run `step` from `initial`, remembering every state, until one repeats.
+pub fn find_cycle<S, F>(initial: S, mut step: F) -> Cycle<S>
+where
+    S: Hash + Eq + Clone,
+    F: FnMut(&S) -> S,
+{
+    find_cycle_in(std::iter::successors(Some(initial), |state| {
+        Some(step(state))
+    }))
+    .expect("an endless sequence either repeats or never returns")
+}
+
+/// Read `states` until one repeats, or `None` when the iterator ends first.
+pub fn find_cycle_in<I>(states: I) -> Option<Cycle<I::Item>>
+where
+    I: IntoIterator,
+    I::Item: Hash + Eq + Clone,
+{
+    detect(states).ok()
+}
+
+/// The `n`th state from `initial`, fast-forwarding through the cycle once found.
+pub fn nth_state<S, F>(initial: S, mut step: F, n: usize) -> S
+where
+    S: Hash + Eq + Clone,
+    F: FnMut(&S) -> S,
+{
+    let states = std::iter::successors(Some(initial), |state| Some(step(state)));
+    match detect(states.take(n + 1)) {
+        Ok(cycle) => cycle.nth_state(n).clone(),
+        // No repeat up to the `n`th state, which is the last one.
+        Err(mut history) => history.pop().expect("`n + 1` states were taken"),
+    }
+}
+
+/// The cycle, or every state read when `states` ends without repeating.
+fn detect<I>(states: I) -> Result<Cycle<I::Item>, Vec<I::Item>>
+where
+    I: IntoIterator,
+    I::Item: Hash + Eq + Clone,
+{
+    let mut seen = HashMap::new();
+    let mut history = Vec::new();
+    for (index, state) in states.into_iter().enumerate() {
+        if let Some(&start) = seen.get(&state) {
+            return Ok(Cycle {
+                start,
+                length: index - start,
+                states: history,
+            });
+        }
+        seen.insert(state.clone(), index);
+        history.push(state);
+    }
+    Err(history)
+}
+
+/// `(start, length)` of the cycle from `initial` with Brent's algorithm.
+///
+/// Only keeps a couple of states around, at the cost of running `step`
+/// more often than `find_cycle`.
+pub fn brent<S, F>(initial: S, mut step: F) -> (usize, usize)
+where
+    S: Eq + Clone,
+    F: FnMut(&S) -> S,
+{
+    // Find the length by racing a hare against a tortoise teleported to it at powers of two.
+    let (mut power, mut length) = (1, 1);
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+    }
+
+    // Find the start with two walkers `length` apart.
+    let mut tortoise = initial.clone();
+    let mut hare = initial;
+    for _ in 0..length {
+        hare = step(&hare);
+    }
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+    (start, length)
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    /// 0 -> 1 -> 2 -> 3 -> 4 -> 2
+    fn lasso(state: &u32) -> u32 {
+        if *state == 4 {
+            2
+        } else {
+            state + 1
+        }
+    }
+
+    #[test]
+    fn test_find_cycle() {
+        let cycle = find_cycle(0, lasso);
+        assert_eq!((cycle.start, cycle.length), (2, 3));
+        assert_eq!(cycle.states(), [0, 1, 2, 3, 4]);
+        assert_eq!(*cycle.nth_state(1), 1);
+        assert_eq!(*cycle.nth_state(5), 2);
+        assert_eq!(*cycle.nth_state(1_000_000_000), 4);
+    }
+
+    #[test]
+    fn test_find_cycle_in() {
+        assert_eq!(find_cycle_in(0..10), None);
+        let cycle = find_cycle_in("abcbc".chars()).unwrap();
+        assert_eq!((cycle.start, cycle.length), (1, 2));
+    }
+
+    #[test]
+    fn test_nth_state() {
+        assert_eq!(nth_state(0, lasso, 0), 0);
+        assert_eq!(nth_state(0, lasso, 3), 3);
+        assert_eq!(nth_state(0, lasso, 1_000_000_000), 4);
+    }
+
+    #[test]
+    fn test_brent() {
+        assert_eq!(brent(0, lasso), (2, 3));
+        assert_eq!(brent(7, |_| 7), (0, 1));
+    }
+
+    proptest! {
+        #[test]
+        fn methods_agree(seed in 0..1000u64, modulus in 1..500u64, n in 0..5000usize) {
+            // A pseudo-random walk in `0..modulus` has to repeat
+            let step = move |x: &u64| (x * x + seed) % modulus;
+            let cycle = find_cycle(seed % modulus, step);
+            prop_assert_eq!(brent(seed % modulus, step), (cycle.start, cycle.length));
+
+            let mut expected = seed % modulus;
+            for _ in 0..n {
+                expected = step(&expected);
+            }
+            prop_assert_eq!(*cycle.nth_state(n), expected);
+            prop_assert_eq!(nth_state(seed % modulus, step, n), expected);
+        }
+    }
+}
@@ -0,0 +1,441 @@
+//! 2D points, vectors and directions.
+//!
+//! Points are positions and vectors are displacements: `point - point` is a
+//! vector and `point + vector` is a point. The directions take `y` growing
+//! downwards, like the rows of a `Grid`, so `Dir4::Up` is `(0, -1)`.
+
+use std::{
+    fmt::Display,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+/// Integer types usable as coordinates or range bounds.
+pub trait Coord:
+    Copy + Ord + Default + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+    const MAX: Self;
+
+    /// `self + other`, `None` on overflow.
+    fn checked_add(self, other: Self) -> Option<Self>;
+
+    /// `self - other`, `None` on overflow.
+    fn checked_sub(self, other: Self) -> Option<Self>;
+
+    /// `|self - other|` without overflowing for unsigned types.
+    fn abs_diff(self, other: Self) -> Self {
+        if self > other {
+            self - other
+        } else {
+            other - self
+        }
+    }
+}
+
+macro_rules! impl_coord {
+    ($($t:ty),*) => {
+        $(impl Coord for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+            const MAX: Self = <$t>::MAX;
+
+            fn checked_add(self, other: Self) -> Option<Self> {
+                <$t>::checked_add(self, other)
+            }
+
+            fn checked_sub(self, other: Self) -> Option<Self> {
+                <$t>::checked_sub(self, other)
+            }
+        })*
+    };
+}
+
+impl_coord!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+//
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Vec2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T> Vec2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T: Coord> Point2<T> {
+    /// Taxicab distance, the number of orthogonal steps between two points.
+    pub fn manhattan(self, other: Self) -> T {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// King's move distance, the number of orthogonal or diagonal steps.
+    pub fn chebyshev(self, other: Self) -> T {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+
+    pub fn euclidean_squared(self, other: Self) -> T {
+        let (dx, dy) = (self.x.abs_diff(other.x), self.y.abs_diff(other.y));
+        dx * dx + dy * dy
+    }
+
+    /// Convert each coordinate, `None` when one doesn't fit in `U`.
+    pub fn try_cast<U: TryFrom<T>>(self) -> Option<Point2<U>> {
+        Some(Point2::new(
+            self.x.try_into().ok()?,
+            self.y.try_into().ok()?,
+        ))
+    }
+}
+
+impl<T: Coord> Vec2<T> {
+    pub fn manhattan_len(self) -> T {
+        self.x.abs_diff(T::ZERO) + self.y.abs_diff(T::ZERO)
+    }
+
+    /// Convert each coordinate, `None` when one doesn't fit in `U`.
+    pub fn try_cast<U: TryFrom<T>>(self) -> Option<Vec2<U>> {
+        Some(Vec2::new(self.x.try_into().ok()?, self.y.try_into().ok()?))
+    }
+}
+
+macro_rules! impl_signed {
+    ($($t:ty),*) => {
+        $(
+            impl Vec2<$t> {
+                /// Clamp each coordinate to `-1`, `0` or `1`.
+                pub fn signum(self) -> Self {
+                    Self::new(self.x.signum(), self.y.signum())
+                }
+            }
+        )*
+    };
+}
+
+impl_signed!(i8, i16, i32, i64, i128, isize);
+
+impl Point2<usize> {
+    /// Move by a signed vector, `None` when a coordinate would become negative.
+    pub fn checked_add_signed(self, vector: Vec2<isize>) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_add_signed(vector.x)?,
+            self.y.checked_add_signed(vector.y)?,
+        ))
+    }
+}
+
+impl<T> From<(T, T)> for Point2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+impl<T> From<Point2<T>> for (T, T) {
+    fn from(Point2 { x, y }: Point2<T>) -> Self {
+        (x, y)
+    }
+}
+
+impl<T> From<(T, T)> for Vec2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+impl<T: Display> Display for Point2<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl<T: Display> Display for Vec2<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "<{}, {}>", self.x, self.y)
+    }
+}
+
+//
+
+impl<T: Add<Output = T>> Add<Vec2<T>> for Point2<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Vec2<T>) -> Self::Output {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub<Vec2<T>> for Point2<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Vec2<T>) -> Self::Output {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point2<T> {
+    type Output = Vec2<T>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Vec2::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: AddAssign> AddAssign<Vec2<T>> for Point2<T> {
+    fn add_assign(&mut self, rhs: Vec2<T>) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+
+impl<T: SubAssign> SubAssign<Vec2<T>> for Point2<T> {
+    fn sub_assign(&mut self, rhs: Vec2<T>) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+    }
+}
+
+impl<T: Add<Output = T>> Add for Vec2<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Vec2<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: AddAssign> AddAssign for Vec2<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+
+impl<T: SubAssign> SubAssign for Vec2<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Vec2<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+impl<T: Mul<Output = T> + Copy> Mul<T> for Vec2<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Self::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+//
+
+/// The 4 orthogonal directions, clockwise from `Up`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Dir4 {
+    pub const ALL: [Dir4; 4] = [Dir4::Up, Dir4::Right, Dir4::Down, Dir4::Left];
+
+    pub fn iter() -> std::array::IntoIter<Dir4, 4> {
+        Self::ALL.into_iter()
+    }
+
+    /// Quarter turn clockwise.
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    /// Quarter turn counter-clockwise.
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+
+    pub fn vector(self) -> Vec2<isize> {
+        Dir8::from(self).vector()
+    }
+}
+
+/// The 8 compass directions, clockwise from `Up`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Dir8 {
+    pub const ALL: [Dir8; 8] = [
+        Dir8::Up,
+        Dir8::UpRight,
+        Dir8::Right,
+        Dir8::DownRight,
+        Dir8::Down,
+        Dir8::DownLeft,
+        Dir8::Left,
+        Dir8::UpLeft,
+    ];
+
+    pub fn iter() -> std::array::IntoIter<Dir8, 8> {
+        Self::ALL.into_iter()
+    }
+
+    /// Eighth of a turn clockwise.
+    pub fn rotate_cw(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    /// Eighth of a turn counter-clockwise.
+    pub fn rotate_ccw(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+
+    pub fn vector(self) -> Vec2<isize> {
+        match self {
+            Dir8::Up => Vec2::new(0, -1),
+            Dir8::UpRight => Vec2::new(1, -1),
+            Dir8::Right => Vec2::new(1, 0),
+            Dir8::DownRight => Vec2::new(1, 1),
+            Dir8::Down => Vec2::new(0, 1),
+            Dir8::DownLeft => Vec2::new(-1, 1),
+            Dir8::Left => Vec2::new(-1, 0),
+            Dir8::UpLeft => Vec2::new(-1, -1),
+        }
+    }
+}
+
+impl From<Dir4> for Dir8 {
+    fn from(direction: Dir4) -> Self {
+        Self::ALL[direction as usize * 2]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let a = Point2::new(1, 2);
+        let b = Point2::new(4, -2);
+        assert_eq!(b - a, Vec2::new(3, -4));
+        assert_eq!(a + (b - a), b);
+        assert_eq!(b - (b - a), a);
+        assert_eq!(Vec2::new(1, 2) * 3, Vec2::new(3, 6));
+        assert_eq!(-Vec2::new(1, -2), Vec2::new(-1, 2));
+        assert_eq!(Vec2::new(5isize, -7).signum(), Vec2::new(1, -1));
+
+        let mut c = a;
+        c += Vec2::new(1, 1);
+        c -= Vec2::new(0, 2);
+        assert_eq!(c, Point2::new(2, 1));
+    }
+
+    #[test]
+    fn test_distances() {
+        let a = Point2::new(1, 2);
+        let b = Point2::new(4, -2);
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.chebyshev(b), 4);
+        assert_eq!(a.euclidean_squared(b), 25);
+        assert_eq!((b - a).manhattan_len(), 7);
+
+        let (a, b) = (Point2::new(0usize, 5), Point2::new(3usize, 1));
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(b.manhattan(a), 7);
+    }
+
+    #[test]
+    fn test_conversions() {
+        assert_eq!(
+            Point2::new(3isize, 4).try_cast(),
+            Some(Point2::new(3usize, 4))
+        );
+        assert_eq!(Point2::new(-1isize, 4).try_cast::<usize>(), None);
+        assert_eq!(Point2::new(300u32, 0).try_cast::<u8>(), None);
+        assert_eq!(
+            Point2::new(1usize, 0).checked_add_signed(Dir4::Left.vector()),
+            Some(Point2::new(0, 0))
+        );
+        assert_eq!(
+            Point2::new(1usize, 0).checked_add_signed(Dir4::Up.vector()),
+            None
+        );
+        assert_eq!(<(i32, i32)>::from(Point2::from((1, 2))), (1, 2));
+    }
+
+    #[test]
+    fn test_dir4() {
+        assert_eq!(Dir4::Up.turn_right(), Dir4::Right);
+        assert_eq!(Dir4::Up.turn_left(), Dir4::Left);
+        assert_eq!(Dir4::Left.opposite(), Dir4::Right);
+        assert_eq!(
+            Dir4::iter()
+                .map(Dir4::vector)
+                .fold(Vec2::default(), Add::add),
+            Vec2::default()
+        );
+        assert!(Dir4::iter().all(|d| d.turn_right().turn_left() == d));
+    }
+
+    #[test]
+    fn test_dir8() {
+        assert_eq!(Dir8::Up.rotate_cw(), Dir8::UpRight);
+        assert_eq!(Dir8::Up.rotate_ccw(), Dir8::UpLeft);
+        assert_eq!(Dir8::DownLeft.opposite(), Dir8::UpRight);
+        assert_eq!(Dir8::from(Dir4::Down), Dir8::Down);
+        assert!(Dir8::iter().all(|d| d.vector() == -d.opposite().vector()));
+        assert_eq!(
+            Dir8::iter()
+                .filter(|d| d.vector().manhattan_len() == 1)
+                .count(),
+            4
+        );
+    }
+}
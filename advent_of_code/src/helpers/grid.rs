@@ -0,0 +1,415 @@
+//! A rectangular grid of cells stored in row-major order.
+//!
+//! Positions are `(x, y)` tuples where `x` is the column and `y` the row, so
+//! `grid[(x, y)]` reads like a plane. `get_rc` is there for puzzles that think
+//! in `(row, col)`.
+
+use std::{
+    fmt::Display,
+    iter::StepBy,
+    ops::{Index, IndexMut, Range},
+    slice::{Chunks, Iter, IterMut},
+};
+
+use super::parse::{char_grid, parse_all, ParseError};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Wrap `cells` given row by row. Panics when they don't fill `width` x `height`.
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(
+            cells.len(),
+            width * height,
+            "{} cells can't fill a {}x{} grid",
+            cells.len(),
+            width,
+            height
+        );
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Panics when the rows don't all have the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+        Self::new(width, height, rows.into_iter().flatten().collect())
+    }
+
+    /// Parse one cell per character, rejecting characters `cell` maps to `None`.
+    pub fn parse<F>(input: &str, cell: F) -> Result<Self, ParseError>
+    where
+        F: Fn(char) -> Option<T> + Copy,
+    {
+        parse_all(char_grid(cell), input).map(Self::from_rows)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, (x, y): (usize, usize)) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn index_to_position(&self, index: usize) -> (usize, usize) {
+        (index % self.width, index / self.width)
+    }
+
+    pub fn position_to_index(&self, (x, y): (usize, usize)) -> Option<usize> {
+        self.contains((x, y)).then(|| y * self.width + x)
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        self.position_to_index((x, y)).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        self.position_to_index((x, y)).map(|i| &mut self.cells[i])
+    }
+
+    pub fn get_rc(&self, row: usize, col: usize) -> Option<&T> {
+        self.get(col, row)
+    }
+
+    /// Cells in row-major order.
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.cells.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        self.cells.iter_mut()
+    }
+
+    /// Cells in row-major order along with their `(x, y)` position.
+    pub fn positions(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(index, cell)| (self.index_to_position(index), cell))
+    }
+
+    /// Position of the first cell matching `predicate`.
+    pub fn position(&self, predicate: impl Fn(&T) -> bool) -> Option<(usize, usize)> {
+        self.cells
+            .iter()
+            .position(predicate)
+            .map(|index| self.index_to_position(index))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> Chunks<'_, T> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Cells of the column `x`, top to bottom, panicking outside of the grid.
+    pub fn column(&self, x: usize) -> StepBy<Iter<'_, T>> {
+        assert!(
+            x < self.width,
+            "column {} out of {}x{} grid",
+            x,
+            self.width,
+            self.height
+        );
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = StepBy<Iter<'_, T>>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Cells of the `xs` columns and `ys` rows, row by row.
+    ///
+    /// The ranges are clipped to the grid, and reversed ones are empty.
+    pub fn sub_grid(&self, xs: Range<usize>, ys: Range<usize>) -> impl Iterator<Item = &T> {
+        let clip = |range: Range<usize>, len: usize| {
+            let end = range.end.min(len);
+            range.start.min(end)..end
+        };
+        let xs = clip(xs, self.width);
+        let ys = clip(ys, self.height);
+        ys.flat_map(move |y| &self.row(y)[xs.clone()])
+    }
+
+    /// Right, down, left and up neighbours inside the grid.
+    pub fn neighbours4(&self, position: (usize, usize)) -> impl Iterator<Item = (usize, usize)> {
+        self.neighbours(position, &[(1, 0), (0, 1), (-1, 0), (0, -1)])
+    }
+
+    /// The 8 surrounding neighbours inside the grid, clockwise from the right.
+    pub fn neighbours8(&self, position: (usize, usize)) -> impl Iterator<Item = (usize, usize)> {
+        self.neighbours(
+            position,
+            &[
+                (1, 0),
+                (1, 1),
+                (0, 1),
+                (-1, 1),
+                (-1, 0),
+                (-1, -1),
+                (0, -1),
+                (1, -1),
+            ],
+        )
+    }
+
+    fn neighbours(
+        &self,
+        (x, y): (usize, usize),
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> {
+        let (width, height) = (self.width, self.height);
+        offsets.iter().filter_map(move |&(dx, dy)| {
+            let x = x.checked_add_signed(dx).filter(|&x| x < width)?;
+            let y = y.checked_add_signed(dy).filter(|&y| y < height)?;
+            Some((x, y))
+        })
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+
+    /// Display the grid row by row, each cell rendered by `render`.
+    pub fn display<F, D>(&self, render: F) -> GridDisplay<'_, T, F>
+    where
+        F: Fn(&T) -> D,
+        D: Display,
+    {
+        GridDisplay { grid: self, render }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn filled(width: usize, height: usize, value: T) -> Self {
+        Self::new(width, height, vec![value; width * height])
+    }
+
+    /// Swap rows and columns.
+    pub fn transpose(&self) -> Self {
+        self.rearranged(self.height, self.width, |(x, y)| (y, x))
+    }
+
+    /// Rotate a quarter turn clockwise.
+    pub fn rotate_cw(&self) -> Self {
+        let height = self.height;
+        self.rearranged(height, self.width, |(x, y)| (y, height - 1 - x))
+    }
+
+    /// Rotate a quarter turn counter-clockwise.
+    pub fn rotate_ccw(&self) -> Self {
+        let width = self.width;
+        self.rearranged(self.height, width, |(x, y)| (width - 1 - y, x))
+    }
+
+    /// Mirror left to right.
+    pub fn flip_horizontal(&self) -> Self {
+        let width = self.width;
+        self.rearranged(width, self.height, |(x, y)| (width - 1 - x, y))
+    }
+
+    /// Mirror top to bottom.
+    pub fn flip_vertical(&self) -> Self {
+        let height = self.height;
+        self.rearranged(self.width, height, |(x, y)| (x, height - 1 - y))
+    }
+
+    /// Build a `width` x `height` grid where each position reads `self[source(position)]`.
+    fn rearranged(
+        &self,
+        width: usize,
+        height: usize,
+        source: impl Fn((usize, usize)) -> (usize, usize),
+    ) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|position| self[source(position)].clone())
+            .collect();
+        Self::new(width, height, cells)
+    }
+}
+
+/// Performs the indexing (`grid[(x, y)]`) operation, panicking outside of the grid.
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        self.get(x, y).unwrap_or_else(|| {
+            panic!(
+                "position ({}, {}) out of {}x{} grid",
+                x, y, self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut Self::Output {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(x, y)
+            .unwrap_or_else(|| panic!("position ({}, {}) out of {}x{} grid", x, y, width, height))
+    }
+}
+
+pub struct GridDisplay<'a, T, F> {
+    grid: &'a Grid<T>,
+    render: F,
+}
+
+impl<T, F, D> Display for GridDisplay<'_, T, F>
+where
+    F: Fn(&T) -> D,
+    D: Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.grid.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", (self.render)(cell))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(input: &str) -> Grid<u32> {
+        Grid::parse(input, |c| c.to_digit(10)).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = digits("12\n34\n56\n");
+        assert_eq!((grid.width(), grid.height()), (2, 3));
+        assert_eq!(grid.iter().copied().collect::<Vec<_>>(), [1, 2, 3, 4, 5, 6]);
+        assert!(Grid::parse("12\n3x", |c| c.to_digit(10)).is_err());
+    }
+
+    #[test]
+    fn test_get() {
+        let grid = digits("12\n34\n56");
+        assert_eq!(grid.get(1, 0), Some(&2));
+        assert_eq!(grid.get_rc(1, 0), Some(&3));
+        assert_eq!(grid.get(2, 0), None);
+        assert_eq!(grid.get(0, 3), None);
+        assert_eq!(grid[(1, 2)], 6);
+    }
+
+    #[test]
+    fn test_index_to_position() {
+        let grid = digits("01\n23\n45");
+        assert_eq!(grid.index_to_position(0), (0, 0));
+        assert_eq!(grid.index_to_position(3), (1, 1));
+        assert_eq!(grid.index_to_position(4), (0, 2));
+        assert_eq!(grid.position_to_index((1, 2)), Some(5));
+        assert_eq!(grid.position_to_index((2, 0)), None);
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = digits("12\n34\n56");
+        let sum_by_row: Vec<u32> = grid.rows().map(|row| row.iter().sum()).collect();
+        assert_eq!(sum_by_row, vec![1 + 2, 3 + 4, 5 + 6]);
+        let sum_by_col: Vec<u32> = grid.columns().map(|col| col.sum()).collect();
+        assert_eq!(sum_by_col, vec![1 + 3 + 5, 2 + 4 + 6]);
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), [2, 4, 6]);
+        assert_eq!(grid.row(2), [5, 6]);
+    }
+
+    #[test]
+    fn test_sub_grid() {
+        let grid = digits("1111\n1221\n1221\n1111");
+        assert_eq!(grid.sub_grid(1..3, 1..3).collect::<Vec<_>>(), [&2; 4]);
+        let grid = digits("123\n456\n789");
+        assert_eq!(grid.sub_grid(1..2, 1..2).collect::<Vec<_>>(), [&5]);
+        assert_eq!(grid.sub_grid(2..9, 0..2).collect::<Vec<_>>(), [&3, &6]);
+        #[allow(clippy::reversed_empty_ranges)]
+        let reversed = grid.sub_grid(2..1, 0..3).count();
+        assert_eq!(reversed, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "column 2 out of 2x3 grid")]
+    fn test_column_outside() {
+        let _ = digits("12\n34\n56").column(2);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = digits("123\n456\n789");
+        assert_eq!(
+            grid.neighbours4((0, 0)).collect::<Vec<_>>(),
+            [(1, 0), (0, 1)]
+        );
+        assert_eq!(grid.neighbours4((1, 1)).count(), 4);
+        assert_eq!(
+            grid.neighbours8((2, 2)).collect::<Vec<_>>(),
+            [(1, 2), (1, 1), (2, 1)]
+        );
+        assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+    }
+
+    #[test]
+    fn test_transformations() {
+        let grid = digits("123\n456");
+        let render = |grid: &Grid<u32>| grid.display(|d| *d).to_string();
+        assert_eq!(render(&grid.transpose()), "14\n25\n36");
+        assert_eq!(render(&grid.rotate_cw()), "41\n52\n63");
+        assert_eq!(render(&grid.rotate_ccw()), "36\n25\n14");
+        assert_eq!(render(&grid.flip_horizontal()), "321\n654");
+        assert_eq!(render(&grid.flip_vertical()), "456\n123");
+        assert_eq!(grid.rotate_cw().rotate_ccw(), grid);
+    }
+
+    #[test]
+    fn test_display() {
+        let mut grid = Grid::filled(3, 2, false);
+        grid[(1, 1)] = true;
+        assert_eq!(
+            grid.display(|&lit| if lit { '#' } else { '.' }).to_string(),
+            "...\n.#."
+        );
+    }
+
+    #[test]
+    fn test_position() {
+        let grid = Grid::parse("Sab\nbcE", Some).unwrap();
+        assert_eq!(grid.position(|&c| c == 'E'), Some((2, 1)));
+        assert_eq!(
+            grid.map(|&c| c.is_uppercase())
+                .iter()
+                .filter(|&&b| b)
+                .count(),
+            2
+        );
+    }
+}
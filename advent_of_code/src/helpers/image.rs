@@ -0,0 +1,346 @@
+//! Save grids as pictures: binary PPM, PNG and SVG, written in plain Rust.
+//!
+//! PNGs use uncompressed deflate blocks: they are larger than they could be but
+//! any viewer opens them and nothing outside of std is needed.
+
+use std::{
+    fmt::Write as _,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use super::grid::Grid;
+
+pub type Rgb = [u8; 3];
+
+pub const BLACK: Rgb = [0, 0, 0];
+pub const WHITE: Rgb = [255, 255, 255];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Ppm,
+    Png,
+    Svg,
+}
+
+impl Format {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Format::Ppm => "ppm",
+            Format::Png => "png",
+            Format::Svg => "svg",
+        }
+    }
+
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()? {
+            "ppm" => Some(Format::Ppm),
+            "png" => Some(Format::Png),
+            "svg" => Some(Format::Svg),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl Image {
+    /// One pixel per cell, colored by `color`.
+    pub fn from_grid<T>(grid: &Grid<T>, color: impl Fn(&T) -> Rgb) -> Self {
+        Self {
+            width: grid.width(),
+            height: grid.height(),
+            pixels: grid.iter().map(color).collect(),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn pixel(&self, x: usize, y: usize) -> Rgb {
+        self.pixels[y * self.width + x]
+    }
+
+    pub fn set_pixel(&mut self, x: usize, y: usize, color: Rgb) {
+        self.pixels[y * self.width + x] = color;
+    }
+
+    /// Every pixel becomes a `factor` x `factor` square.
+    pub fn scaled(&self, factor: usize) -> Self {
+        let (width, height) = (self.width * factor, self.height * factor);
+        let pixels = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x / factor, y / factor)))
+            .map(|(x, y)| self.pixel(x, y))
+            .collect();
+        Self {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    pub fn encode(&self, format: Format) -> Vec<u8> {
+        match format {
+            Format::Ppm => self.to_ppm(),
+            Format::Png => self.to_png(),
+            Format::Svg => self.to_svg(1).into_bytes(),
+        }
+    }
+
+    /// Save in the format matching the extension of `path`.
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        let format = Format::from_path(path).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("{} is not a .ppm, .png or .svg file", path.display()),
+            )
+        })?;
+        fs::write(path, self.encode(format))
+    }
+
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let mut ppm = format!("P6\n{} {}\n255\n", self.width, self.height).into_bytes();
+        ppm.extend(self.pixels.iter().flatten());
+        ppm
+    }
+
+    pub fn to_png(&self) -> Vec<u8> {
+        let mut ihdr = Vec::with_capacity(13);
+        ihdr.extend((self.width as u32).to_be_bytes());
+        ihdr.extend((self.height as u32).to_be_bytes());
+        // 8 bits per channel, truecolor, deflate, no filter, no interlace
+        ihdr.extend([8, 2, 0, 0, 0]);
+
+        // Every scanline starts with its filter type, 0 for none.
+        let scanlines: Vec<u8> = self
+            .pixels
+            .chunks(self.width.max(1))
+            .flat_map(|row| std::iter::once(0).chain(row.iter().flatten().copied()))
+            .collect();
+
+        let mut png = b"\x89PNG\r\n\x1a\n".to_vec();
+        png_chunk(&mut png, b"IHDR", &ihdr);
+        png_chunk(&mut png, b"IDAT", &zlib_stored(&scanlines));
+        png_chunk(&mut png, b"IEND", &[]);
+        png
+    }
+
+    /// One `cell_size` square per pixel, with runs of a color merged into one rectangle.
+    pub fn to_svg(&self, cell_size: usize) -> String {
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" viewBox=\"0 0 {w} {h}\" shape-rendering=\"crispEdges\">\n",
+            w = self.width * cell_size,
+            h = self.height * cell_size
+        );
+        for (y, row) in self.pixels.chunks(self.width.max(1)).enumerate() {
+            let mut x = 0;
+            for run in row.chunk_by(|a, b| a == b) {
+                let [r, g, b] = run[0];
+                let _ = writeln!(
+                    svg,
+                    "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"#{:02x}{:02x}{:02x}\"/>",
+                    x * cell_size,
+                    y * cell_size,
+                    run.len() * cell_size,
+                    cell_size,
+                    r,
+                    g,
+                    b
+                );
+                x += run.len();
+            }
+        }
+        svg.push_str("</svg>\n");
+        svg
+    }
+}
+
+/// Save `frames` as `<prefix>_0000.<ext>`, `<prefix>_0001.<ext>`... in `directory`.
+pub fn save_frames(
+    frames: impl IntoIterator<Item = Image>,
+    directory: impl AsRef<Path>,
+    prefix: &str,
+    format: Format,
+) -> io::Result<Vec<PathBuf>> {
+    let directory = directory.as_ref();
+    fs::create_dir_all(directory)?;
+    frames
+        .into_iter()
+        .enumerate()
+        .map(|(index, frame)| {
+            let path = directory.join(format!("{}_{:04}.{}", prefix, index, format.extension()));
+            fs::write(&path, frame.encode(format))?;
+            Ok(path)
+        })
+        .collect()
+}
+
+/// A light to dark blue gradient, `value` between 0 and `max`.
+pub fn gradient(value: usize, max: usize) -> Rgb {
+    let t = value.min(max) as f64 / max.max(1) as f64;
+    let mix = |from: f64, to: f64| (from + (to - from) * t).round() as u8;
+    [mix(230.0, 8.0), mix(240.0, 48.0), mix(255.0, 107.0)]
+}
+
+//
+
+fn png_chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    png.extend((data.len() as u32).to_be_bytes());
+    png.extend(kind);
+    png.extend(data);
+    png.extend(crc32(kind.iter().chain(data)).to_be_bytes());
+}
+
+/// zlib stream made of stored (uncompressed) deflate blocks.
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    const MAX_BLOCK: usize = u16::MAX as usize;
+
+    // Deflate, 32K window, no dictionary, fastest: 0x7801 is a multiple of 31.
+    let mut zlib = vec![0x78, 0x01];
+    let blocks: Vec<&[u8]> = if data.is_empty() {
+        vec![&[]]
+    } else {
+        data.chunks(MAX_BLOCK).collect()
+    };
+    for (index, block) in blocks.iter().enumerate() {
+        let is_final = index + 1 == blocks.len();
+        zlib.push(is_final as u8);
+        let len = block.len() as u16;
+        zlib.extend(len.to_le_bytes());
+        zlib.extend((!len).to_le_bytes());
+        zlib.extend(*block);
+    }
+    zlib.extend(adler32(data).to_be_bytes());
+    zlib
+}
+
+fn crc32<'a>(bytes: impl IntoIterator<Item = &'a u8>) -> u32 {
+    !bytes.into_iter().fold(!0u32, |crc, &byte| {
+        (0..8).fold(crc ^ byte as u32, |crc, _| {
+            if crc & 1 == 1 {
+                (crc >> 1) ^ 0xEDB8_8320
+            } else {
+                crc >> 1
+            }
+        })
+    })
+}
+
+fn adler32(bytes: &[u8]) -> u32 {
+    const MOD_ADLER: u32 = 65521;
+    let (a, b) = bytes.iter().fold((1u32, 0u32), |(a, b), &byte| {
+        let a = (a + byte as u32) % MOD_ADLER;
+        (a, (b + a) % MOD_ADLER)
+    });
+    (b << 16) | a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn checkerboard() -> Image {
+        let grid = Grid::new(3, 2, vec![true, false, false, false, true, true]);
+        Image::from_grid(&grid, |&on| if on { BLACK } else { WHITE })
+    }
+
+    /// Read back the scanlines of a PNG written by `to_png`.
+    fn inflate_stored(png: &[u8]) -> Vec<u8> {
+        let idat = png.windows(4).position(|w| w == b"IDAT").unwrap();
+        let length = u32::from_be_bytes(png[idat - 4..idat].try_into().unwrap()) as usize;
+        let mut zlib = &png[idat + 4 + 2..idat + 4 + length - 4];
+        let mut data = Vec::new();
+        loop {
+            let is_final = zlib[0] & 1 == 1;
+            let len = u16::from_le_bytes([zlib[1], zlib[2]]) as usize;
+            data.extend(&zlib[5..5 + len]);
+            zlib = &zlib[5 + len..];
+            if is_final {
+                return data;
+            }
+        }
+    }
+
+    #[test]
+    fn test_checksums() {
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+        assert_eq!(adler32(b"Wikipedia"), 0x11E6_0398);
+    }
+
+    #[test]
+    fn test_ppm() {
+        let ppm = checkerboard().to_ppm();
+        assert!(ppm.starts_with(b"P6\n3 2\n255\n"));
+        assert_eq!(
+            &ppm[11..],
+            [0, 0, 0, 255, 255, 255, 255, 255, 255, 255, 255, 255, 0, 0, 0, 0, 0, 0]
+        );
+    }
+
+    #[test]
+    fn test_png() {
+        let png = checkerboard().to_png();
+        assert!(png.starts_with(b"\x89PNG\r\n\x1a\n\0\0\0\x0dIHDR\0\0\0\x03\0\0\0\x02\x08\x02"));
+        assert!(png.ends_with(b"IEND\xae\x42\x60\x82"));
+        assert_eq!(
+            inflate_stored(&png),
+            [0, 0, 0, 0, 255, 255, 255, 255, 255, 255, 0, 255, 255, 255, 0, 0, 0, 0, 0, 0]
+        );
+    }
+
+    #[test]
+    fn test_png_spans_several_blocks() {
+        let grid = Grid::new(200, 200, (0..40_000).map(|i| i % 7).collect());
+        let png = Image::from_grid(&grid, |&v| [v as u8, 0, 0]).to_png();
+        let data = inflate_stored(&png);
+        assert_eq!(data.len(), 200 * (1 + 3 * 200));
+        assert_eq!(&data[..7], [0, 0, 0, 0, 1, 0, 0]);
+    }
+
+    #[test]
+    fn test_svg_merges_runs() {
+        let svg = checkerboard().to_svg(10);
+        assert!(
+            svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"30\" height=\"20\"")
+        );
+        assert_eq!(svg.matches("<rect").count(), 4);
+        assert!(
+            svg.contains("<rect x=\"10\" y=\"0\" width=\"20\" height=\"10\" fill=\"#ffffff\"/>")
+        );
+    }
+
+    #[test]
+    fn test_scaled() {
+        let image = checkerboard().scaled(2);
+        assert_eq!((image.width(), image.height()), (6, 4));
+        assert_eq!(image.pixel(1, 1), BLACK);
+        assert_eq!(image.pixel(2, 1), WHITE);
+    }
+
+    #[test]
+    fn test_save_frames() {
+        let directory = std::env::temp_dir().join(format!("image-frames-{}", std::process::id()));
+        let frames = [checkerboard(), checkerboard().scaled(2)];
+        let paths = save_frames(frames, &directory, "step", Format::Ppm).unwrap();
+        assert_eq!(
+            paths,
+            [
+                directory.join("step_0000.ppm"),
+                directory.join("step_0001.ppm")
+            ]
+        );
+        assert!(fs::read(&paths[1]).unwrap().starts_with(b"P6\n6 4\n"));
+        fs::remove_dir_all(&directory).unwrap();
+    }
+}
@@ -0,0 +1,206 @@
+//! Number theory on `u64`, with `u128`/`i128` intermediates so products of two
+//! `u64` never overflow.
+
+/// Greatest common divisor, `gcd(0, 0) == 0`.
+pub fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Least common multiple, `None` when it doesn't fit in a `u64`.
+pub fn lcm(a: u64, b: u64) -> Option<u64> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+pub fn gcd_all(values: impl IntoIterator<Item = u64>) -> u64 {
+    values.into_iter().fold(0, gcd)
+}
+
+/// Least common multiple of all `values`, `1` when there are none.
+pub fn lcm_all(values: impl IntoIterator<Item = u64>) -> Option<u64> {
+    values.into_iter().try_fold(1, lcm)
+}
+
+/// `(g, x, y)` such that `a * x + b * y == g == gcd(a, b)`.
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// `x` in `0..modulus` such that `a * x ≡ 1 (mod modulus)`, if `a` and `modulus` are coprime.
+///
+/// `None` when `modulus` is 0.
+pub fn mod_inv(a: i64, modulus: u64) -> Option<u64> {
+    if modulus == 0 {
+        return None;
+    }
+    let modulus = modulus as i128;
+    let (g, x, _) = extended_gcd((a as i128).rem_euclid(modulus), modulus);
+    (g == 1).then(|| x.rem_euclid(modulus) as u64)
+}
+
+/// `a * b % modulus` without overflow.
+///
+/// # Panics
+///
+/// When `modulus` is 0.
+pub fn mod_mul(a: u64, b: u64, modulus: u64) -> u64 {
+    (a as u128 * b as u128 % modulus as u128) as u64
+}
+
+/// `base ^ exponent % modulus` by repeated squaring.
+///
+/// # Panics
+///
+/// When `modulus` is 0.
+pub fn mod_pow(base: u64, mut exponent: u64, modulus: u64) -> u64 {
+    if modulus == 1 {
+        return 0;
+    }
+    let mut base = base % modulus;
+    let mut result = 1;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = mod_mul(result, base, modulus);
+        }
+        base = mod_mul(base, base, modulus);
+        exponent >>= 1;
+    }
+    result
+}
+
+/// Solve `x ≡ remainder (mod modulus)` for every `(remainder, modulus)` pair.
+///
+/// Moduli don't have to be coprime. Returns the smallest `x` and the combined
+/// modulus (their lcm), or `None` when a modulus is 0, the congruences contradict
+/// each other or the combined modulus doesn't fit in a `u64`.
+pub fn crt(congruences: impl IntoIterator<Item = (i64, u64)>) -> Option<(u64, u64)> {
+    congruences.into_iter().try_fold(
+        (0, 1),
+        |(x, modulus): (u64, u64), (remainder, other_modulus)| {
+            if other_modulus == 0 {
+                return None;
+            }
+            let other = (remainder as i128).rem_euclid(other_modulus as i128);
+            let (m1, m2) = (modulus as i128, other_modulus as i128);
+            let (g, p, _) = extended_gcd(m1, m2);
+            let difference = other - x as i128;
+            if difference % g != 0 {
+                return None;
+            }
+            let combined = lcm(modulus, other_modulus)? as u128;
+            // x + m1 * k ≡ other (mod m2) with k = difference / g * p (mod m2 / g)
+            let step = m2 / g;
+            let k = (difference / g).rem_euclid(step) as u128 * p.rem_euclid(step) as u128
+                % step as u128;
+            let solution = (x as u128 + m1 as u128 * k) % combined;
+            Some((solution as u64, combined as u64))
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn test_gcd_lcm() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(0, 5), 5);
+        assert_eq!(lcm(4, 6), Some(12));
+        assert_eq!(lcm(u64::MAX, 2), None);
+        assert_eq!(gcd_all([12, 18, 27]), 3);
+        assert_eq!(lcm_all([2, 3, 4, 6]), Some(12));
+        assert_eq!(lcm_all([]), Some(1));
+    }
+
+    #[test]
+    fn test_extended_gcd() {
+        let (g, x, y) = extended_gcd(240, 46);
+        assert_eq!(g, 2);
+        assert_eq!(240 * x + 46 * y, 2);
+    }
+
+    #[test]
+    fn test_mod_inv() {
+        assert_eq!(mod_inv(3, 11), Some(4));
+        assert_eq!(mod_inv(-3, 11), Some(7));
+        assert_eq!(mod_inv(6, 9), None);
+        assert_eq!(mod_inv(3, 0), None);
+    }
+
+    #[test]
+    fn test_mod_pow() {
+        assert_eq!(mod_pow(4, 13, 497), 445);
+        assert_eq!(mod_pow(2, 0, 7), 1);
+        assert_eq!(mod_pow(7, 5, 1), 0);
+        assert_eq!(mod_pow(u64::MAX - 1, 2, u64::MAX), 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_mod_pow_modulus_0() {
+        mod_pow(2, 3, 0);
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        // Not coprime but consistent
+        assert_eq!(crt([(2, 4), (4, 6)]), Some((10, 12)));
+        // Not coprime and contradicting: x is even and odd
+        assert_eq!(crt([(1, 4), (2, 6)]), None);
+        assert_eq!(crt([(-1, 5)]), Some((4, 5)));
+        assert_eq!(crt([]), Some((0, 1)));
+        assert_eq!(crt([(2, 3), (0, 0)]), None);
+    }
+
+    proptest! {
+        #[test]
+        fn crt_solves_every_congruence(x in 0..1_000_000u64, moduli in prop::collection::vec(1..1000u64, 1..5)) {
+            let congruences: Vec<(i64, u64)> = moduli.iter().map(|&m| ((x % m) as i64, m)).collect();
+            let (solution, modulus) = crt(congruences).unwrap();
+            prop_assert_eq!(Some(modulus), lcm_all(moduli.iter().copied()));
+            prop_assert_eq!(solution, x % modulus);
+        }
+
+        #[test]
+        fn crt_handles_large_moduli(x in any::<u64>(), a in 1u64 << 40..1u64 << 62, b in 1u64 << 20..1u64 << 24) {
+            if let Some(modulus) = lcm(a, b) {
+                let (solution, combined) = crt([((x % a) as i64, a), ((x % b) as i64, b)]).unwrap();
+                prop_assert_eq!(combined, modulus);
+                prop_assert_eq!(solution, x % modulus);
+            }
+        }
+
+        #[test]
+        fn mod_inv_is_an_inverse(a in any::<i64>(), modulus in 2..u64::MAX) {
+            match mod_inv(a, modulus) {
+                Some(inverse) => {
+                    let a = (a as i128).rem_euclid(modulus as i128) as u64;
+                    prop_assert_eq!(mod_mul(a, inverse, modulus), 1);
+                }
+                None => prop_assert_ne!(gcd((a as i128).rem_euclid(modulus as i128) as u64, modulus), 1),
+            }
+        }
+    }
+}
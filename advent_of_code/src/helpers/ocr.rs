@@ -0,0 +1,328 @@
+//! Recognize the block letters drawn by some puzzles (e.g. 2022 day 10, 2018 day 10).
+//!
+//! Two fonts are known: 4x6 letters drawn every 5 columns and 6x10 letters drawn
+//! every 8 columns. The font is picked from the height of the picture once blank
+//! rows are trimmed.
+
+use std::fmt::Display;
+
+#[derive(Debug, PartialEq)]
+pub enum OcrError {
+    /// The picture is neither 6 nor 10 rows high.
+    UnsupportedHeight(usize),
+    /// The glyph starting at `column` is not a known letter.
+    UnknownGlyph { column: usize, glyph: String },
+}
+
+impl Display for OcrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OcrError::UnsupportedHeight(height) => {
+                write!(f, "no font is {} rows high, expected 6 or 10", height)
+            }
+            OcrError::UnknownGlyph { column, glyph } => {
+                write!(f, "unknown glyph at column {}:\n{}", column, glyph)
+            }
+        }
+    }
+}
+
+struct Font {
+    width: usize,
+    height: usize,
+    pitch: usize,
+    glyphs: &'static [(char, &'static str)],
+}
+
+const FONT_4X6: Font = Font {
+    width: 4,
+    height: 6,
+    pitch: 5,
+    glyphs: &[
+        ('A', ".##.#..##..######..##..#"),
+        ('B', "###.#..####.#..##..####."),
+        ('C', ".##.#..##...#...#..#.##."),
+        ('E', "#####...###.#...#...####"),
+        ('F', "#####...###.#...#...#..."),
+        ('G', ".##.#..##...#.###..#.###"),
+        ('H', "#..##..######..##..##..#"),
+        ('I', ".###..#...#...#...#..###"),
+        ('J', "..##...#...#...##..#.##."),
+        ('K', "#..##.#.##..#.#.#.#.#..#"),
+        ('L', "#...#...#...#...#...####"),
+        ('O', ".##.#..##..##..##..#.##."),
+        ('P', "###.#..##..####.#...#..."),
+        ('R', "###.#..##..####.#.#.#..#"),
+        ('S', ".####...#....##....####."),
+        ('U', "#..##..##..##..##..#.##."),
+        ('Y', "#...#....#.#..#...#...#."),
+        ('Z', "####...#..#..#..#...####"),
+    ],
+};
+
+const FONT_6X10: Font = Font {
+    width: 6,
+    height: 10,
+    pitch: 8,
+    glyphs: &[
+        (
+            'A',
+            "..##...#..#.#....##....##....########....##....##....##....#",
+        ),
+        (
+            'B',
+            "#####.#....##....##....######.#....##....##....##....######.",
+        ),
+        (
+            'C',
+            ".####.#....##.....#.....#.....#.....#.....#.....#....#.####.",
+        ),
+        (
+            'E',
+            "#######.....#.....#.....#####.#.....#.....#.....#.....######",
+        ),
+        (
+            'F',
+            "#######.....#.....#.....#####.#.....#.....#.....#.....#.....",
+        ),
+        (
+            'G',
+            ".####.#....##.....#.....#.....#..####....##....##...##.###.#",
+        ),
+        (
+            'H',
+            "#....##....##....##....########....##....##....##....##....#",
+        ),
+        (
+            'J',
+            "...###....#.....#.....#.....#.....#.....#.#...#.#...#..###..",
+        ),
+        (
+            'K',
+            "#....##...#.#..#..#.#...##....##....#.#...#..#..#...#.#....#",
+        ),
+        (
+            'L',
+            "#.....#.....#.....#.....#.....#.....#.....#.....#.....######",
+        ),
+        (
+            'N',
+            "#....###...###...##.#..##.#..##..#.##..#.##...###...###....#",
+        ),
+        (
+            'P',
+            "#####.#....##....##....######.#.....#.....#.....#.....#.....",
+        ),
+        (
+            'R',
+            "#####.#....##....##....######.#..#..#...#.#...#.#....##....#",
+        ),
+        (
+            'X',
+            "#....##....#.#..#..#..#...##....##...#..#..#..#.#....##....#",
+        ),
+        (
+            'Z',
+            "######.....#.....#....#....#....#....#....#.....#.....######",
+        ),
+    ],
+};
+
+/// Recognize a picture where `#` is a lit pixel and anything else is blank.
+pub fn recognize(picture: &str) -> Result<String, OcrError> {
+    let grid: Vec<Vec<bool>> = picture
+        .lines()
+        .map(|line| line.chars().map(|c| c == '#').collect())
+        .collect();
+    recognize_grid(&grid)
+}
+
+/// Recognize a picture given as rows of lit pixels. Rows may have different lengths.
+pub fn recognize_grid<R: AsRef<[bool]>>(grid: &[R]) -> Result<String, OcrError> {
+    let is_blank = |row: &R| !row.as_ref().iter().any(|&lit| lit);
+    let top = grid.iter().position(|row| !is_blank(row)).unwrap_or(0);
+    let bottom = grid
+        .iter()
+        .rposition(|row| !is_blank(row))
+        .map_or(0, |i| i + 1);
+    let rows = &grid[top..bottom.max(top)];
+
+    let font = match rows.len() {
+        6 => FONT_4X6,
+        10 => FONT_6X10,
+        height => return Err(OcrError::UnsupportedHeight(height)),
+    };
+
+    let lit = |row: usize, col: usize| rows[row].as_ref().get(col).copied().unwrap_or(false);
+    let width = rows.iter().map(|row| row.as_ref().len()).max().unwrap_or(0);
+    let column_is_blank = |col: usize| (0..font.height).all(|row| !lit(row, col));
+    // The large font is usually drawn away from the left edge.
+    let left = match font.height {
+        10 => (0..width).find(|&col| !column_is_blank(col)).unwrap_or(0),
+        _ => 0,
+    };
+
+    (left..width)
+        .step_by(font.pitch)
+        .filter(|&start| !(start..start + font.width).all(column_is_blank))
+        .map(|start| {
+            let glyph: String = (0..font.height)
+                .flat_map(|row| (start..start + font.width).map(move |col| (row, col)))
+                .map(|(row, col)| if lit(row, col) { '#' } else { '.' })
+                .collect();
+            font.glyphs
+                .iter()
+                .find(|(_, pattern)| *pattern == glyph)
+                .map(|(letter, _)| *letter)
+                .ok_or_else(|| OcrError::UnknownGlyph {
+                    column: start,
+                    glyph: glyph
+                        .as_bytes()
+                        .chunks(font.width)
+                        .map(|row| String::from_utf8_lossy(row).into_owned())
+                        .collect::<Vec<_>>()
+                        .join("\n"),
+                })
+        })
+        .collect()
+}
+
+/// Letters of the 4x6 font, the one `draw` uses.
+pub fn alphabet() -> impl Iterator<Item = char> {
+    FONT_4X6.glyphs.iter().map(|(letter, _)| *letter)
+}
+
+/// Rows of lit pixels spelling `text` in the 4x6 font, or `None` for an unknown letter.
+pub fn draw(text: &str) -> Option<Vec<Vec<bool>>> {
+    let font = FONT_4X6;
+    let glyphs = text
+        .chars()
+        .map(|letter| {
+            font.glyphs
+                .iter()
+                .find(|(known, _)| *known == letter)
+                .map(|(_, pattern)| pattern.as_bytes())
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    let rows = (0..font.height)
+        .map(|row| {
+            glyphs
+                .iter()
+                .flat_map(|glyph| {
+                    let pixels = &glyph[row * font.width..(row + 1) * font.width];
+                    let blank = font.pitch - font.width;
+                    pixels
+                        .iter()
+                        .map(|&pixel| pixel == b'#')
+                        .chain(std::iter::repeat_n(false, blank))
+                })
+                .collect()
+        })
+        .collect();
+    Some(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recognize_4x6() {
+        let picture = "
+####.#..#.###..####.###....##..##..#....
+#....#..#.#..#....#.#..#....#.#..#.#....
+###..####.#..#...#..#..#....#.#....#....
+#....#..#.###...#...###.....#.#.##.#....
+#....#..#.#....#....#....#..#.#..#.#....
+####.#..#.#....####.#.....##...###.####.
+";
+        assert_eq!(recognize(picture), Ok("EHPZPJGL".to_string()));
+    }
+
+    #[test]
+    fn test_unknown_glyph_column() {
+        let picture = "
+.##..###
+#..#.#..
+#..#.###
+####.#..
+#..#.#..
+#..#.###
+";
+        assert_eq!(
+            recognize(picture),
+            Err(OcrError::UnknownGlyph {
+                column: 5,
+                glyph: "###.\n#...\n###.\n#...\n#...\n###.".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_recognize_6x10() {
+        let picture = "
+...........................
+...#....#..######....##....
+...#....#..#........#..#...
+...#....#..#.......#....#..
+...#....#..#.......#....#..
+...######..#####...#....#..
+...#....#..#.......######..
+...#....#..#.......#....#..
+...#....#..#.......#....#..
+...#....#..#.......#....#..
+...#....#..######..#....#..
+";
+        assert_eq!(recognize(picture), Ok("HEA".to_string()));
+    }
+
+    #[test]
+    fn test_recognize_grid() {
+        let grid = [
+            [true, true, true, true],
+            [true, false, false, false],
+            [true, true, true, false],
+            [true, false, false, false],
+            [true, false, false, false],
+            [true, false, false, false],
+        ];
+        assert_eq!(recognize_grid(&grid), Ok("F".to_string()));
+    }
+
+    #[test]
+    fn test_unknown_glyph() {
+        let picture = "
+##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######.....
+";
+        assert_eq!(
+            recognize(picture),
+            Err(OcrError::UnknownGlyph {
+                column: 0,
+                glyph: "##..\n###.\n####\n####\n####\n####".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_unsupported_height() {
+        assert_eq!(
+            recognize("#..#\n####\n#..#"),
+            Err(OcrError::UnsupportedHeight(3))
+        );
+    }
+
+    #[test]
+    fn test_draw_round_trip() {
+        let word: String = alphabet().collect();
+        let rows = draw(&word).unwrap();
+        assert_eq!((rows.len(), rows[0].len()), (6, 5 * word.len()));
+        assert_eq!(recognize_grid(&rows), Ok(word));
+        assert_eq!(draw("AQ"), None);
+    }
+}
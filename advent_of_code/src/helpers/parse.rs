@@ -0,0 +1,376 @@
+//! Shared nom combinators for puzzle inputs.
+//!
+//! Every parser uses `VerboseError` so that `parse_all` can point at the line,
+//! column and token where the input stopped making sense.
+
+use std::{cell::RefCell, fmt::Display, str::FromStr};
+
+use nom::{
+    bytes::complete::{tag, take_while1},
+    character::complete::{
+        char, digit1, line_ending, multispace0, none_of, one_of, space0, space1,
+    },
+    combinator::{all_consuming, map_opt, map_res, opt, recognize, verify},
+    error::{context, ErrorKind, VerboseError, VerboseErrorKind},
+    multi::{many1, separated_list1},
+    sequence::{pair, preceded, separated_pair, terminated, tuple},
+    Finish, Offset, Parser,
+};
+
+//
+
+pub type IResult<I, O> = nom::IResult<I, O, VerboseError<I>>;
+
+/// A parse failure located in the source text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based line of the offending token.
+    pub line: usize,
+    /// 1-based column, in chars, of the offending token.
+    pub column: usize,
+    /// The text found where the parser failed, up to the next whitespace.
+    pub token: String,
+    pub message: String,
+    source_line: String,
+}
+
+impl ParseError {
+    /// Locate `token`, a sub-slice of `source`, and describe why it was rejected.
+    pub fn new(source: &str, token: &str, message: impl Into<String>) -> Self {
+        let offset = if source.as_ptr() <= token.as_ptr()
+            && token.as_ptr() as usize <= source.as_ptr() as usize + source.len()
+        {
+            source.offset(token)
+        } else {
+            0
+        };
+        let before = &source[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let line_end = source[offset..]
+            .find('\n')
+            .map_or(source.len(), |i| offset + i);
+
+        Self {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            token: source[offset..]
+                .split(char::is_whitespace)
+                .next()
+                .unwrap_or_default()
+                .to_string(),
+            message: message.into(),
+            source_line: source[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_string(),
+        }
+    }
+
+    /// Move the location to the 1-based `line` of a larger input that `source` was taken from.
+    pub fn at_line(mut self, line: usize) -> Self {
+        self.line += line - 1;
+        self
+    }
+
+    /// Move the location of an error raised while parsing `token` alone to where
+    /// `token` sits in `source`.
+    pub fn within(self, source: &str, token: &str) -> Self {
+        let mut error = Self::new(source, token, self.message);
+        error.column += self.column - 1;
+        error.token = self.token;
+        error
+    }
+
+    fn from_verbose(source: &str, error: VerboseError<&str>) -> Self {
+        let token = error.errors.first().map_or(source, |(input, _)| *input);
+        let message = error
+            .errors
+            .iter()
+            .map(|(_, kind)| match kind {
+                VerboseErrorKind::Context(context) => context.to_string(),
+                VerboseErrorKind::Char(c) => format!("expected '{}'", c),
+                VerboseErrorKind::Nom(ErrorKind::Eof) => "unexpected input".to_string(),
+                VerboseErrorKind::Nom(kind) => format!("expected {:?}", kind),
+            })
+            .collect::<Vec<_>>()
+            .join(" in ");
+        Self::new(source, token, message)
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let token = if self.token.is_empty() {
+            "end of line"
+        } else {
+            &self.token
+        };
+        writeln!(
+            f,
+            "line {}, column {}: {} (found `{}`)",
+            self.line, self.column, self.message, token
+        )?;
+        writeln!(f, "  {}", self.source_line)?;
+        write!(f, "  {:>width$}", "^", width = self.column)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+thread_local! {
+    static REPORTED: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// The value of `result`, or `None` with the error kept for `solve!` to print, so
+/// that a part gives up on an invalid input without panicking.
+pub fn report<T>(context: &str, result: Result<T, ParseError>) -> Option<T> {
+    result
+        .map_err(|error| {
+            let message = format!("{}, {}", context, error);
+            REPORTED.with(|reported| *reported.borrow_mut() = Some(message));
+        })
+        .ok()
+}
+
+/// The last error given to `report` on this thread since the previous call.
+pub fn take_reported() -> Option<String> {
+    REPORTED.with(|reported| reported.borrow_mut().take())
+}
+
+/// Run `parser` on the whole input, allowing only trailing whitespace.
+pub fn parse_all<'a, O, F>(parser: F, input: &'a str) -> Result<O, ParseError>
+where
+    F: Parser<&'a str, O, VerboseError<&'a str>>,
+{
+    all_consuming(terminated(parser, multispace0))(input)
+        .finish()
+        .map(|(_, output)| output)
+        .map_err(|error| ParseError::from_verbose(input, error))
+}
+
+/// Parse each line with `FromStr`, reporting errors at their line in `input`.
+pub fn parse_lines<T>(input: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr<Err = ParseError>,
+{
+    map_lines(input, str::parse)
+}
+
+/// Parse each line with `parser`, reporting errors at their line in `input`.
+pub fn map_lines<T, F>(input: &str, parser: F) -> Result<Vec<T>, ParseError>
+where
+    F: Fn(&str) -> Result<T, ParseError>,
+{
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| parser(line).map_err(|e| e.at_line(index + 1)))
+        .collect()
+}
+
+//
+
+/// `42`
+pub fn unsigned<T: FromStr>(input: &str) -> IResult<&str, T> {
+    context("unsigned integer", map_res(digit1, str::parse))(input)
+}
+
+/// `42`, `+42` or `-42`
+pub fn signed<T: FromStr>(input: &str) -> IResult<&str, T> {
+    context(
+        "signed integer",
+        map_res(recognize(pair(opt(one_of("+-")), digit1)), str::parse),
+    )(input)
+}
+
+/// `a b  c`
+pub fn space_separated<'a, O, F>(item: F) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>>
+where
+    F: Parser<&'a str, O, VerboseError<&'a str>>,
+{
+    separated_list1(space1, item)
+}
+
+/// `a,b, c`
+pub fn comma_separated<'a, O, F>(item: F) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>>
+where
+    F: Parser<&'a str, O, VerboseError<&'a str>>,
+{
+    separated_list1(pair(char(','), space0), item)
+}
+
+/// One `item` per line.
+pub fn lines<'a, O, F>(item: F) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>>
+where
+    F: Parser<&'a str, O, VerboseError<&'a str>>,
+{
+    separated_list1(line_ending, item)
+}
+
+/// Blocks separated by a blank line.
+pub fn blocks<'a, O, F>(block: F) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>>
+where
+    F: Parser<&'a str, O, VerboseError<&'a str>>,
+{
+    separated_list1(pair(line_ending, line_ending), block)
+}
+
+/// Rectangular grid of characters, each one mapped by `cell`.
+pub fn char_grid<'a, T, F>(cell: F) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<Vec<T>>>
+where
+    F: Fn(char) -> Option<T> + Copy,
+{
+    context(
+        "rectangular grid",
+        verify(
+            separated_list1(line_ending, many1(map_opt(none_of("\r\n"), cell))),
+            |rows: &Vec<Vec<T>>| rows.iter().all(|row| row.len() == rows[0].len()),
+        ),
+    )
+}
+
+/// `Starting items: 79, 98` with `key` "Starting items".
+pub fn field<'a, O, F>(key: &'static str, value: F) -> impl FnMut(&'a str) -> IResult<&'a str, O>
+where
+    F: Parser<&'a str, O, VerboseError<&'a str>>,
+{
+    context(
+        key,
+        preceded(tuple((space0, tag(key), char(':'), space0)), value),
+    )
+}
+
+/// `key: value` for any key.
+pub fn record<'a, O, F>(value: F) -> impl FnMut(&'a str) -> IResult<&'a str, (&'a str, O)>
+where
+    F: Parser<&'a str, O, VerboseError<&'a str>>,
+{
+    separated_pair(
+        preceded(space0, take_while1(|c: char| c != ':' && c != '\n')),
+        pair(char(':'), space0),
+        value,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_integers() {
+        assert_eq!(unsigned::<u8>("42 rest"), Ok((" rest", 42)));
+        assert!(unsigned::<u8>("256").is_err());
+        assert!(unsigned::<u32>("-1").is_err());
+        assert_eq!(signed::<i64>("-5"), Ok(("", -5)));
+        assert_eq!(signed::<i64>("+5"), Ok(("", 5)));
+    }
+
+    #[test]
+    fn test_lists() {
+        assert_eq!(
+            parse_all(space_separated(unsigned::<u32>), "1 2  3"),
+            Ok(vec![1, 2, 3])
+        );
+        assert_eq!(
+            parse_all(comma_separated(signed::<i32>), "1,-2, 3"),
+            Ok(vec![1, -2, 3])
+        );
+    }
+
+    #[test]
+    fn test_blocks_of_lines() {
+        assert_eq!(
+            parse_all(blocks(lines(unsigned::<u32>)), "1000\n2000\n\n4000\n"),
+            Ok(vec![vec![1000, 2000], vec![4000]])
+        );
+    }
+
+    #[test]
+    fn test_char_grid() {
+        let digit = |c: char| c.to_digit(10);
+        assert_eq!(
+            parse_all(char_grid(digit), "123\n456"),
+            Ok(vec![vec![1, 2, 3], vec![4, 5, 6]])
+        );
+        assert!(parse_all(char_grid(digit), "123\n45").is_err());
+    }
+
+    #[test]
+    fn test_field_and_record() {
+        assert_eq!(
+            parse_all(
+                field("Starting items", comma_separated(unsigned::<u64>)),
+                "  Starting items: 79, 98"
+            ),
+            Ok(vec![79, 98])
+        );
+        assert_eq!(
+            parse_all(lines(record(unsigned::<u32>)), "a: 1\nb c: 2"),
+            Ok(vec![("a", 1), ("b c", 2)])
+        );
+    }
+
+    #[test]
+    fn test_parse_all_location() {
+        let error = parse_all(blocks(lines(unsigned::<u32>)), "1000\n2000\n\n40x0\n").unwrap_err();
+        assert_eq!((error.line, error.column), (4, 3));
+        assert_eq!(error.token, "x0");
+        assert_eq!(
+            error.to_string(),
+            "line 4, column 3: unexpected input (found `x0`)\n  40x0\n    ^"
+        );
+    }
+
+    #[test]
+    fn test_parse_error_at_line() {
+        let line = "addx five";
+        let error = ParseError::new(line, &line[5..], "invalid number").at_line(12);
+        assert_eq!((error.line, error.column), (12, 6));
+        assert_eq!(error.token, "five");
+    }
+
+    #[test]
+    fn test_parse_error_within() {
+        let line = "A W";
+        let error =
+            ParseError::new(&line[2..], &line[2..], "invalid letter").within(line, &line[2..]);
+        assert_eq!((error.line, error.column), (1, 3));
+        assert_eq!(error.token, "W");
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        #[derive(Debug, PartialEq)]
+        struct Even(u32);
+        impl FromStr for Even {
+            type Err = ParseError;
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s.parse() {
+                    Ok(n) if n % 2 == 0 => Ok(Even(n)),
+                    _ => Err(ParseError::new(s, s, "expected an even number")),
+                }
+            }
+        }
+        assert_eq!(parse_lines("2\n4"), Ok(vec![Even(2), Even(4)]));
+        let error = parse_lines::<Even>("2\n4\n5").unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.token.as_str()),
+            (3, 1, "5")
+        );
+    }
+    #[test]
+    fn test_report() {
+        assert_eq!(
+            report("invalid list", parse_all(unsigned::<u32>, "12")),
+            Some(12)
+        );
+        assert_eq!(take_reported(), None);
+
+        assert_eq!(
+            report("invalid list", parse_all(unsigned::<u32>, "1x")),
+            None
+        );
+        let error = take_reported().unwrap();
+        assert!(error.starts_with("invalid list, line 1, column 2:"));
+        assert_eq!(take_reported(), None);
+    }
+}
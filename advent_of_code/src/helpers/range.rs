@@ -0,0 +1,360 @@
+//! Sets of integers stored as sorted, disjoint, half-open ranges.
+
+use std::ops::{Range, RangeInclusive};
+
+use super::geometry::Coord;
+
+/// A set of values kept as the fewest sorted `start..end` ranges.
+///
+/// Ranges never overlap nor touch: `1..3` and `3..5` are stored as `1..5`. No half-open
+/// range holds `T::MAX`, so it is kept apart, inserted by `insert_inclusive` only.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RangeSet<T> {
+    ranges: Vec<Range<T>>,
+    has_max: bool,
+}
+
+impl<T> Default for RangeSet<T> {
+    fn default() -> Self {
+        Self {
+            ranges: Vec::new(),
+            has_max: false,
+        }
+    }
+}
+
+impl<T: Coord> RangeSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The ranges of the set, without `T::MAX`.
+    pub fn ranges(&self) -> &[Range<T>] {
+        &self.ranges
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, Range<T>> {
+        self.ranges.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty() && !self.has_max
+    }
+
+    /// Number of values in the set, `None` when it doesn't fit in a `T`, as for the whole domain.
+    pub fn coverage(&self) -> Option<T> {
+        let max = if self.has_max { T::ONE } else { T::ZERO };
+        self.ranges.iter().try_fold(max, |total, range| {
+            total.checked_add(range.end.checked_sub(range.start)?)
+        })
+    }
+
+    pub fn insert(&mut self, range: Range<T>) {
+        if range.start >= range.end {
+            return;
+        }
+        // First range ending at or after `range.start`, i.e. the first one it may touch.
+        let first = self.ranges.partition_point(|r| r.end < range.start);
+        let last = self.ranges.partition_point(|r| r.start <= range.end);
+        let merged = match self.ranges[first..last] {
+            [] => range,
+            ref touched => {
+                touched[0].start.min(range.start)..touched[touched.len() - 1].end.max(range.end)
+            }
+        };
+        self.ranges.splice(first..last, [merged]);
+    }
+
+    pub fn insert_inclusive(&mut self, range: RangeInclusive<T>) {
+        let (start, end) = range.into_inner();
+        if start > end {
+            return;
+        }
+        match end.checked_add(T::ONE) {
+            Some(end) => self.insert(start..end),
+            None => {
+                self.insert(start..end);
+                self.has_max = true;
+            }
+        }
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        if value == T::MAX {
+            return self.has_max;
+        }
+        let index = self.ranges.partition_point(|r| r.end <= value);
+        self.ranges
+            .get(index)
+            .is_some_and(|range| range.start <= value)
+    }
+
+    /// Whether every value of `range` is in the set.
+    pub fn contains_range(&self, range: &Range<T>) -> bool {
+        if range.start >= range.end {
+            return true;
+        }
+        let index = self.ranges.partition_point(|r| r.end <= range.start);
+        self.ranges
+            .get(index)
+            .is_some_and(|r| r.start <= range.start && range.end <= r.end)
+    }
+
+    pub fn is_superset(&self, other: &Self) -> bool {
+        (self.has_max || !other.has_max) && other.iter().all(|range| self.contains_range(range))
+    }
+
+    pub fn intersects(&self, other: &Self) -> bool {
+        !self.intersection(other).is_empty()
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        for range in other.iter() {
+            union.insert(range.clone());
+        }
+        union.has_max |= other.has_max;
+        union
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while let (Some(a), Some(b)) = (self.ranges.get(i), other.ranges.get(j)) {
+            let (start, end) = (a.start.max(b.start), a.end.min(b.end));
+            if start < end {
+                ranges.push(start..end);
+            }
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        Self {
+            ranges,
+            has_max: self.has_max && other.has_max,
+        }
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let mut j = 0;
+        for range in self.iter() {
+            let mut start = range.start;
+            while let Some(cut) = other.ranges.get(j).filter(|cut| cut.start < range.end) {
+                if cut.end <= start {
+                    j += 1;
+                    continue;
+                }
+                if start < cut.start {
+                    ranges.push(start..cut.start);
+                }
+                start = start.max(cut.end);
+                if cut.end > range.end {
+                    break;
+                }
+                j += 1;
+            }
+            if start < range.end {
+                ranges.push(start..range.end);
+            }
+        }
+        Self {
+            ranges,
+            has_max: self.has_max && !other.has_max,
+        }
+    }
+}
+
+impl<T: Coord> From<Range<T>> for RangeSet<T> {
+    fn from(range: Range<T>) -> Self {
+        std::iter::once(range).collect()
+    }
+}
+
+impl<T: Coord> From<RangeInclusive<T>> for RangeSet<T> {
+    fn from(range: RangeInclusive<T>) -> Self {
+        std::iter::once(range).collect()
+    }
+}
+
+impl<T: Coord> FromIterator<Range<T>> for RangeSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        for range in iter {
+            set.insert(range);
+        }
+        set
+    }
+}
+
+impl<T: Coord> FromIterator<RangeInclusive<T>> for RangeSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        for range in iter {
+            set.insert_inclusive(range);
+        }
+        set
+    }
+}
+
+/// Cut `range` where it meets the `mappings` ranges, in order.
+///
+/// Each piece comes with the index of the mapping covering it, or `None` for the
+/// gaps between mappings. When mappings overlap, the one starting first wins.
+pub fn split_range<T: Coord>(
+    range: Range<T>,
+    mappings: &[Range<T>],
+) -> Vec<(Range<T>, Option<usize>)> {
+    let mut overlaps: Vec<_> = mappings
+        .iter()
+        .enumerate()
+        .map(|(index, m)| (m.start.max(range.start)..m.end.min(range.end), index))
+        .filter(|(overlap, _)| overlap.start < overlap.end)
+        .collect();
+    overlaps.sort_by_key(|(overlap, index)| (overlap.start, *index));
+
+    let mut pieces = Vec::new();
+    let mut cursor = range.start;
+    for (overlap, index) in overlaps {
+        let start = overlap.start.max(cursor);
+        if start >= overlap.end {
+            continue;
+        }
+        if cursor < start {
+            pieces.push((cursor..start, None));
+        }
+        pieces.push((start..overlap.end, Some(index)));
+        cursor = overlap.end;
+    }
+    if cursor < range.end {
+        pieces.push((cursor..range.end, None));
+    }
+    pieces
+}
+
+#[cfg(test)]
+#[allow(clippy::single_range_in_vec_init)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use proptest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn test_insert_merges() {
+        let set: RangeSet<i32> = [5..7, 1..3, 3..4, 10..12, 6..11].into_iter().collect();
+        assert_eq!(set.ranges(), [1..4, 5..12]);
+        assert_eq!(set.coverage(), Some(10));
+        let set: RangeSet<u8> = [2..=4, 6..=8, 5..=5].into_iter().collect();
+        assert_eq!(set.ranges(), [2..9]);
+    }
+
+    #[test]
+    fn test_insert_up_to_max() {
+        let mut set: RangeSet<u8> = [250..=255, 0..=0].into_iter().collect();
+        assert_eq!(set.ranges(), [0..1, 250..255]);
+        assert!(set.contains(255) && set.contains(254) && !set.contains(249));
+        assert_eq!(set.coverage(), Some(7));
+        set.insert_inclusive(255..=255);
+        assert_eq!(set.coverage(), Some(7));
+
+        let top = RangeSet::from(255..=255);
+        assert!(!top.is_empty());
+        assert!(set.is_superset(&top) && !RangeSet::from(0..=254).is_superset(&top));
+        assert_eq!(set.intersection(&top), top);
+        assert!(set.difference(&top).contains(254) && !set.difference(&top).contains(255));
+        assert!(top.difference(&set).is_empty());
+        assert!(RangeSet::from(0..1).union(&top).contains(255));
+    }
+
+    #[test]
+    fn test_coverage_of_whole_domain() {
+        assert_eq!(RangeSet::<u8>::from(0..=254).coverage(), Some(255));
+        assert_eq!(RangeSet::<u8>::from(1..=255).coverage(), Some(255));
+        assert_eq!(RangeSet::<u8>::from(0..=255).coverage(), None);
+        assert_eq!(RangeSet::<i8>::from(-128..=126).coverage(), None);
+        assert_eq!(RangeSet::<i8>::from(-1..=125).coverage(), Some(127));
+    }
+
+    #[test]
+    fn test_contains() {
+        let set: RangeSet<i32> = [1..4, 6..8].into_iter().collect();
+        assert!(set.contains(1) && set.contains(3) && set.contains(7));
+        assert!(!set.contains(4) && !set.contains(0) && !set.contains(8));
+        assert!(set.contains_range(&(1..4)));
+        assert!(!set.contains_range(&(3..7)));
+        assert!(set.is_superset(&RangeSet::from(6..=7)));
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a: RangeSet<i32> = [0..10, 20..30].into_iter().collect();
+        let b = RangeSet::from(5..25);
+        assert_eq!(a.union(&b).ranges(), [0..30]);
+        assert_eq!(a.intersection(&b).ranges(), [5..10, 20..25]);
+        assert_eq!(a.difference(&b).ranges(), [0..5, 25..30]);
+        assert_eq!(b.difference(&a).ranges(), [10..20]);
+        assert!(a.intersects(&b));
+        assert!(!a.intersects(&RangeSet::from(10..20)));
+    }
+
+    #[test]
+    fn test_split_range() {
+        assert_eq!(
+            split_range(0..20, &[15..30, 2..5, 4..8]),
+            vec![
+                (0..2, None),
+                (2..5, Some(1)),
+                (5..8, Some(2)),
+                (8..15, None),
+                (15..20, Some(0))
+            ]
+        );
+        assert_eq!(split_range(0..3, &[5..8]), vec![(0..3, None)]);
+    }
+
+    fn brute(ranges: &[Range<i32>]) -> BTreeSet<i32> {
+        ranges.iter().flat_map(|r| r.clone()).collect()
+    }
+
+    fn ranges_strategy() -> impl Strategy<Value = Vec<Range<i32>>> {
+        prop::collection::vec((0..64i32, 0..12i32).prop_map(|(s, l)| s..s + l), 0..6)
+    }
+
+    proptest! {
+        #[test]
+        fn matches_a_brute_force_set(a in ranges_strategy(), b in ranges_strategy(), value in 0..80i32) {
+            let (set_a, set_b): (RangeSet<i32>, RangeSet<i32>) = (a.iter().cloned().collect(), b.iter().cloned().collect());
+            let (brute_a, brute_b) = (brute(&a), brute(&b));
+
+            prop_assert_eq!(brute(set_a.ranges()), brute_a.clone());
+            prop_assert!(set_a.ranges().windows(2).all(|w| w[0].end < w[1].start));
+            prop_assert!(set_a.iter().all(|r| r.start < r.end));
+            prop_assert_eq!(set_a.coverage().unwrap() as usize, brute_a.len());
+            prop_assert_eq!(set_a.contains(value), brute_a.contains(&value));
+
+            prop_assert_eq!(brute(set_a.union(&set_b).ranges()), &brute_a | &brute_b);
+            prop_assert_eq!(brute(set_a.intersection(&set_b).ranges()), &brute_a & &brute_b);
+            prop_assert_eq!(brute(set_a.difference(&set_b).ranges()), &brute_a - &brute_b);
+            prop_assert_eq!(set_a.is_superset(&set_b), brute_a.is_superset(&brute_b));
+            prop_assert_eq!(set_a.intersects(&set_b), !brute_a.is_disjoint(&brute_b));
+        }
+
+        #[test]
+        fn split_range_partitions_the_range(range in (0..64i32, 0..20i32).prop_map(|(s, l)| s..s + l), mappings in ranges_strategy()) {
+            let pieces = split_range(range.clone(), &mappings);
+            let values: Vec<i32> = pieces.iter().flat_map(|(piece, _)| piece.clone()).collect();
+            prop_assert_eq!(values, range.clone().collect::<Vec<_>>());
+            for (piece, index) in pieces {
+                prop_assert!(piece.start < piece.end);
+                match index {
+                    Some(index) => prop_assert!(piece.clone().all(|v| mappings[index].contains(&v))),
+                    None => prop_assert!(piece.clone().all(|v| mappings.iter().all(|m| !m.contains(&v)))),
+                }
+            }
+        }
+    }
+}
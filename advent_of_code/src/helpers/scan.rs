@@ -0,0 +1,155 @@
+//! What `scan!` expands to: a cursor over a line, matching text and parsing values.
+//!
+//! `scan!(line, "move {usize} from {usize} to {usize}")` checks the literal text and
+//! parses each placeholder up to the text that follows it, without backtracking, which
+//! is all simple line formats need. Errors are `ParseError`s pointing into the line.
+
+use std::str::FromStr;
+
+use super::parse::ParseError;
+
+/// Where `needle` first starts in `haystack`.
+///
+/// Looking for its first byte is faster than `str::find` on the few bytes between two
+/// pieces of text of a line.
+fn find(haystack: &str, needle: &str) -> Option<usize> {
+    let first = *needle.as_bytes().first()?;
+    let bytes = haystack.as_bytes();
+    let mut from = 0;
+    while let Some(offset) = bytes[from..].iter().position(|&b| b == first) {
+        let at = from + offset;
+        if bytes[at..].starts_with(needle.as_bytes()) {
+            return Some(at);
+        }
+        from = at + 1;
+    }
+    None
+}
+
+pub struct Scanner<'a> {
+    line: &'a str,
+    rest: &'a str,
+}
+
+impl<'a> Scanner<'a> {
+    pub fn new(line: &'a str) -> Self {
+        Self { line, rest: line }
+    }
+
+    fn error(&self, token: &str, message: String) -> ParseError {
+        ParseError::new(self.line, token, message)
+    }
+
+    /// Skip `text`, which must come next.
+    pub fn literal(&mut self, text: &str) -> Result<(), ParseError> {
+        match self.rest.strip_prefix(text) {
+            Some(rest) => {
+                self.rest = rest;
+                Ok(())
+            }
+            None => Err(self.error(self.rest, format!("expected `{}`", text))),
+        }
+    }
+
+    /// The text up to `until`, or to the end of the line.
+    pub fn text(&mut self, until: Option<&str>) -> Result<&'a str, ParseError> {
+        let end = until
+            .and_then(|until| find(self.rest, until))
+            .unwrap_or(self.rest.len());
+        let (text, rest) = self.rest.split_at(end);
+        if text.is_empty() {
+            return Err(self.error(self.rest, "expected some text".to_string()));
+        }
+        self.rest = rest;
+        Ok(text)
+    }
+
+    /// The text up to `until` parsed as a `T`, described as `expected` in errors.
+    pub fn value<T: FromStr>(
+        &mut self,
+        until: Option<&str>,
+        expected: &str,
+    ) -> Result<T, ParseError> {
+        let start = self.rest;
+        let text = self
+            .text(until)
+            .map_err(|_| self.error(start, format!("expected {}", expected)))?;
+        text.parse()
+            .map_err(|_| self.error(text, format!("expected {}", expected)))
+    }
+
+    /// Check that the whole line was scanned.
+    pub fn finish(&self) -> Result<(), ParseError> {
+        match self.rest.trim_end_matches('\r') {
+            "" => Ok(()),
+            rest => Err(self.error(rest, "unexpected input".to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::scan;
+
+    #[test]
+    fn test_find() {
+        assert_eq!(super::find("12 to 3", " to "), Some(2));
+        assert_eq!(super::find("a tb to c", " to "), Some(4));
+        assert_eq!(super::find("é-1", "-"), Some(2));
+        assert_eq!(super::find("12", " to "), None);
+    }
+
+    #[test]
+    fn test_scan_line() {
+        assert_eq!(
+            scan!("move 1 from 2 to 3", "move {usize} from {usize} to {usize}"),
+            Ok((1, 2, 3))
+        );
+        assert_eq!(scan!("2-4,6-8", "{u8}-{u8},{u8}-{u8}"), Ok((2, 4, 6, 8)));
+        assert_eq!(scan!("$ cd a.b", "$ cd {str}"), Ok("a.b"));
+        assert_eq!(scan!("x=-3", "x={i32}"), Ok(-3));
+        assert_eq!(scan!("{}", "{{}}"), Ok(()));
+
+        let (name, size): (String, u64) = scan!("dir 123", "{} {}").unwrap();
+        assert_eq!((name.as_str(), size), ("dir", 123));
+    }
+
+    #[test]
+    fn test_scan_errors() {
+        let error =
+            scan!("move 1 from x to 3", "move {usize} from {usize} to {usize}").unwrap_err();
+        assert_eq!(
+            (error.column, error.token.as_str(), error.message.as_str()),
+            (13, "x", "expected usize")
+        );
+
+        let error = scan!("move 1 to 3", "move {usize} from {usize} to {usize}").unwrap_err();
+        assert_eq!(
+            (error.column, error.message.as_str()),
+            (6, "expected usize")
+        );
+
+        let error = scan!("2-4,6-8 !", "{u8}-{u8},{u8}-{u8}").unwrap_err();
+        assert_eq!((error.column, error.message.as_str()), (7, "expected u8"));
+
+        let error = scan!("a 1 b", "a {u8}").unwrap_err();
+        assert_eq!((error.column, error.message.as_str()), (3, "expected u8"));
+
+        let error = scan!("move 1", "move {u8} from").unwrap_err();
+        assert_eq!(
+            (error.column, error.message.as_str()),
+            (7, "expected ` from`")
+        );
+    }
+
+    #[test]
+    fn test_scan_lines() {
+        let pairs: Result<Vec<(u32, u32)>, _> = scan!(lines("1,2\n3,4\n"), "{},{}").collect();
+        assert_eq!(pairs, Ok(vec![(1, 2), (3, 4)]));
+
+        let error = scan!(lines("1,2\n3;4"), "{u32},{u32}")
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+    }
+}
@@ -0,0 +1,400 @@
+//! Graph searches over nodes produced by successor closures.
+//!
+//! Nodes are anything `Eq + Hash + Clone`. Unweighted searches take successors
+//! as nodes, weighted ones as `(node, cost)` pairs. To search backwards, from the
+//! goal to the start, pass a closure returning the predecessors instead.
+
+use std::{
+    cmp::Ordering,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// Cost of a weighted edge.
+pub trait Cost: Copy + Ord + Default + Add<Output = Self> {}
+
+impl<C: Copy + Ord + Default + Add<Output = C>> Cost for C {}
+
+/// For every reached node, the node it was reached from and the cost to reach it.
+/// Sources are their own parent.
+pub type Parents<N, C> = HashMap<N, (N, C)>;
+
+/// Shortest path, in number of steps, from `start` to the first node matching `success`.
+pub fn bfs<N, FN, IN, FS>(start: &N, successors: FN, success: FS) -> Option<Vec<N>>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FS: FnMut(&N) -> bool,
+{
+    bfs_multi([start.clone()], successors, success)
+}
+
+/// Shortest path from the closest of `starts` to the first node matching `success`.
+pub fn bfs_multi<N, FN, IN, FS>(
+    starts: impl IntoIterator<Item = N>,
+    successors: FN,
+    success: FS,
+) -> Option<Vec<N>>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FS: FnMut(&N) -> bool,
+{
+    let mut parents = HashMap::new();
+    let goal = run_bfs(starts, successors, success, &mut parents)?;
+    Some(build_path(&goal, &parents))
+}
+
+/// Number of steps from the closest of `starts` to every reachable node.
+pub fn distance_map<N, FN, IN>(
+    starts: impl IntoIterator<Item = N>,
+    successors: FN,
+) -> HashMap<N, usize>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    bfs_parents(starts, successors)
+        .into_iter()
+        .map(|(node, (_, steps))| (node, steps))
+        .collect()
+}
+
+/// Breadth-first predecessor map from `starts`, with step counts as costs.
+pub fn bfs_parents<N, FN, IN>(
+    starts: impl IntoIterator<Item = N>,
+    successors: FN,
+) -> Parents<N, usize>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    let mut parents = HashMap::new();
+    run_bfs(starts, successors, |_| false, &mut parents);
+    parents
+}
+
+/// Cheapest path from `start` to the first node matching `success`, with its cost.
+pub fn dijkstra<N, C, FN, IN, FS>(start: &N, successors: FN, success: FS) -> Option<(Vec<N>, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Cost,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FS: FnMut(&N) -> bool,
+{
+    astar(start, successors, |_| C::default(), success)
+}
+
+/// Cheapest predecessor map from `start` to every reachable node.
+pub fn dijkstra_all<N, C, FN, IN>(start: &N, successors: FN) -> Parents<N, C>
+where
+    N: Eq + Hash + Clone,
+    C: Cost,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+{
+    let mut parents = HashMap::new();
+    run_astar(start, successors, |_| C::default(), |_| false, &mut parents);
+    parents
+}
+
+/// Cheapest path from `start` to the first node matching `success`, with its cost.
+///
+/// `heuristic` must never overestimate the remaining cost, or the path found may
+/// not be the cheapest.
+pub fn astar<N, C, FN, IN, FH, FS>(
+    start: &N,
+    successors: FN,
+    heuristic: FH,
+    success: FS,
+) -> Option<(Vec<N>, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Cost,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FH: FnMut(&N) -> C,
+    FS: FnMut(&N) -> bool,
+{
+    let mut parents = HashMap::new();
+    let goal = run_astar(start, successors, heuristic, success, &mut parents)?;
+    let cost = parents[&goal].1;
+    Some((build_path(&goal, &parents), cost))
+}
+
+/// Walk `parents` back from `target` to its source. Empty when `target` wasn't reached.
+pub fn build_path<N, C>(target: &N, parents: &Parents<N, C>) -> Vec<N>
+where
+    N: Eq + Hash + Clone,
+{
+    let mut path = Vec::new();
+    let mut node = target;
+    while let Some((parent, _)) = parents.get(node) {
+        path.push(node.clone());
+        if parent == node {
+            break;
+        }
+        node = parent;
+    }
+    path.reverse();
+    path
+}
+
+//
+
+struct Candidate<N, C> {
+    estimate: C,
+    cost: C,
+    node: N,
+}
+
+impl<N, C: Ord> PartialEq for Candidate<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<N, C: Ord> Eq for Candidate<N, C> {}
+
+impl<N, C: Ord> PartialOrd for Candidate<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> Ord for Candidate<N, C> {
+    /// Lowest estimate first, then the deepest node, as `BinaryHeap` is a max-heap.
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .estimate
+            .cmp(&self.estimate)
+            .then_with(|| self.cost.cmp(&other.cost))
+    }
+}
+
+/// Fill `parents` breadth first until a node matches `success`, and return that node.
+fn run_bfs<N, FN, IN, FS>(
+    starts: impl IntoIterator<Item = N>,
+    mut successors: FN,
+    mut success: FS,
+    parents: &mut Parents<N, usize>,
+) -> Option<N>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FS: FnMut(&N) -> bool,
+{
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if let Entry::Vacant(entry) = parents.entry(start.clone()) {
+            entry.insert((start.clone(), 0));
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(node) = queue.pop_front() {
+        if success(&node) {
+            return Some(node);
+        }
+        let steps = parents[&node].1 + 1;
+        for next in successors(&node) {
+            if let Entry::Vacant(entry) = parents.entry(next.clone()) {
+                entry.insert((node.clone(), steps));
+                queue.push_back(next);
+            }
+        }
+    }
+    None
+}
+
+/// Fill `parents` cheapest first until a node matches `success`, and return that node.
+fn run_astar<N, C, FN, IN, FH, FS>(
+    start: &N,
+    mut successors: FN,
+    mut heuristic: FH,
+    mut success: FS,
+    parents: &mut Parents<N, C>,
+) -> Option<N>
+where
+    N: Eq + Hash + Clone,
+    C: Cost,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FH: FnMut(&N) -> C,
+    FS: FnMut(&N) -> bool,
+{
+    let mut heap = BinaryHeap::new();
+    parents.insert(start.clone(), (start.clone(), C::default()));
+    heap.push(Candidate {
+        estimate: heuristic(start),
+        cost: C::default(),
+        node: start.clone(),
+    });
+
+    while let Some(Candidate { cost, node, .. }) = heap.pop() {
+        if cost > parents[&node].1 {
+            // A cheaper way to `node` was found after this one was queued.
+            continue;
+        }
+        if success(&node) {
+            return Some(node);
+        }
+        for (next, step) in successors(&node) {
+            let next_cost = cost + step;
+            match parents.entry(next.clone()) {
+                Entry::Occupied(mut entry) if next_cost < entry.get().1 => {
+                    entry.insert((node.clone(), next_cost));
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert((node.clone(), next_cost));
+                }
+                Entry::Occupied(_) => continue,
+            }
+            heap.push(Candidate {
+                estimate: next_cost + heuristic(&next),
+                cost: next_cost,
+                node: next,
+            });
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::helpers::grid::Grid;
+
+    type Position = (usize, usize);
+
+    fn open_neighbours(grid: &Grid<bool>) -> impl Fn(&Position) -> Vec<Position> + '_ {
+        |&position| {
+            grid.neighbours4(position)
+                .filter(|&next| grid[next])
+                .collect()
+        }
+    }
+
+    fn manhattan((ax, ay): Position, (bx, by): Position) -> usize {
+        ax.abs_diff(bx) + ay.abs_diff(by)
+    }
+
+    fn is_walk(grid: &Grid<bool>, path: &[Position]) -> bool {
+        path.iter().all(|&p| grid[p]) && path.windows(2).all(|w| manhattan(w[0], w[1]) == 1)
+    }
+
+    #[test]
+    fn test_bfs() {
+        let grid = Grid::parse("..#\n#..\n...", |c| Some(c == '.')).unwrap();
+        let path = bfs(&(0, 0), open_neighbours(&grid), |&p| p == (2, 2)).unwrap();
+        assert_eq!(path, vec![(0, 0), (1, 0), (1, 1), (2, 1), (2, 2)]);
+        assert_eq!(bfs(&(0, 0), open_neighbours(&grid), |&p| p == (2, 0)), None);
+    }
+
+    #[test]
+    fn test_bfs_multi() {
+        let grid = Grid::filled(5, 1, true);
+        let path = bfs_multi([(0, 0), (3, 0)], open_neighbours(&grid), |&p| p == (1, 0));
+        assert_eq!(path, Some(vec![(0, 0), (1, 0)]));
+    }
+
+    #[test]
+    fn test_weighted() {
+        // 0 -> 1 -> 3 costs 2 + 2, 0 -> 2 -> 3 costs 1 + 5, 0 -> 3 costs 10
+        let edges = |&node: &u8| -> Vec<(u8, u32)> {
+            match node {
+                0 => vec![(1, 2), (2, 1), (3, 10)],
+                1 => vec![(3, 2)],
+                2 => vec![(3, 5)],
+                _ => vec![],
+            }
+        };
+        assert_eq!(dijkstra(&0, edges, |&n| n == 3), Some((vec![0, 1, 3], 4)));
+        assert_eq!(
+            astar(&0, edges, |_| 0, |&n| n == 3),
+            Some((vec![0, 1, 3], 4))
+        );
+
+        let parents = dijkstra_all(&0, edges);
+        assert_eq!(parents[&3], (1, 4));
+        assert_eq!(parents[&2], (0, 1));
+        assert_eq!(build_path(&3, &parents), vec![0, 1, 3]);
+        assert_eq!(build_path(&9, &parents), Vec::<u8>::new());
+    }
+
+    #[test]
+    fn test_distance_map() {
+        let grid = Grid::parse("...\n.#.\n...", |c| Some(c == '.')).unwrap();
+        let distances = distance_map([(0, 0)], open_neighbours(&grid));
+        assert_eq!(distances.len(), 8);
+        assert_eq!(distances[&(2, 2)], 4);
+        assert!(!distances.contains_key(&(1, 1)));
+    }
+
+    fn grid_strategy() -> impl Strategy<Value = Grid<bool>> {
+        (1..8usize, 1..8usize).prop_flat_map(|(width, height)| {
+            proptest::collection::vec(prop::bool::weighted(0.7), width * height)
+                .prop_map(move |cells| Grid::new(width, height, cells))
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn searches_agree_on_random_grids(grid in grid_strategy(), seed in any::<usize>()) {
+            let start = grid.index_to_position(seed % grid.len());
+            let goal = grid.index_to_position(seed / 7 % grid.len());
+            prop_assume!(grid[start] && grid[goal]);
+
+            let neighbours = open_neighbours(&grid);
+            let weighted = |p: &Position| neighbours(p).into_iter().map(|n| (n, 1usize));
+            let distances = distance_map([start], &neighbours);
+
+            let by_bfs = bfs(&start, &neighbours, |&p| p == goal);
+            let by_dijkstra = dijkstra(&start, weighted, |&p| p == goal);
+            let by_astar = astar(&start, weighted, |&p| manhattan(p, goal), |&p| p == goal);
+            let parents = dijkstra_all(&start, weighted);
+
+            prop_assert_eq!(by_bfs.as_ref().map(|path| path.len() - 1), distances.get(&goal).copied());
+            prop_assert_eq!(by_dijkstra.as_ref().map(|(_, cost)| *cost), distances.get(&goal).copied());
+            prop_assert_eq!(by_astar.as_ref().map(|(_, cost)| *cost), distances.get(&goal).copied());
+            prop_assert_eq!(parents.get(&goal).map(|(_, cost)| *cost), distances.get(&goal).copied());
+            prop_assert_eq!(parents.len(), distances.len());
+
+            for path in [by_bfs, by_dijkstra.map(|(p, _)| p), by_astar.map(|(p, _)| p)].into_iter().flatten() {
+                prop_assert!(is_walk(&grid, &path));
+                prop_assert_eq!(path.first(), Some(&start));
+                prop_assert_eq!(path.last(), Some(&goal));
+            }
+        }
+
+        #[test]
+        fn multi_source_is_the_closest_source(grid in grid_strategy(), seeds in prop::array::uniform3(any::<usize>())) {
+            let starts: Vec<Position> = seeds
+                .iter()
+                .map(|seed| grid.index_to_position(seed % grid.len()))
+                .filter(|&p| grid[p])
+                .collect();
+            let neighbours = open_neighbours(&grid);
+            let together = distance_map(starts.clone(), &neighbours);
+
+            for (position, _) in grid.positions().filter(|(_, &open)| open) {
+                let closest = starts
+                    .iter()
+                    .filter_map(|&start| distance_map([start], &neighbours).get(&position).copied())
+                    .min();
+                prop_assert_eq!(together.get(&position).copied(), closest);
+                let path = bfs_multi(starts.clone(), &neighbours, |&p| p == position);
+                prop_assert_eq!(path.map(|path| path.len() - 1), closest);
+            }
+        }
+    }
+}
@@ -0,0 +1,410 @@
+//! A first look at an input, to choose how to parse it and which integer types to use.
+
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    fmt,
+};
+
+use crate::{ANSI_BOLD, ANSI_DIM, ANSI_RESET};
+
+/// Templates listed at most by the report.
+const TEMPLATES_SHOWN: usize = 10;
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct CharClasses {
+    pub lowercase: usize,
+    pub uppercase: usize,
+    pub digits: usize,
+    pub spaces: usize,
+    pub punctuation: usize,
+    /// Tabs, control and non-ASCII characters.
+    pub other: usize,
+    /// Every punctuation character seen.
+    pub punctuation_seen: BTreeSet<char>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Numbers {
+    pub count: usize,
+    pub min: i128,
+    pub max: i128,
+    /// Whether a number was too long even for an `i128`, and left out of `min` and `max`.
+    pub huge: bool,
+}
+
+impl Numbers {
+    pub fn exceeds_u32(&self) -> bool {
+        self.huge || self.min < 0 || self.max > u32::MAX as i128
+    }
+
+    pub fn exceeds_i64(&self) -> bool {
+        self.huge || self.min < i64::MIN as i128 || self.max > i64::MAX as i128
+    }
+
+    /// The smallest primitive type holding every number.
+    pub fn smallest_type(&self) -> &'static str {
+        let fits = |min: i128, max: i128| !self.huge && self.min >= min && self.max <= max;
+        let types = [
+            ("u8", 0, u8::MAX as i128),
+            ("i8", i8::MIN as i128, i8::MAX as i128),
+            ("u16", 0, u16::MAX as i128),
+            ("i16", i16::MIN as i128, i16::MAX as i128),
+            ("u32", 0, u32::MAX as i128),
+            ("i32", i32::MIN as i128, i32::MAX as i128),
+            ("u64", 0, u64::MAX as i128),
+            ("i64", i64::MIN as i128, i64::MAX as i128),
+            ("i128", i128::MIN, i128::MAX),
+        ];
+        types
+            .iter()
+            .find(|&&(_, min, max)| fits(min, max))
+            .map_or("a big integer", |&(name, _, _)| name)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub lines: usize,
+    /// Groups of lines separated by blank lines.
+    pub blocks: usize,
+    /// Lengths of the lines that are not blank.
+    pub min_length: usize,
+    pub max_length: usize,
+    /// The most common length, and how many lines have it.
+    pub mode_length: (usize, usize),
+    pub classes: CharClasses,
+    /// Width and height, when every line has the same length.
+    pub grid: Option<(usize, usize)>,
+    pub numbers: Option<Numbers>,
+    /// Lines with their numbers abstracted as `N`, the most frequent first.
+    pub templates: Vec<(String, usize)>,
+}
+
+//
+
+fn classify(input: &str) -> CharClasses {
+    let mut classes = CharClasses::default();
+    for c in input.chars().filter(|&c| c != '\n' && c != '\r') {
+        match c {
+            'a'..='z' => classes.lowercase += 1,
+            'A'..='Z' => classes.uppercase += 1,
+            '0'..='9' => classes.digits += 1,
+            ' ' => classes.spaces += 1,
+            c if c.is_ascii_punctuation() => {
+                classes.punctuation += 1;
+                classes.punctuation_seen.insert(c);
+            }
+            _ => classes.other += 1,
+        }
+    }
+    classes
+}
+
+/// A piece of a line: a number, with its sign, or the text between numbers.
+#[derive(Debug, PartialEq, Eq)]
+enum Token<'a> {
+    Number(&'a str),
+    Text(&'a str),
+}
+
+fn tokenize(line: &str) -> Vec<Token<'_>> {
+    let bytes = line.as_bytes();
+    let mut tokens = Vec::new();
+    let (mut start, mut i) = (0, 0);
+    while i < bytes.len() {
+        // A minus sign is a sign when it is not between two words, as in `a-b`.
+        let signed = bytes[i] == b'-'
+            && bytes.get(i + 1).is_some_and(u8::is_ascii_digit)
+            && (i == 0 || !bytes[i - 1].is_ascii_alphanumeric());
+        if !bytes[i].is_ascii_digit() && !signed {
+            i += 1;
+            continue;
+        }
+        // Digits glued to letters, as in `x12`, are part of a name.
+        if i > 0 && bytes[i - 1].is_ascii_alphabetic() {
+            while i < bytes.len() && bytes[i].is_ascii_alphanumeric() {
+                i += 1;
+            }
+            continue;
+        }
+        if start < i {
+            tokens.push(Token::Text(&line[start..i]));
+        }
+        let end = (i + 1..bytes.len())
+            .find(|&j| !bytes[j].is_ascii_digit())
+            .unwrap_or(bytes.len());
+        tokens.push(Token::Number(&line[i..end]));
+        (start, i) = (end, end);
+    }
+    if start < line.len() {
+        tokens.push(Token::Text(&line[start..]));
+    }
+    tokens
+}
+
+/// `line` with its numbers as `N`, and lists of them, like `1, 2, 3`, as `N, ...`.
+pub fn template(line: &str) -> String {
+    let tokens = tokenize(line);
+    let is_separator = |text: &str| {
+        !text.is_empty() && text.len() <= 2 && text.chars().all(|c| c == ' ' || c == ',')
+    };
+    let mut template = String::new();
+    let mut i = 0;
+    while i < tokens.len() {
+        match tokens[i] {
+            Token::Text(text) => {
+                template.push_str(text);
+                i += 1;
+            }
+            Token::Number(_) => {
+                template.push('N');
+                let mut next = i + 1;
+                if let Some(&Token::Text(separator)) = tokens.get(next) {
+                    let mut listed = 0;
+                    while is_separator(separator)
+                        && tokens.get(next) == Some(&Token::Text(separator))
+                        && matches!(tokens.get(next + 1), Some(Token::Number(_)))
+                    {
+                        next += 2;
+                        listed += 1;
+                    }
+                    // Two numbers make a list only at the end of the line, unlike `2-4,6-8`.
+                    if listed > 1 || (listed == 1 && next == tokens.len()) {
+                        template.push_str(separator);
+                        template.push_str("...");
+                    } else {
+                        next = i + 1;
+                    }
+                }
+                i = next;
+            }
+        }
+    }
+    template
+}
+
+/// Analyse `input`.
+pub fn inspect(input: &str) -> Report {
+    let lines: Vec<&str> = input.lines().collect();
+    let filled: Vec<&str> = lines
+        .iter()
+        .copied()
+        .filter(|line| !line.trim().is_empty())
+        .collect();
+
+    let mut lengths = BTreeMap::<usize, usize>::new();
+    for line in &filled {
+        *lengths.entry(line.chars().count()).or_default() += 1;
+    }
+    let mode_length = lengths
+        .iter()
+        .max_by(|a, b| a.1.cmp(b.1).then(b.0.cmp(a.0)))
+        .map_or((0, 0), |(&length, &count)| (length, count));
+
+    let blocks = lines
+        .split(|line| line.trim().is_empty())
+        .filter(|block| !block.is_empty())
+        .count();
+
+    let width = filled.first().map_or(0, |line| line.chars().count());
+    let grid = (filled.len() > 1
+        && blocks == 1
+        && filled.iter().all(|line| line.chars().count() == width))
+    .then_some((width, filled.len()));
+
+    let mut numbers: Option<Numbers> = None;
+    let mut templates = HashMap::<String, usize>::new();
+    for line in &filled {
+        for token in tokenize(line) {
+            let Token::Number(number) = token else {
+                continue;
+            };
+            let numbers = numbers.get_or_insert(Numbers {
+                count: 0,
+                min: i128::MAX,
+                max: i128::MIN,
+                huge: false,
+            });
+            numbers.count += 1;
+            match number.parse::<i128>() {
+                Ok(number) => {
+                    numbers.min = numbers.min.min(number);
+                    numbers.max = numbers.max.max(number);
+                }
+                Err(_) => numbers.huge = true,
+            }
+        }
+        *templates.entry(template(line)).or_default() += 1;
+    }
+    let mut templates: Vec<(String, usize)> = templates.into_iter().collect();
+    templates.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+
+    Report {
+        lines: lines.len(),
+        blocks,
+        min_length: lengths.keys().next().copied().unwrap_or(0),
+        max_length: lengths.keys().next_back().copied().unwrap_or(0),
+        mode_length,
+        classes: classify(input),
+        grid,
+        numbers,
+        templates,
+    }
+}
+
+//
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let row = |f: &mut fmt::Formatter, title: &str, value: String| {
+            writeln!(f, "{}{:<12}{} {}", ANSI_BOLD, title, ANSI_RESET, value)
+        };
+
+        let blocks = if self.blocks == 1 { "block" } else { "blocks" };
+        row(
+            f,
+            "lines",
+            format!("{} in {} {}", self.lines, self.blocks, blocks),
+        )?;
+        row(
+            f,
+            "line length",
+            format!(
+                "min {}, max {}, mode {} ({} lines)",
+                self.min_length, self.max_length, self.mode_length.0, self.mode_length.1
+            ),
+        )?;
+
+        let classes = &self.classes;
+        let mut punctuation: String = classes.punctuation_seen.iter().collect();
+        if !punctuation.is_empty() {
+            punctuation = format!(" `{}`", punctuation);
+        }
+        row(
+            f,
+            "characters",
+            format!(
+                "lowercase {}, uppercase {}, digits {}, spaces {}, punctuation {}{}, other {}",
+                classes.lowercase,
+                classes.uppercase,
+                classes.digits,
+                classes.spaces,
+                classes.punctuation,
+                punctuation,
+                classes.other
+            ),
+        )?;
+
+        row(
+            f,
+            "grid",
+            match self.grid {
+                Some((width, height)) => format!("{} × {}", width, height),
+                None => "no".to_string(),
+            },
+        )?;
+
+        row(
+            f,
+            "numbers",
+            match &self.numbers {
+                None => "none".to_string(),
+                Some(numbers) => {
+                    let range = if numbers.huge {
+                        "some longer than an i128".to_string()
+                    } else {
+                        format!("from {} to {}", numbers.min, numbers.max)
+                    };
+                    let exceeds = match (numbers.exceeds_u32(), numbers.exceeds_i64()) {
+                        (_, true) => ", exceeds i64",
+                        (true, false) => ", exceeds u32",
+                        (false, false) => "",
+                    };
+                    format!(
+                        "{} {}, fits {}{}",
+                        numbers.count,
+                        range,
+                        numbers.smallest_type(),
+                        exceeds
+                    )
+                }
+            },
+        )?;
+
+        row(f, "templates", format!("{} distinct", self.templates.len()))?;
+        // The rows of a grid are not worth listing.
+        if self.grid.is_some() {
+            return Ok(());
+        }
+        for (template, count) in self.templates.iter().take(TEMPLATES_SHOWN) {
+            writeln!(f, "  {:>6} × {}", count, template)?;
+        }
+        if self.templates.len() > TEMPLATES_SHOWN {
+            writeln!(
+                f,
+                "  {}... {} more{}",
+                ANSI_DIM,
+                self.templates.len() - TEMPLATES_SHOWN,
+                ANSI_RESET
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_template() {
+        assert_eq!(template("move 1 from 2 to 3"), "move N from N to N");
+        assert_eq!(
+            template("  Starting items: 79, 98"),
+            "  Starting items: N, ..."
+        );
+        assert_eq!(template("Sensor at x=-2, y=18"), "Sensor at x=N, y=N");
+        assert_eq!(template("2-4,6-8"), "N-N,N-N");
+        assert_eq!(template("$ cd a1b x12"), "$ cd a1b x12");
+        assert_eq!(template("1 2 3"), "N ...");
+    }
+
+    #[test]
+    fn test_inspect_blocks_and_numbers() {
+        let report = inspect("1000\n2000\n\n-3\n\n4294967296\n");
+        assert_eq!((report.lines, report.blocks), (6, 3));
+        assert_eq!((report.min_length, report.max_length), (2, 10));
+        assert_eq!(report.mode_length, (4, 2));
+        assert_eq!(report.grid, None);
+        let numbers = report.numbers.unwrap();
+        assert_eq!(
+            (numbers.count, numbers.min, numbers.max),
+            (4, -3, 4294967296)
+        );
+        assert!(numbers.exceeds_u32() && !numbers.exceeds_i64());
+        assert_eq!(numbers.smallest_type(), "i64");
+        assert_eq!(report.templates, [("N".to_string(), 4)]);
+    }
+
+    #[test]
+    fn test_inspect_grid() {
+        let report = inspect("Sabqponm\nabcryxxl\naccszExk\n");
+        assert_eq!(report.grid, Some((8, 3)));
+        assert_eq!(report.numbers, None);
+        assert_eq!(report.classes.uppercase, 2);
+        assert_eq!(report.classes.lowercase, 22);
+
+        let huge = inspect("123456789012345678901234567890123456789012");
+        assert!(huge.numbers.unwrap().exceeds_i64());
+    }
+
+    #[test]
+    fn test_render() {
+        let report = inspect("[D]\n[N] [C]\n 1   2\n\nmove 1 from 2 to 1\n");
+        let rendered = report.to_string();
+        assert!(rendered.contains("5 in 2 blocks"), "{rendered}");
+        assert!(rendered.contains("punctuation 6 `[]`"), "{rendered}");
+        assert!(rendered.contains("from 1 to 2, fits u8"), "{rendered}");
+        assert!(rendered.contains("1 × move N from N to N"), "{rendered}");
+    }
+}
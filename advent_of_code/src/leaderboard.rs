@@ -0,0 +1,468 @@
+/*
+ * Private leaderboard parsing and rendering backing the `leaderboard` binary.
+ * The JSON format is the one served by `/{year}/leaderboard/private/view/{id}.json`.
+ */
+use std::{
+    collections::BTreeMap,
+    env, fmt, fs,
+    io::Write,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+};
+
+use serde::Deserialize;
+
+use crate::{ANSI_BOLD, ANSI_DIM, ANSI_RESET, ANSI_YELLOW};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Puzzles unlock at midnight EST, i.e. 05:00 UTC.
+const UNLOCK_OFFSET_SECS: i64 = 5 * 3600;
+const SECS_PER_DAY: i64 = 86_400;
+
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Leaderboard {
+    pub owner_id: u64,
+    pub event: String,
+    pub members: BTreeMap<String, Member>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Member {
+    pub id: u64,
+    pub name: Option<String>,
+    pub stars: u32,
+    pub local_score: u32,
+    #[serde(default)]
+    pub global_score: u32,
+    pub last_star_ts: i64,
+    /// Day -> part -> star.
+    #[serde(default)]
+    pub completion_day_level: BTreeMap<String, BTreeMap<String, Star>>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Star {
+    pub get_star_ts: i64,
+    #[serde(default)]
+    pub star_index: u64,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum LeaderboardError {
+    Io(String),
+    Json(String),
+    Fetch(String),
+}
+
+impl fmt::Display for LeaderboardError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LeaderboardError::Io(e) => write!(f, "could not read leaderboard: {}", e),
+            LeaderboardError::Json(e) => write!(f, "invalid leaderboard JSON: {}", e),
+            LeaderboardError::Fetch(e) => write!(f, "could not fetch leaderboard: {}", e),
+        }
+    }
+}
+
+impl Leaderboard {
+    pub fn parse(json: &str) -> Result<Self, LeaderboardError> {
+        serde_json::from_str(json).map_err(|e| LeaderboardError::Json(e.to_string()))
+    }
+
+    pub fn from_file(path: &Path) -> Result<Self, LeaderboardError> {
+        let json = fs::read_to_string(path).map_err(|e| LeaderboardError::Io(e.to_string()))?;
+        Self::parse(&json)
+    }
+
+    /// Download through `curl`, authenticated with the aoc-cli session cookie.
+    pub fn fetch(base_url: &str, year: u16, id: u64) -> Result<Self, LeaderboardError> {
+        let session = read_session().ok_or_else(|| {
+            LeaderboardError::Fetch(
+                "no session found in $AOC_SESSION or ~/.adventofcode.session".into(),
+            )
+        })?;
+        // Through a config on stdin rather than the command line, where `ps` would show it
+        let mut curl = Command::new("curl")
+            .args([
+                "--silent",
+                "--fail",
+                "--config",
+                "-",
+                &url(base_url, year, id),
+            ])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()
+            .map_err(|e| LeaderboardError::Fetch(e.to_string()))?;
+        let config = format!(
+            "cookie = \"session={}\"\n",
+            session.replace(['"', '\\'], "")
+        );
+        let written = curl
+            .stdin
+            .take()
+            .expect("curl stdin is piped")
+            .write_all(config.as_bytes());
+        let output = curl
+            .wait_with_output()
+            .map_err(|e| LeaderboardError::Fetch(e.to_string()))?;
+        written.map_err(|e| LeaderboardError::Fetch(e.to_string()))?;
+        if !output.status.success() {
+            return Err(LeaderboardError::Fetch(format!(
+                "curl exited with {}",
+                output.status
+            )));
+        }
+        Self::parse(&String::from_utf8_lossy(&output.stdout))
+    }
+
+    pub fn year(&self) -> i64 {
+        self.event.parse().unwrap_or_default()
+    }
+
+    /// Members by local score, ties broken by who got their last star first.
+    pub fn ranking(&self) -> Vec<&Member> {
+        let mut members: Vec<&Member> = self.members.values().collect();
+        members.sort_by_key(|member| {
+            (
+                std::cmp::Reverse(member.local_score),
+                member.last_star_ts,
+                member.id,
+            )
+        });
+        members
+    }
+}
+
+/// `$AOC_BASE_URL`, falling back to the public site.
+pub fn base_url() -> String {
+    env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.to_string())
+}
+
+pub fn url(base_url: &str, year: u16, id: u64) -> String {
+    format!(
+        "{}/{}/leaderboard/private/view/{}.json",
+        base_url.trim_end_matches('/'),
+        year,
+        id
+    )
+}
+
+fn read_session() -> Option<String> {
+    if let Ok(session) = env::var("AOC_SESSION") {
+        return Some(session);
+    }
+    let home = env::var("HOME").ok()?;
+    fs::read_to_string(PathBuf::from(home).join(".adventofcode.session"))
+        .ok()
+        .map(|session| session.trim().to_string())
+}
+
+impl Member {
+    pub fn display_name(&self) -> String {
+        self.name
+            .clone()
+            .unwrap_or_else(|| format!("(anonymous user #{})", self.id))
+    }
+
+    pub fn star_ts(&self, day: u8, part: u8) -> Option<i64> {
+        self.completion_day_level
+            .get(&day.to_string())?
+            .get(&part.to_string())
+            .map(|star| star.get_star_ts)
+    }
+
+    /// Seconds between the first and the second star of a day.
+    pub fn part_two_delta(&self, day: u8) -> Option<i64> {
+        Some(self.star_ts(day, 2)? - self.star_ts(day, 1)?)
+    }
+
+    pub fn days_stars(&self) -> [u8; 25] {
+        let mut stars = [0; 25];
+        for (day, count) in stars.iter_mut().enumerate() {
+            let day = day as u8 + 1;
+            *count = (1..=2)
+                .filter(|part| self.star_ts(day, *part).is_some())
+                .count() as u8;
+        }
+        stars
+    }
+}
+
+/// Unix timestamp of the unlock of a puzzle.
+pub fn unlock_ts(year: i64, day: u8) -> i64 {
+    days_from_civil(year, 12, day as i64) * SECS_PER_DAY + UNLOCK_OFFSET_SECS
+}
+
+/// Days since 1970-01-01, from http://howardhinnant.github.io/date_algorithms.html
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year - era * 400;
+    let doy = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+/// Inverse of `days_from_civil`.
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+/// Format as `YYYY-MM-DD HH:MM:SS` UTC.
+pub fn format_timestamp(ts: i64) -> String {
+    let (year, month, day) = civil_from_days(ts.div_euclid(SECS_PER_DAY));
+    let secs = ts.rem_euclid(SECS_PER_DAY);
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}:{:02}",
+        year,
+        month,
+        day,
+        secs / 3600,
+        secs % 3600 / 60,
+        secs % 60
+    )
+}
+
+/// Format as `HH:MM:SS`, prefixed with the number of days past the first one.
+pub fn format_duration(secs: i64) -> String {
+    let (days, secs) = (secs / SECS_PER_DAY, secs % SECS_PER_DAY);
+    let hms = format!(
+        "{:02}:{:02}:{:02}",
+        secs / 3600,
+        secs % 3600 / 60,
+        secs % 60
+    );
+    if days > 0 {
+        format!("{}d {}", days, hms)
+    } else {
+        hms
+    }
+}
+
+/// The name of `member`, in bold when it's `me`, padded to `width` first so the codes don't count.
+fn highlighted_name(member: &Member, me: Option<u64>, width: usize) -> String {
+    let name = format!("{:<width$}", member.display_name(), width = width);
+    if Some(member.id) == me {
+        format!("{}{}{}", ANSI_BOLD, name, ANSI_RESET)
+    } else {
+        name
+    }
+}
+
+/// Ranking table with one star glyph per day, in the style of the website.
+pub fn render_ranking(leaderboard: &Leaderboard, me: Option<u64>) -> String {
+    let header = format!(
+        "{:>4} {:>5}  {}  {}",
+        "",
+        "score",
+        (1..=25)
+            .map(|day| char::from_digit(day / 10, 10).unwrap())
+            .collect::<String>()
+            .replace('0', " "),
+        "name"
+    );
+    let subheader = format!(
+        "{:>4} {:>5}  {}",
+        "",
+        "",
+        (1..=25)
+            .map(|day| char::from_digit(day % 10, 10).unwrap())
+            .collect::<String>()
+    );
+
+    let rows = leaderboard
+        .ranking()
+        .into_iter()
+        .enumerate()
+        .map(|(rank, member)| {
+            let stars = member
+                .days_stars()
+                .iter()
+                .map(|count| match count {
+                    2 => format!("{}*{}", ANSI_YELLOW, ANSI_RESET),
+                    1 => format!("{}*{}", ANSI_DIM, ANSI_RESET),
+                    _ => " ".to_string(),
+                })
+                .collect::<String>();
+            format!(
+                "{:>3}) {:>5}  {}  {}",
+                rank + 1,
+                member.local_score,
+                stars,
+                highlighted_name(member, me, 0)
+            )
+        });
+
+    [header, subheader]
+        .into_iter()
+        .chain(rows)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Per-day completion times of every member, relative to the puzzle unlock.
+pub fn render_day(leaderboard: &Leaderboard, day: u8, me: Option<u64>) -> String {
+    let unlock = unlock_ts(leaderboard.year(), day);
+    let header = format!(
+        "{}Day {}{} (unlocked {} UTC)\n{:<24} {:>12} {:>12} {:>12}",
+        ANSI_BOLD,
+        day,
+        ANSI_RESET,
+        format_timestamp(unlock),
+        "name",
+        "part 1",
+        "part 2",
+        "delta"
+    );
+
+    let mut members: Vec<&Member> = leaderboard
+        .members
+        .values()
+        .filter(|member| member.star_ts(day, 1).is_some())
+        .collect();
+    members.sort_by_key(|member| {
+        (
+            member.star_ts(day, 2).unwrap_or(i64::MAX),
+            member.star_ts(day, 1),
+        )
+    });
+
+    let rows = members.into_iter().map(|member| {
+        let time = |part| {
+            member
+                .star_ts(day, part)
+                .map(|ts| format_duration(ts - unlock))
+                .unwrap_or_else(|| "-".to_string())
+        };
+        let delta = member
+            .part_two_delta(day)
+            .map(format_duration)
+            .unwrap_or_else(|| "-".to_string());
+        format!(
+            "{} {:>12} {:>12} {:>12}",
+            highlighted_name(member, me, 24),
+            time(1),
+            time(2),
+            delta
+        )
+    });
+
+    std::iter::once(header)
+        .chain(rows)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIXTURE: &str = include_str!("../fixtures/leaderboard_2022.json");
+
+    fn fixture() -> Leaderboard {
+        Leaderboard::parse(FIXTURE).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let leaderboard = fixture();
+        assert_eq!(leaderboard.owner_id, 1001);
+        assert_eq!(leaderboard.year(), 2022);
+        assert_eq!(leaderboard.members.len(), 3);
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(matches!(
+            Leaderboard::parse("{\"members\": []}"),
+            Err(LeaderboardError::Json(_))
+        ));
+    }
+
+    #[test]
+    fn test_ranking() {
+        let names: Vec<String> = fixture()
+            .ranking()
+            .iter()
+            .map(|member| member.display_name())
+            .collect();
+        assert_eq!(names, ["Ferris", "Douglas", "(anonymous user #1003)"]);
+    }
+
+    #[test]
+    fn test_star_timestamps() {
+        let leaderboard = fixture();
+        let ferris = &leaderboard.members["1002"];
+        assert_eq!(ferris.star_ts(1, 1), Some(1669871283));
+        assert_eq!(ferris.part_two_delta(1), Some(95));
+        assert_eq!(ferris.part_two_delta(3), None);
+        assert_eq!(&ferris.days_stars()[..4], [2, 2, 1, 0]);
+    }
+
+    #[test]
+    fn test_unlock_ts() {
+        // 2022-12-01T05:00:00Z
+        assert_eq!(unlock_ts(2022, 1), 1669870800);
+        assert_eq!(format_timestamp(1669870800), "2022-12-01 05:00:00");
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(95), "00:01:35");
+        assert_eq!(format_duration(SECS_PER_DAY + 3661), "1d 01:01:01");
+    }
+
+    #[test]
+    fn test_url() {
+        assert_eq!(
+            url("http://localhost:8080/", 2022, 1001),
+            "http://localhost:8080/2022/leaderboard/private/view/1001.json"
+        );
+    }
+
+    #[test]
+    fn test_render_ranking_highlights_me() {
+        let rendered = render_ranking(&fixture(), Some(1001));
+        let douglas = rendered
+            .lines()
+            .find(|line| line.contains("Douglas"))
+            .unwrap();
+        assert!(douglas.ends_with(&format!("  {}Douglas{}", ANSI_BOLD, ANSI_RESET)));
+        assert!(douglas.starts_with("  2)    52  "));
+        let ferris = rendered
+            .lines()
+            .find(|line| line.contains("Ferris"))
+            .unwrap();
+        assert!(!ferris.contains(ANSI_BOLD));
+    }
+
+    #[test]
+    fn test_render_day() {
+        let rendered = render_day(&fixture(), 1, None);
+        let lines: Vec<&str> = rendered.lines().skip(2).collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].starts_with("Ferris"));
+        assert!(lines[0].contains("00:08:03     00:09:38     00:01:35"));
+        assert!(lines[2].contains("-"));
+    }
+
+    #[test]
+    fn test_render_day_highlights_me() {
+        let rendered = render_day(&fixture(), 1, Some(1002));
+        let ferris = rendered.lines().nth(2).unwrap();
+        let name = format!("{}{:<24}{}", ANSI_BOLD, "Ferris", ANSI_RESET);
+        assert!(ferris.starts_with(&name));
+        assert!(ferris.ends_with("00:08:03     00:09:38     00:01:35"));
+    }
+}
@@ -12,12 +12,47 @@ use std::{
     process::{Command, Output, Stdio},
 };
 
+// `scan!` expands to paths starting with `::advent_of_code`, used by the tests of this crate too.
+extern crate self as advent_of_code;
+
+pub use aoc_macros::scan;
+
+pub mod alloc_stats;
+pub mod compare;
+pub mod generate;
 pub mod helpers;
+pub mod inspect;
+pub mod leaderboard;
+pub mod minimize;
+pub mod picture;
+pub mod profile;
+pub mod sandbox;
 pub mod solve;
+pub mod status;
+pub mod trace;
+pub mod watch;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
+pub const ANSI_DIM: &str = "\x1b[2m";
+pub const ANSI_RED: &str = "\x1b[31m";
+pub const ANSI_GREEN: &str = "\x1b[32m";
+pub const ANSI_YELLOW: &str = "\x1b[33m";
+
+/// `text` without its ANSI escape codes, as shown by a terminal.
+pub fn strip_ansi(text: &str) -> String {
+    let mut stripped = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            chars.by_ref().find(|&c| c == 'm');
+        } else {
+            stripped.push(c);
+        }
+    }
+    stripped
+}
 
 pub fn read_input() -> String {
     let cwd = env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR not found in env");
@@ -57,6 +92,29 @@ pub fn read_file(folder: &str, day: u8) -> String {
     f.expect("could not open input file")
 }
 
+/// Cargo feature of this crate counting allocations, to enable from a day package.
+pub const ALLOC_STATS_FEATURE: &str = "advent_of_code/alloc-stats";
+
+/// Run the `day_<year>_<day>_<name>` example of a day with `args`, in release mode.
+pub fn run_day_example(
+    year: u16,
+    day: u8,
+    name: &str,
+    features: &[&str],
+    args: &[String],
+) -> std::io::Result<std::process::ExitStatus> {
+    let package = format!("day_{}_{:02}", year, day);
+    let mut command = Command::new("cargo");
+    command.args(["run", "--quiet", "--release", "-p", &package]);
+    if !features.is_empty() {
+        command.args(["--features", &features.join(",")]);
+    }
+    command
+        .args(["--example", &format!("{}_{}", package, name), "--"])
+        .args(args)
+        .status()
+}
+
 fn parse_time(val: &str, postfix: &str) -> f64 {
     val.split(postfix).next().unwrap().parse().unwrap()
 }
@@ -98,39 +156,6 @@ macro_rules! assert_approx_eq {
     }};
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_parse_exec_time() {
-        assert_approx_eq!(
-            parse_exec_time(&format!(
-                "🎄 Part 1 🎄\n0 (elapsed: 74.13ns){}\n🎄 Part 2 🎄\n0 (elapsed: 50.00ns){}",
-                ANSI_RESET, ANSI_RESET
-            )),
-            0_f64
-        );
-
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 755µs)\n🎄 Part 2 🎄\n0 (elapsed: 700µs)"),
-            1.455_f64
-        );
-
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 70µs)\n🎄 Part 2 🎄\n0 (elapsed: 1.45ms)"),
-            1.52_f64
-        );
-
-        assert_approx_eq!(
-            parse_exec_time(
-                "🎄 Part 1 🎄\n0 (elapsed: 10.3s)\n🎄 Part 2 🎄\n0 (elapsed: 100.50ms)"
-            ),
-            10400.50_f64
-        );
-    }
-}
-
 pub enum AocCliError {
     CommandNotFound,
     CommandNotCallable,
@@ -229,3 +254,47 @@ fn call_aoc_cli(args: &[String]) -> Result<Output, AocCliError> {
         .output()
         .map_err(|_| AocCliError::CommandNotCallable)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strip_ansi() {
+        assert_eq!(
+            strip_ansi(&format!(
+                "{}✗{} 1, expected 2{}",
+                ANSI_RED, ANSI_BOLD, ANSI_RESET
+            )),
+            "✗ 1, expected 2"
+        );
+    }
+
+    #[test]
+    fn test_parse_exec_time() {
+        assert_approx_eq!(
+            parse_exec_time(&format!(
+                "🎄 Part 1 🎄\n0 (elapsed: 74.13ns){}\n🎄 Part 2 🎄\n0 (elapsed: 50.00ns){}",
+                ANSI_RESET, ANSI_RESET
+            )),
+            0_f64
+        );
+
+        assert_approx_eq!(
+            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 755µs)\n🎄 Part 2 🎄\n0 (elapsed: 700µs)"),
+            1.455_f64
+        );
+
+        assert_approx_eq!(
+            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 70µs)\n🎄 Part 2 🎄\n0 (elapsed: 1.45ms)"),
+            1.52_f64
+        );
+
+        assert_approx_eq!(
+            parse_exec_time(
+                "🎄 Part 1 🎄\n0 (elapsed: 10.3s)\n🎄 Part 2 🎄\n0 (elapsed: 100.50ms)"
+            ),
+            10400.50_f64
+        );
+    }
+}
@@ -0,0 +1,374 @@
+//! Shrink an input while it keeps failing, with delta debugging.
+//!
+//! Blocks separated by blank lines are removed first, then single lines. Every
+//! candidate is run in a child process, so that panics and endless loops are
+//! caught, and is skipped unless the day's parser accepts it.
+
+use std::{
+    env,
+    fmt::Display,
+    fs, panic,
+    path::PathBuf,
+    process::{self, Command},
+    time::Duration,
+};
+
+use crate::{
+    compare::Registry,
+    sandbox::{self, Exit, Limits},
+};
+
+/// Exit code of a child given an input its parser rejects.
+const UNPARSEABLE: i32 = 2;
+
+/// Without `--timeout`, how long the original input may run.
+const ORIGINAL_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Without `--timeout`, candidates running this many times longer than the original
+/// are given up on.
+const SLOWDOWN: u32 = 10;
+
+/// Smallest subset of `units` found by ddmin for which `fails` holds, keeping their order.
+///
+/// `fails` must hold for `units` itself.
+pub fn ddmin<T: Clone>(units: &[T], mut fails: impl FnMut(&[T]) -> bool) -> Vec<T> {
+    let mut units = units.to_vec();
+    let mut granularity = 2;
+    while units.len() >= 2 {
+        let chunk_len = units.len().div_ceil(granularity);
+        let chunks: Vec<(usize, usize)> = (0..units.len())
+            .step_by(chunk_len)
+            .map(|start| (start, (start + chunk_len).min(units.len())))
+            .collect();
+
+        if let Some(subset) = chunks
+            .iter()
+            .map(|&(start, end)| units[start..end].to_vec())
+            .find(|subset| fails(subset))
+        {
+            units = subset;
+            granularity = 2;
+            continue;
+        }
+        if let Some(complement) = chunks
+            .iter()
+            .map(|&(start, end)| [&units[..start], &units[end..]].concat())
+            .find(|complement| fails(complement))
+        {
+            units = complement;
+            granularity = (granularity - 1).max(2);
+            continue;
+        }
+        if granularity >= units.len() {
+            break;
+        }
+        granularity = (granularity * 2).min(units.len());
+    }
+    units
+}
+
+/// `input` shrunk block by block, then line by line, while `fails` holds.
+pub fn minimize(input: &str, mut fails: impl FnMut(&str) -> bool) -> String {
+    let blocks: Vec<&str> = input.trim_end().split("\n\n").collect();
+    let blocks = ddmin(&blocks, |blocks| fails(&(blocks.join("\n\n") + "\n")));
+    let input = blocks.join("\n\n");
+
+    let lines: Vec<&str> = input.lines().collect();
+    let lines = ddmin(&lines, |lines| fails(&(lines.join("\n") + "\n")));
+    lines.join("\n") + "\n"
+}
+
+//
+
+/// What running the implementations on an input gave.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Unparseable,
+    /// Where the panic happened, e.g. `2022/day_05/src/lib.rs:23:50`.
+    Panicked(String),
+    TimedOut,
+    /// Answer of each implementation, by part.
+    Answers(Vec<(u8, String, Option<String>)>),
+}
+
+/// The failure to keep while shrinking.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Failure {
+    /// Panics at the same place as the original input.
+    Panic,
+    Timeout,
+    /// Implementations of a part disagree.
+    Mismatch,
+    /// An implementation answers something else than this, the answer stored for the input.
+    Answer(String),
+}
+
+impl Failure {
+    /// Whether `outcome` reproduces `original`, the outcome of the full input.
+    pub fn matches(&self, outcome: &Outcome, original: &Outcome) -> bool {
+        match (self, outcome) {
+            (Failure::Panic, Outcome::Panicked(location)) => {
+                matches!(original, Outcome::Panicked(first) if first == location)
+            }
+            (Failure::Timeout, Outcome::TimedOut) => true,
+            (Failure::Mismatch, Outcome::Answers(answers)) => [1, 2].into_iter().any(|part| {
+                let mut part_answers = answers.iter().filter(|(p, ..)| *p == part);
+                part_answers
+                    .next()
+                    .is_some_and(|(_, _, first)| part_answers.any(|(_, _, answer)| answer != first))
+            }),
+            (Failure::Answer(expected), Outcome::Answers(answers)) => answers
+                .iter()
+                .any(|(_, _, answer)| answer.as_ref().is_some_and(|answer| answer != expected)),
+            _ => false,
+        }
+    }
+}
+
+impl Display for Failure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Failure::Panic => write!(f, "panics"),
+            Failure::Timeout => write!(f, "times out"),
+            Failure::Mismatch => write!(f, "implementations disagree"),
+            Failure::Answer(answer) => write!(f, "does not answer {}", answer),
+        }
+    }
+}
+
+/// Panic location in the stderr of a child.
+fn panic_location(stderr: &str) -> Option<String> {
+    let line = stderr.lines().find(|line| line.contains("panicked at"))?;
+    let location = line.split("panicked at ").nth(1)?;
+    Some(location.trim_end_matches(':').to_string())
+}
+
+fn parse_answers(stdout: &str) -> Vec<(u8, String, Option<String>)> {
+    stdout
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.splitn(3, '\t');
+            let part = fields.next()?.parse().ok()?;
+            let name = fields.next()?.to_string();
+            let answer = fields.next()?;
+            Some((part, name, (answer != "-").then(|| answer.to_string())))
+        })
+        .collect()
+}
+
+/// Run this executable in `--evaluate` mode on `path`, and how long it took.
+fn evaluate(path: &PathBuf, parts: &[u8], timeout: Duration) -> (Outcome, Duration) {
+    let mut command = Command::new(env::current_exe().unwrap());
+    command.arg("--evaluate").arg(path).args(
+        parts
+            .iter()
+            .flat_map(|part| ["--part".to_string(), part.to_string()]),
+    );
+    let limits = Limits {
+        timeout: Some(timeout),
+        ..Limits::default()
+    };
+    let outcome = sandbox::run(command, &limits).expect("could not run the day");
+
+    let result = match outcome.exit {
+        Exit::Success => Outcome::Answers(parse_answers(&outcome.stdout)),
+        Exit::Timeout => Outcome::TimedOut,
+        Exit::Error(UNPARSEABLE) => Outcome::Unparseable,
+        _ => Outcome::Panicked(
+            panic_location(&outcome.stderr).unwrap_or_else(|| outcome.stderr.trim().to_string()),
+        ),
+    };
+    (result, outcome.elapsed)
+}
+
+/// Print the answer of every implementation of `parts` on the input at `path`.
+fn print_answers(registry: &Registry, path: &str, parts: &[u8]) {
+    let input = fs::read_to_string(path).expect("could not read the candidate input");
+    if !registry.parses(&input) {
+        process::exit(UNPARSEABLE);
+    }
+    for &part in parts {
+        for implementation in registry.part(part) {
+            let answer = implementation.solve(&input);
+            println!(
+                "{}\t{}\t{}",
+                part,
+                implementation.name,
+                answer.as_deref().unwrap_or("-")
+            );
+        }
+    }
+}
+
+//
+
+struct Args {
+    input: Option<String>,
+    evaluate: Option<String>,
+    failure: Failure,
+    parts: Vec<u8>,
+    timeout: Option<Duration>,
+    output: Option<String>,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let timeout_ms: Option<u64> = args.opt_value_from_str("--timeout")?;
+    let failure = if args.contains("--panic") {
+        Failure::Panic
+    } else if args.contains("--mismatch") {
+        Failure::Mismatch
+    } else if let Some(answer) = args.opt_value_from_str("--answer")? {
+        Failure::Answer(answer)
+    } else if timeout_ms.is_some() {
+        Failure::Timeout
+    } else {
+        Failure::Panic
+    };
+    let parts: Vec<u8> = args.values_from_str("--part")?;
+    Ok(Args {
+        evaluate: args.opt_value_from_str("--evaluate")?,
+        output: args.opt_value_from_str(["-o", "--output"])?,
+        failure,
+        parts: if parts.is_empty() { vec![1, 2] } else { parts },
+        timeout: timeout_ms.map(Duration::from_millis),
+        input: args.opt_free_from_str()?,
+    })
+}
+
+/// Shrink the input file given as argument while it keeps failing the way asked:
+/// `--panic` (the default), `--timeout <ms>`, `--mismatch` or `--answer <answer>`.
+pub fn main(registry: Registry) {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    if let Some(path) = args.evaluate {
+        // Candidates are expected to panic, their messages would only be noise.
+        panic::set_hook(Box::new(|info| {
+            eprintln!("panicked at {}", info.location().unwrap())
+        }));
+        print_answers(&registry, &path, &args.parts);
+        return;
+    }
+
+    let Some(input_path) = args.input else {
+        eprintln!("Missing the input file to minimize");
+        process::exit(1);
+    };
+    let input = fs::read_to_string(&input_path).unwrap_or_else(|err| {
+        eprintln!("Could not read {}: {}", input_path, err);
+        process::exit(1);
+    });
+
+    let candidate = env::temp_dir().join(format!("minimize-{}.txt", process::id()));
+    let run = |text: &str, timeout: Duration| {
+        fs::write(&candidate, text).unwrap();
+        evaluate(&candidate, &args.parts, timeout)
+    };
+
+    let (original, elapsed) = run(&input, args.timeout.unwrap_or(ORIGINAL_TIMEOUT));
+    if !args.failure.matches(&original, &original) {
+        eprintln!("{} does not fail that way ({:?})", input_path, original);
+        process::exit(1);
+    }
+    // At least a second, as starting the process alone takes a while.
+    let timeout = args
+        .timeout
+        .unwrap_or((elapsed * SLOWDOWN).max(Duration::from_secs(1)));
+
+    let mut runs = 0;
+    let minimized = minimize(&input, |text| {
+        runs += 1;
+        args.failure.matches(&run(text, timeout).0, &original)
+    });
+    let _ = fs::remove_file(&candidate);
+
+    let output = args
+        .output
+        .unwrap_or_else(|| format!("{}.min.txt", input_path.trim_end_matches(".txt")));
+    fs::write(&output, &minimized).unwrap();
+    println!(
+        "{} lines -> {} lines that still {}, after {} runs. Written to {}",
+        input.lines().count(),
+        minimized.lines().count(),
+        args.failure,
+        runs,
+        output
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ddmin_finds_the_failing_pair() {
+        let units: Vec<u32> = (0..40).collect();
+        let mut tests = 0;
+        let minimal = ddmin(&units, |subset| {
+            tests += 1;
+            subset.contains(&7) && subset.contains(&31)
+        });
+        assert_eq!(minimal, [7, 31]);
+        assert!(tests < 100, "{tests} tests");
+    }
+
+    #[test]
+    fn test_minimize_blocks_then_lines() {
+        let input = "1\n2\n\n3\n4\n\n5\n6\n";
+        // Fails when the sum of the numbers is odd and at least 4
+        let fails = |text: &str| {
+            let sum: u32 = text
+                .lines()
+                .filter_map(|line| line.parse::<u32>().ok())
+                .sum();
+            sum % 2 == 1 && sum >= 4
+        };
+        assert!(fails(input));
+        let minimized = minimize(input, fails);
+        assert!(fails(&minimized));
+        assert_eq!(minimized.lines().filter(|line| !line.is_empty()).count(), 1);
+    }
+
+    #[test]
+    fn test_failure_matches() {
+        let original = Outcome::Panicked("src/lib.rs:1:1".to_string());
+        let other_panic = Outcome::Panicked("src/lib.rs:9:9".to_string());
+        assert!(Failure::Panic.matches(&original, &original));
+        assert!(!Failure::Panic.matches(&other_panic, &original));
+        assert!(!Failure::Panic.matches(&Outcome::Unparseable, &original));
+
+        let answers = |second: &str| {
+            Outcome::Answers(vec![
+                (1, "naive".to_string(), Some("4".to_string())),
+                (1, "fast".to_string(), Some(second.to_string())),
+            ])
+        };
+        assert!(Failure::Mismatch.matches(&answers("5"), &original));
+        assert!(!Failure::Mismatch.matches(&answers("4"), &original));
+        assert!(Failure::Answer("4".to_string()).matches(&answers("5"), &original));
+        assert!(!Failure::Answer("4".to_string()).matches(&answers("4"), &original));
+        let unsolved = Outcome::Answers(vec![(1, "naive".to_string(), None)]);
+        assert!(!Failure::Answer("4".to_string()).matches(&unsolved, &original));
+    }
+
+    #[test]
+    fn test_child_output() {
+        assert_eq!(
+            panic_location("panicked at 2022/day_05/src/lib.rs:23:50:\nattempt to subtract"),
+            Some("2022/day_05/src/lib.rs:23:50".to_string())
+        );
+        assert_eq!(
+            parse_answers("1\tnaive\t7\n2\tnaive\t-\n"),
+            vec![
+                (1, "naive".to_string(), Some("7".to_string())),
+                (2, "naive".to_string(), None)
+            ]
+        );
+    }
+}
@@ -0,0 +1,66 @@
+//! Pictures of puzzle inputs, saved with `helpers::image`.
+//!
+//! A day draws its input as an `Image` and exposes it through an `examples/picture.rs`
+//! calling `picture::main`, which `cargo picture` runs.
+
+use std::{env, fs, path::PathBuf, process};
+
+use crate::helpers::{image::Image, parse};
+
+struct Args {
+    input: String,
+    scale: usize,
+    output: PathBuf,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let example = args.contains("--example");
+    let input = args.opt_value_from_str("--input")?;
+    Ok(Args {
+        input: input
+            .unwrap_or_else(|| if example { "example.txt" } else { "input.txt" }.to_string()),
+        scale: args.opt_value_from_str("--scale")?.unwrap_or(4),
+        output: args.free_from_str()?,
+    })
+}
+
+/// Draw `input.txt`, `example.txt` with `--example` or another file of the day with
+/// `--input <name>`, and save it to the path given as argument, each cell `--scale`
+/// pixels wide. `picture` gives `None` for an input it cannot draw, with the error
+/// given to `parse::report`.
+pub fn main(picture: impl Fn(&str) -> Option<Image>) {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let directory = env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR not found in env");
+    let input_path = PathBuf::from(directory).join(&args.input);
+    let input = fs::read_to_string(&input_path).unwrap_or_else(|err| {
+        eprintln!("Could not read {}: {}", input_path.display(), err);
+        process::exit(1);
+    });
+
+    let Some(image) = picture(&input) else {
+        eprintln!("Could not draw {}", input_path.display());
+        if let Some(error) = parse::take_reported() {
+            eprintln!("{}", error);
+        }
+        process::exit(1);
+    };
+    let image = image.scaled(args.scale.max(1));
+    if let Err(err) = image.save(&args.output) {
+        eprintln!("Could not save {}: {}", args.output.display(), err);
+        process::exit(1);
+    }
+    println!(
+        "{}x{} picture saved to {}",
+        image.width(),
+        image.height(),
+        args.output.display()
+    );
+}
@@ -0,0 +1,192 @@
+//! Named timing spans inside solutions, nested as they are entered.
+//!
+//! `aoc_span!("parse")` times the rest of the enclosing block, `aoc_span!("parse", expr)`
+//! times `expr` only. Spans are collected per thread, and only once `enable(true)` was
+//! called: otherwise entering one costs a relaxed atomic load.
+
+use std::{
+    cell::RefCell,
+    fmt::Write,
+    sync::atomic::{AtomicBool, Ordering::Relaxed},
+    time::{Duration, Instant},
+};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    static COLLECTOR: RefCell<Collector> = RefCell::new(Collector::default());
+}
+
+/// Time the rest of the enclosing block, or the given expression, as a span.
+#[macro_export]
+macro_rules! aoc_span {
+    ($name:expr) => {
+        let _aoc_span = $crate::profile::Span::enter($name);
+    };
+    ($name:expr, $body:expr) => {{
+        let _aoc_span = $crate::profile::Span::enter($name);
+        $body
+    }};
+}
+
+/// Total time and number of calls of a span, under the same parents.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpanStats {
+    pub name: &'static str,
+    pub calls: u64,
+    pub total: Duration,
+    pub children: Vec<SpanStats>,
+}
+
+#[derive(Default)]
+struct Collector {
+    roots: Vec<SpanStats>,
+    /// Index of each open span among the children of the previous one.
+    open: Vec<usize>,
+}
+
+impl Collector {
+    fn siblings(&mut self) -> &mut Vec<SpanStats> {
+        let mut siblings = &mut self.roots;
+        for &index in &self.open {
+            siblings = &mut siblings[index].children;
+        }
+        siblings
+    }
+
+    fn enter(&mut self, name: &'static str) {
+        let siblings = self.siblings();
+        let index = match siblings.iter().position(|span| span.name == name) {
+            Some(index) => index,
+            None => {
+                siblings.push(SpanStats {
+                    name,
+                    calls: 0,
+                    total: Duration::ZERO,
+                    children: Vec::new(),
+                });
+                siblings.len() - 1
+            }
+        };
+        self.open.push(index);
+    }
+
+    fn exit(&mut self, elapsed: Duration) {
+        let Some(index) = self.open.pop() else {
+            return;
+        };
+        let span = &mut self.siblings()[index];
+        span.calls += 1;
+        span.total += elapsed;
+    }
+}
+
+/// Guard of an entered span, recording it when dropped.
+pub struct Span {
+    start: Option<Instant>,
+}
+
+impl Span {
+    pub fn enter(name: &'static str) -> Self {
+        if !ENABLED.load(Relaxed) {
+            return Span { start: None };
+        }
+        COLLECTOR.with(|collector| collector.borrow_mut().enter(name));
+        Span {
+            start: Some(Instant::now()),
+        }
+    }
+}
+
+impl Drop for Span {
+    fn drop(&mut self) {
+        if let Some(start) = self.start {
+            let elapsed = start.elapsed();
+            COLLECTOR.with(|collector| collector.borrow_mut().exit(elapsed));
+        }
+    }
+}
+
+//
+
+pub fn enable(enabled: bool) {
+    ENABLED.store(enabled, Relaxed);
+}
+
+pub fn is_enabled() -> bool {
+    ENABLED.load(Relaxed)
+}
+
+/// Whether the binary was given `--profile`.
+pub fn requested() -> bool {
+    std::env::args().any(|arg| arg == "--profile")
+}
+
+/// The spans of this thread since the last call, forgetting them.
+pub fn take() -> Vec<SpanStats> {
+    COLLECTOR.with(|collector| {
+        let mut collector = collector.borrow_mut();
+        collector.open.clear();
+        std::mem::take(&mut collector.roots)
+    })
+}
+
+/// Spans as an indented tree, one line per span.
+pub fn render(spans: &[SpanStats]) -> String {
+    fn render_level(spans: &[SpanStats], depth: usize, output: &mut String) {
+        for span in spans {
+            let name = format!("{}{}", "  ".repeat(depth), span.name);
+            let calls = if span.calls == 1 { "call" } else { "calls" };
+            let _ = writeln!(
+                output,
+                "  {:<24} {:>8} {:<5} {:>10.2?}",
+                name, span.calls, calls, span.total
+            );
+            render_level(&span.children, depth + 1, output);
+        }
+    }
+
+    let mut output = String::new();
+    render_level(spans, 0, &mut output);
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A single test, as the flag is shared by the tests running in parallel.
+    #[test]
+    fn test_spans() {
+        enable(false);
+        {
+            aoc_span!("ignored");
+        }
+        assert!(take().is_empty());
+
+        enable(true);
+        let parsed = aoc_span!("parse", 21 * 2);
+        for _ in 0..3 {
+            aoc_span!("simulate");
+            for _ in 0..2 {
+                aoc_span!("round");
+            }
+        }
+        enable(false);
+
+        let spans = take();
+        assert_eq!(parsed, 42);
+        let calls: Vec<_> = spans.iter().map(|span| (span.name, span.calls)).collect();
+        assert_eq!(calls, [("parse", 1), ("simulate", 3)]);
+        let round = &spans[1].children[0];
+        assert_eq!((round.name, round.calls), ("round", 6));
+        assert!(spans[1].total >= spans[1].children[0].total);
+
+        let tree = render(&spans);
+        let lines: Vec<&str> = tree.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].starts_with("  parse "), "{tree}");
+        assert!(lines[2].starts_with("    round "), "{tree}");
+        assert!(lines[2].contains("6 calls"), "{tree}");
+    }
+}
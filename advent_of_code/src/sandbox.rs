@@ -0,0 +1,289 @@
+//! Run a child process under resource limits and tell how it ended.
+//!
+//! The CPU time and address space limits are set with `setrlimit` in the child, on Linux
+//! only. The wall-clock timeout is enforced everywhere by killing the child, along with
+//! the processes it started on Unix, where it leads its own process group.
+
+use std::{
+    io::{self, Read},
+    process::{Child, Command, ExitStatus, Stdio},
+    thread,
+    time::{Duration, Instant},
+};
+
+/// Exit code of a Rust program that panicked.
+const PANIC_EXIT_CODE: i32 = 101;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Limits {
+    /// CPU time, rounded up to whole seconds.
+    pub cpu: Option<Duration>,
+    /// Address space, in bytes.
+    pub memory: Option<u64>,
+    /// Wall-clock time.
+    pub timeout: Option<Duration>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Exit {
+    Success,
+    Panic,
+    OutOfMemory,
+    /// Killed for running longer than the timeout or its CPU time.
+    Timeout,
+    Signal(i32),
+    /// Exited with another code than success or a panic.
+    Error(i32),
+}
+
+impl Exit {
+    pub fn label(&self) -> String {
+        match self {
+            Exit::Success => "success".to_string(),
+            Exit::Panic => "panic".to_string(),
+            Exit::OutOfMemory => "out of memory".to_string(),
+            Exit::Timeout => "timeout".to_string(),
+            Exit::Signal(signal) => format!("signal {}", signal),
+            Exit::Error(code) => format!("exit code {}", code),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Outcome {
+    pub exit: Exit,
+    pub stdout: String,
+    pub stderr: String,
+    pub elapsed: Duration,
+}
+
+#[cfg(unix)]
+fn signal_of(status: &ExitStatus) -> Option<i32> {
+    std::os::unix::process::ExitStatusExt::signal(status)
+}
+
+#[cfg(not(unix))]
+fn signal_of(_status: &ExitStatus) -> Option<i32> {
+    None
+}
+
+/// How a child ended, from its exit code or signal and what it printed on stderr.
+pub fn classify(code: Option<i32>, signal: Option<i32>, stderr: &str, timed_out: bool) -> Exit {
+    // Allocations failing past the address space limit abort the process.
+    if stderr.contains("memory allocation of") {
+        return Exit::OutOfMemory;
+    }
+    #[cfg(target_os = "linux")]
+    if signal == Some(libc::SIGXCPU) {
+        return Exit::Timeout;
+    }
+    match (code, signal) {
+        _ if timed_out => Exit::Timeout,
+        (Some(0), _) => Exit::Success,
+        (Some(PANIC_EXIT_CODE), _) => Exit::Panic,
+        (Some(code), _) => Exit::Error(code),
+        (None, Some(signal)) => Exit::Signal(signal),
+        (None, None) => Exit::Error(-1),
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn apply_rlimits(command: &mut Command, limits: &Limits) {
+    use std::os::unix::process::CommandExt;
+
+    // The resource type is `u32` on glibc but `c_int` on musl, hence the casts.
+    fn set(resource: i32, soft: u64, hard: u64) -> io::Result<()> {
+        let limit = libc::rlimit {
+            rlim_cur: soft,
+            rlim_max: hard,
+        };
+        // SAFETY: `limit` outlives the call, which does not keep it.
+        match unsafe { libc::setrlimit(resource as _, &limit) } {
+            0 => Ok(()),
+            _ => Err(io::Error::last_os_error()),
+        }
+    }
+
+    let cpu = limits
+        .cpu
+        .map(|cpu| cpu.as_secs_f64().ceil().max(1.0) as u64);
+    let memory = limits.memory;
+    let apply = move || {
+        if let Some(cpu) = cpu {
+            // A second of leeway gets SIGXCPU delivered before the hard limit's SIGKILL.
+            set(libc::RLIMIT_CPU as i32, cpu, cpu + 1)?;
+        }
+        if let Some(memory) = memory {
+            set(libc::RLIMIT_AS as i32, memory, memory)?;
+        }
+        Ok(())
+    };
+    // SAFETY: `setrlimit` is async-signal-safe and nothing is allocated between fork and exec.
+    unsafe {
+        command.pre_exec(apply);
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn apply_rlimits(_command: &mut Command, _limits: &Limits) {}
+
+/// Start `command` in a process group of its own, to kill whatever it spawns with it.
+#[cfg(unix)]
+fn isolate(command: &mut Command) {
+    std::os::unix::process::CommandExt::process_group(command, 0);
+}
+
+#[cfg(not(unix))]
+fn isolate(_command: &mut Command) {}
+
+/// Kill `child` and the rest of its process group, whose children would keep its pipes open.
+#[cfg(unix)]
+fn kill(child: &mut Child) -> io::Result<()> {
+    // SAFETY: `kill` takes no pointer; a negative pid targets the group led by the child.
+    match unsafe { libc::kill(-(child.id() as i32), libc::SIGKILL) } {
+        0 => Ok(()),
+        _ => Err(io::Error::last_os_error()),
+    }
+}
+
+#[cfg(not(unix))]
+fn kill(child: &mut Child) -> io::Result<()> {
+    child.kill()
+}
+
+/// Run `command` to completion under `limits`, capturing its output.
+pub fn run(mut command: Command, limits: &Limits) -> io::Result<Outcome> {
+    apply_rlimits(&mut command, limits);
+    isolate(&mut command);
+    let timer = Instant::now();
+    let mut child = command
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+
+    // Read while the child runs, or it blocks once a pipe is full.
+    let drain = |mut pipe: Box<dyn Read + Send>| {
+        thread::spawn(move || {
+            let mut output = Vec::new();
+            let _ = pipe.read_to_end(&mut output);
+            String::from_utf8_lossy(&output).into_owned()
+        })
+    };
+    let stdout = drain(Box::new(child.stdout.take().unwrap()));
+    let stderr = drain(Box::new(child.stderr.take().unwrap()));
+
+    let mut timed_out = false;
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break status;
+        }
+        if limits
+            .timeout
+            .is_some_and(|timeout| timer.elapsed() >= timeout)
+        {
+            timed_out = true;
+            let _ = kill(&mut child);
+            break child.wait()?;
+        }
+        thread::sleep(Duration::from_millis(2));
+    };
+    let elapsed = timer.elapsed();
+
+    let stdout = stdout.join().unwrap_or_default();
+    let stderr = stderr.join().unwrap_or_default();
+    Ok(Outcome {
+        exit: classify(status.code(), signal_of(&status), &stderr, timed_out),
+        stdout,
+        stderr,
+        elapsed,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sh(script: &str) -> Command {
+        let mut command = Command::new("sh");
+        command.args(["-c", script]);
+        command
+    }
+
+    #[test]
+    fn test_classify() {
+        assert_eq!(classify(Some(0), None, "", false), Exit::Success);
+        assert_eq!(
+            classify(Some(101), None, "thread 'main' panicked at", false),
+            Exit::Panic
+        );
+        assert_eq!(classify(Some(1), None, "", false), Exit::Error(1));
+        assert_eq!(classify(None, Some(9), "", true), Exit::Timeout);
+        assert_eq!(classify(None, Some(11), "", false), Exit::Signal(11));
+        assert_eq!(
+            classify(
+                None,
+                Some(6),
+                "memory allocation of 1024 bytes failed",
+                false
+            ),
+            Exit::OutOfMemory
+        );
+    }
+
+    #[test]
+    fn test_run_captures_output() {
+        let outcome = run(sh("echo out; echo err >&2"), &Limits::default()).unwrap();
+        assert_eq!(
+            (
+                outcome.exit,
+                outcome.stdout.as_str(),
+                outcome.stderr.as_str()
+            ),
+            (Exit::Success, "out\n", "err\n")
+        );
+        let outcome = run(sh("exit 101"), &Limits::default()).unwrap();
+        assert_eq!(outcome.exit, Exit::Panic);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_run_signal_and_timeout() {
+        let outcome = run(sh("kill -SEGV $$"), &Limits::default()).unwrap();
+        assert_eq!(outcome.exit, Exit::Signal(11));
+
+        let limits = Limits {
+            timeout: Some(Duration::from_millis(100)),
+            ..Limits::default()
+        };
+        let outcome = run(sh("sleep 5"), &limits).unwrap();
+        assert_eq!(outcome.exit, Exit::Timeout);
+        assert!(outcome.elapsed < Duration::from_secs(4));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_run_timeout_kills_grandchildren() {
+        let limits = Limits {
+            timeout: Some(Duration::from_millis(100)),
+            ..Limits::default()
+        };
+        let timer = Instant::now();
+        // The inner shell keeps the pipes open as long as its `sleep` runs.
+        let outcome = run(sh("sh -c 'sleep 5; echo late'; echo done"), &limits).unwrap();
+        assert_eq!(outcome.exit, Exit::Timeout);
+        assert_eq!(outcome.stdout, "");
+        assert!(timer.elapsed() < Duration::from_secs(4));
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_run_cpu_limit() {
+        let limits = Limits {
+            cpu: Some(Duration::from_secs(1)),
+            ..Limits::default()
+        };
+        let outcome = run(sh("while :; do :; done"), &limits).unwrap();
+        assert_eq!(outcome.exit, Exit::Timeout);
+    }
+}
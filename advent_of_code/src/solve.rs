@@ -1,14 +1,33 @@
+use std::{
+    fmt::Display,
+    fs, io,
+    panic::{self, AssertUnwindSafe},
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    status, strip_ansi, ANSI_BOLD, ANSI_GREEN, ANSI_ITALIC, ANSI_RED, ANSI_RESET, ANSI_YELLOW,
+};
+
 #[macro_export]
 macro_rules! solve {
     ($part:expr, $solver:ident, $input:expr) => {{
-        use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+        use advent_of_code::{
+            alloc_stats, helpers::parse, profile, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
+        };
         use std::fmt::Display;
         use std::time::Instant;
 
         fn print_result<T: Display>(func: impl FnOnce(&str) -> Option<T>, input: &str) {
+            profile::enable(profile::requested());
+            profile::take();
+            alloc_stats::reset();
+            parse::take_reported();
             let timer = Instant::now();
             let result = func(input);
             let elapsed = timer.elapsed();
+            let allocations = alloc_stats::stats();
             match result {
                 Some(result) => {
                     println!(
@@ -17,12 +36,331 @@ macro_rules! solve {
                     );
                 }
                 None => {
-                    println!("not solved.")
+                    println!("not solved.");
+                    // On stderr, where it doesn't change what `status` and `watch` read.
+                    if let Some(error) = parse::take_reported() {
+                        eprintln!("{}", error);
+                    }
                 }
             }
+            // On its own line, so that `parse_exec_time` is not thrown off by it.
+            if let Some(allocations) = allocations {
+                println!("  {}", allocations);
+            }
+            if profile::is_enabled() {
+                print!("{}", profile::render(&profile::take()));
+            }
         }
 
         println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, $part, ANSI_RESET);
+        advent_of_code::trace::start(env!("CARGO_PKG_NAME"), $part);
         print_result($solver, $input);
     }};
 }
+
+//
+
+/// An input of `inputs/`, with the answers known for it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NamedInput {
+    pub name: String,
+    pub text: String,
+    pub answers: [Option<String>; 2],
+}
+
+/// Known answers, one `<part>: <answer>` line per part, e.g. `1: 24000`.
+pub fn parse_answers(text: &str) -> [Option<String>; 2] {
+    let mut answers = [None, None];
+    for line in text.lines() {
+        let Some((part, answer)) = line.split_once(':') else {
+            continue;
+        };
+        if let Ok(part @ 1..=2) = part.trim().parse::<usize>() {
+            answers[part - 1] = Some(answer.trim().to_string());
+        }
+    }
+    answers
+}
+
+/// Every `<name>.txt` of `directory` with the answers of `<name>.answers`, sorted by name.
+pub fn read_inputs(directory: &Path) -> io::Result<Vec<NamedInput>> {
+    let mut inputs = Vec::new();
+    for entry in fs::read_dir(directory)? {
+        let path = entry?.path();
+        if path.extension().is_some_and(|extension| extension == "txt") {
+            let answers = fs::read_to_string(path.with_extension("answers")).unwrap_or_default();
+            inputs.push(NamedInput {
+                name: path.file_stem().unwrap().to_string_lossy().into_owned(),
+                text: fs::read_to_string(&path)?,
+                answers: parse_answers(&answers),
+            });
+        }
+    }
+    inputs.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(inputs)
+}
+
+/// Answers known for the day's own `input.txt`: those of `input.answers`, or else
+/// the ones accepted in its `README.md`.
+pub fn input_answers(day_path: &Path) -> [Option<String>; 2] {
+    match fs::read_to_string(day_path.join("input.answers")) {
+        Ok(answers) => parse_answers(&answers),
+        Err(_) => fs::read_to_string(day_path.join("README.md"))
+            .map(|puzzle| status::accepted_answers(&puzzle))
+            .unwrap_or_default(),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong { expected: String },
+    Unknown,
+    Panicked(String),
+}
+
+/// Outcome of a part on one input.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cell {
+    pub answer: Option<String>,
+    pub elapsed: Duration,
+    pub verdict: Verdict,
+}
+
+impl Cell {
+    pub fn is_failure(&self) -> bool {
+        matches!(self.verdict, Verdict::Wrong { .. } | Verdict::Panicked(_))
+    }
+
+    fn render(&self) -> String {
+        let answer = self.answer.as_deref().unwrap_or("not solved");
+        match &self.verdict {
+            Verdict::Correct => format!(
+                "{}✓{} {} ({:.2?})",
+                ANSI_GREEN, ANSI_RESET, answer, self.elapsed
+            ),
+            Verdict::Wrong { expected } => format!(
+                "{}✗{} {} ({:.2?}), expected {}",
+                ANSI_RED, ANSI_RESET, answer, self.elapsed, expected
+            ),
+            Verdict::Unknown => format!(
+                "{}?{} {} ({:.2?})",
+                ANSI_YELLOW, ANSI_RESET, answer, self.elapsed
+            ),
+            Verdict::Panicked(message) => {
+                // Parse errors point at the input on the next lines, only the first one fits.
+                let first_line = message.lines().next().unwrap_or_default();
+                let mut summary: String = first_line.chars().take(60).collect();
+                if summary.len() < first_line.len() {
+                    summary.push('…');
+                }
+                format!("{}!{} panicked: {}", ANSI_RED, ANSI_RESET, summary)
+            }
+        }
+    }
+}
+
+/// Stop printing panic messages, which `check_part` reports instead.
+///
+/// To call once before checking parts: the panic hook is global to the process.
+pub fn silence_panics() {
+    panic::set_hook(Box::new(|_| {}));
+}
+
+/// Run `solver` on `input`, catching its panic.
+pub fn check_part<T: Display>(
+    solver: impl Fn(&str) -> Option<T>,
+    input: &str,
+    expected: Option<&str>,
+) -> Cell {
+    let timer = Instant::now();
+    let result = panic::catch_unwind(AssertUnwindSafe(|| solver(input)));
+    let elapsed = timer.elapsed();
+
+    match result {
+        Ok(answer) => {
+            let answer = answer.map(|answer| answer.to_string());
+            let verdict = match expected {
+                None => Verdict::Unknown,
+                Some(expected) if answer.as_deref() == Some(expected) => Verdict::Correct,
+                Some(expected) => Verdict::Wrong {
+                    expected: expected.to_string(),
+                },
+            };
+            Cell {
+                answer,
+                elapsed,
+                verdict,
+            }
+        }
+        Err(payload) => {
+            let message = payload
+                .downcast_ref::<&str>()
+                .map(|message| message.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_default();
+            Cell {
+                answer: None,
+                elapsed,
+                verdict: Verdict::Panicked(message),
+            }
+        }
+    }
+}
+
+/// Header of a day in the output of `run_all` and `aoc-all`.
+pub fn print_day_header(label: &str) {
+    println!("----------------");
+    println!("{}| {} |{}", ANSI_BOLD, label, ANSI_RESET);
+    println!("----------------");
+}
+
+/// A part as `solve!` prints it, followed by how its answer compares to the known one.
+pub fn print_checked_part(part: u8, cell: &Cell) {
+    println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, part, ANSI_RESET);
+    let mark = match &cell.verdict {
+        Verdict::Correct => format!("{}✓{}", ANSI_GREEN, ANSI_RESET),
+        Verdict::Wrong { expected } => format!("{}✗ expected {}{}", ANSI_RED, expected, ANSI_RESET),
+        Verdict::Unknown => format!("{}?{}", ANSI_YELLOW, ANSI_RESET),
+        Verdict::Panicked(message) => {
+            println!("{}panicked: {}{}", ANSI_RED, message, ANSI_RESET);
+            return;
+        }
+    };
+    match &cell.answer {
+        Some(answer) => println!(
+            "{} {}(elapsed: {:.2?}){} {}",
+            answer, ANSI_ITALIC, cell.elapsed, ANSI_RESET, mark
+        ),
+        None => println!("not solved. {}", mark),
+    }
+}
+
+/// Whether the binary was given `--all-inputs`.
+pub fn all_inputs_requested() -> bool {
+    std::env::args().any(|arg| arg == "--all-inputs")
+}
+
+/// Solve both parts on every input of the day's `inputs/` and print them as a matrix,
+/// exiting with an error when an answer is wrong or a part panicked.
+pub fn all_inputs<A: Display, B: Display>(
+    part_one: impl Fn(&str) -> Option<A>,
+    part_two: impl Fn(&str) -> Option<B>,
+) {
+    let cwd = std::env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR not found in env");
+    let directory = Path::new(&cwd).join("inputs");
+    let inputs = read_inputs(&directory).unwrap_or_else(|err| {
+        eprintln!("Could not read inputs in {}: {}", directory.display(), err);
+        std::process::exit(1);
+    });
+
+    silence_panics();
+    let rows: Vec<(String, [Cell; 2])> = inputs
+        .iter()
+        .map(|input| {
+            let cells = [
+                check_part(&part_one, &input.text, input.answers[0].as_deref()),
+                check_part(&part_two, &input.text, input.answers[1].as_deref()),
+            ];
+            (input.name.clone(), cells)
+        })
+        .collect();
+
+    let name_width = rows
+        .iter()
+        .map(|(name, _)| name.len())
+        .max()
+        .unwrap_or(0)
+        .max(5);
+    let rendered: Vec<[String; 2]> = rows
+        .iter()
+        .map(|(_, [one, two])| [one.render(), two.render()])
+        .collect();
+    // ANSI codes are not shown, the first column is padded on its visible width.
+    let visible = |cell: &str| strip_ansi(cell).chars().count();
+    let first_width = rendered
+        .iter()
+        .map(|[one, _]| visible(one))
+        .max()
+        .unwrap_or(0)
+        .max(6);
+
+    println!(
+        "{}{:<name_width$}  {:<first_width$}  Part 2{}",
+        ANSI_BOLD, "input", "Part 1", ANSI_RESET
+    );
+    for ((name, _), [one, two]) in rows.iter().zip(&rendered) {
+        let padding = " ".repeat(first_width - visible(one));
+        println!("{:<name_width$}  {}{}  {}", name, one, padding, two);
+    }
+
+    if rows
+        .iter()
+        .any(|(_, cells)| cells.iter().any(Cell::is_failure))
+    {
+        std::process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::process;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_answers() {
+        assert_eq!(
+            parse_answers(
+                "1: 24000
+2:  45000 
+"
+            ),
+            [Some("24000".to_string()), Some("45000".to_string())]
+        );
+        assert_eq!(
+            parse_answers("2: EHZFZHCZ"),
+            [None, Some("EHZFZHCZ".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_read_inputs() {
+        let directory = std::env::temp_dir().join(format!("solve-inputs-{}", process::id()));
+        fs::create_dir_all(&directory).unwrap();
+        fs::write(directory.join("bob.txt"), "2").unwrap();
+        fs::write(directory.join("alice.txt"), "1").unwrap();
+        fs::write(directory.join("alice.answers"), "1: 3").unwrap();
+        let inputs = read_inputs(&directory).unwrap();
+        fs::remove_dir_all(&directory).unwrap();
+
+        let names: Vec<_> = inputs.iter().map(|input| input.name.as_str()).collect();
+        assert_eq!(names, ["alice", "bob"]);
+        assert_eq!(inputs[0].answers, [Some("3".to_string()), None]);
+        assert_eq!(inputs[1].answers, [None, None]);
+    }
+
+    #[test]
+    fn test_check_part() {
+        let double = |input: &str| input.parse::<u32>().ok().map(|n| n * 2);
+        assert_eq!(
+            check_part(double, "21", Some("42")).verdict,
+            Verdict::Correct
+        );
+        assert_eq!(check_part(double, "21", None).verdict, Verdict::Unknown);
+        assert_eq!(
+            check_part(double, "20", Some("42")).verdict,
+            Verdict::Wrong {
+                expected: "42".to_string()
+            }
+        );
+
+        let parse = |input: &str| Some(input.parse::<u32>().expect("not a number"));
+        let cell = check_part(parse, "x", Some("1"));
+        assert_eq!(
+            cell.verdict,
+            Verdict::Panicked("not a number: ParseIntError { kind: InvalidDigit }".to_string())
+        );
+        assert!(cell.is_failure());
+    }
+}
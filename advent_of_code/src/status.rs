@@ -0,0 +1,338 @@
+/*
+ * Workspace scan backing the `status` binary.
+ * A day is described by what exists on disk (crate, input, puzzle) and, optionally,
+ * by what `cargo test` and `cargo run` report for it.
+ */
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+};
+
+use serde::Serialize;
+
+use crate::{ANSI_BOLD, ANSI_DIM, ANSI_GREEN, ANSI_RED, ANSI_RESET, ANSI_YELLOW};
+
+pub const DAYS: u8 = 25;
+
+const STAR: &str = "★";
+const EMPTY_STAR: &str = "☆";
+
+#[derive(Debug, Default, PartialEq, Serialize)]
+pub struct DayStatus {
+    pub year: u16,
+    pub day: u8,
+    /// `<year>/day_<dd>/Cargo.toml` exists.
+    pub scaffolded: bool,
+    /// `input.txt` was downloaded.
+    pub input: bool,
+    /// `README.md` (the puzzle description) was downloaded.
+    pub puzzle: bool,
+    /// Outcome of `cargo test` on the day crate, `None` when not run.
+    pub tests: Option<bool>,
+    /// Whether each part returned `Some` on the real input, `None` when not run.
+    pub parts: Option<[bool; 2]>,
+    /// Answers marked as accepted in the downloaded puzzle description.
+    pub accepted: [bool; 2],
+}
+
+#[derive(Debug, Default, PartialEq, Serialize)]
+pub struct YearStatus {
+    pub year: u16,
+    pub days: Vec<DayStatus>,
+}
+
+pub fn package_name(year: u16, day: u8) -> String {
+    format!("day_{}_{:02}", year, day)
+}
+
+/// Year and day of a `day_YYYY_DD` package.
+pub fn parse_package_name(package: &str) -> Option<(u16, u8)> {
+    let (year, day) = package.strip_prefix("day_")?.split_once('_')?;
+    Some((year.parse().ok()?, day.parse().ok()?))
+}
+
+pub fn day_path(root: &Path, year: u16, day: u8) -> PathBuf {
+    root.join(year.to_string()).join(format!("day_{:02}", day))
+}
+
+/// Years with a `20XX/` folder in the workspace, in ascending order.
+pub fn find_years(root: &Path) -> Vec<u16> {
+    let mut years: Vec<u16> = fs::read_dir(root)
+        .map(|entries| {
+            entries
+                .flatten()
+                .filter(|entry| entry.path().is_dir())
+                .filter_map(|entry| entry.file_name().to_str()?.parse().ok())
+                .filter(|year| (2015..=2099).contains(year))
+                .collect()
+        })
+        .unwrap_or_default();
+    years.sort_unstable();
+    years
+}
+
+/// Every day with a package in the workspace, by year then day.
+///
+/// `run_all` runs these days and `aoc-all` is built with them.
+pub fn scaffolded_days(root: &Path) -> Vec<(u16, u8)> {
+    find_years(root)
+        .into_iter()
+        .flat_map(|year| (1..=DAYS).map(move |day| (year, day)))
+        .filter(|&(year, day)| day_path(root, year, day).join("Cargo.toml").is_file())
+        .collect()
+}
+
+/// Inspect the files of a day without running anything.
+pub fn scan_day(root: &Path, year: u16, day: u8) -> DayStatus {
+    let path = day_path(root, year, day);
+    let puzzle = fs::read_to_string(path.join("README.md")).ok();
+
+    DayStatus {
+        year,
+        day,
+        scaffolded: path.join("Cargo.toml").is_file(),
+        input: path.join("input.txt").is_file(),
+        puzzle: puzzle.is_some(),
+        tests: None,
+        parts: None,
+        accepted: puzzle.as_deref().map(parse_accepted).unwrap_or_default(),
+    }
+}
+
+/// Run the example tests and the real input of a scaffolded day.
+pub fn run_day(status: &mut DayStatus) {
+    if !status.scaffolded {
+        return;
+    }
+    let package = package_name(status.year, status.day);
+
+    status.tests = Command::new("cargo")
+        .args(["test", "--quiet", "-p", &package])
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .ok()
+        .map(|exit| exit.success());
+
+    if status.input {
+        status.parts = Command::new("cargo")
+            .args(["run", "--quiet", "--release", "-p", &package])
+            .stderr(Stdio::null())
+            .output()
+            .ok()
+            .map(|output| parse_solved_parts(&String::from_utf8_lossy(&output.stdout)));
+    }
+}
+
+pub fn scan_year(root: &Path, year: u16, run: bool) -> YearStatus {
+    let days = (1..=DAYS)
+        .map(|day| {
+            let mut status = scan_day(root, year, day);
+            if run {
+                run_day(&mut status);
+            }
+            status
+        })
+        .collect();
+
+    YearStatus { year, days }
+}
+
+/// aoc-cli keeps accepted answers in the puzzle description, one per solved part.
+pub fn parse_accepted(puzzle: &str) -> [bool; 2] {
+    let count = puzzle.matches("Your puzzle answer was").count();
+    [count >= 1, count >= 2]
+}
+
+/// The answers accepted in the puzzle description, in part order.
+pub fn accepted_answers(puzzle: &str) -> [Option<String>; 2] {
+    let mut answers = puzzle.split("Your puzzle answer was").skip(1).map(|rest| {
+        let answer = rest.trim_start().strip_prefix('`')?;
+        answer.split_once('`').map(|(answer, _)| answer.to_string())
+    });
+    [answers.next().flatten(), answers.next().flatten()]
+}
+
+/// Read the output of `solve!`: a part is solved unless it printed "not solved.".
+pub fn parse_solved_parts(output: &str) -> [bool; 2] {
+    let mut parts = [false; 2];
+    let mut lines = output.lines();
+
+    while let Some(line) = lines.next() {
+        let index = if line.contains("Part 1") {
+            0
+        } else if line.contains("Part 2") {
+            1
+        } else {
+            continue;
+        };
+        parts[index] = lines
+            .next()
+            .map(|result| !result.trim().is_empty() && result.trim() != "not solved.")
+            .unwrap_or(false);
+    }
+
+    parts
+}
+
+fn colored(color: &str, text: &str) -> String {
+    format!("{}{}{}", color, text, ANSI_RESET)
+}
+
+fn flag(value: bool, label: &str) -> String {
+    if value {
+        colored(ANSI_GREEN, label)
+    } else {
+        colored(ANSI_DIM, "·")
+    }
+}
+
+impl DayStatus {
+    /// Render as a fixed width calendar cell, e.g. `05 irt ★☆`.
+    pub fn cell(&self) -> String {
+        if !self.scaffolded {
+            return colored(ANSI_DIM, &format!("{:02}       ", self.day));
+        }
+
+        let tests = match self.tests {
+            Some(true) => colored(ANSI_GREEN, "t"),
+            Some(false) => colored(ANSI_RED, "t"),
+            None => colored(ANSI_DIM, "·"),
+        };
+
+        let stars = (0..2)
+            .map(
+                |part| match (self.accepted[part], self.parts.map(|p| p[part])) {
+                    (true, _) => colored(ANSI_YELLOW, STAR),
+                    (false, Some(true)) => colored(ANSI_BOLD, EMPTY_STAR),
+                    _ => colored(ANSI_DIM, EMPTY_STAR),
+                },
+            )
+            .collect::<String>();
+
+        format!(
+            "{:02} {}{}{} {}",
+            self.day,
+            flag(self.input, "i"),
+            flag(self.puzzle, "r"),
+            tests,
+            stars
+        )
+    }
+}
+
+impl YearStatus {
+    pub fn stars(&self) -> usize {
+        self.days
+            .iter()
+            .flat_map(|day| day.accepted)
+            .filter(|accepted| *accepted)
+            .count()
+    }
+
+    /// Render the 25 days as a 5x5 calendar grid.
+    pub fn render(&self) -> String {
+        let mut lines = vec![format!(
+            "{}{}{} {}{} {}{}",
+            ANSI_BOLD,
+            self.year,
+            ANSI_RESET,
+            ANSI_YELLOW,
+            self.stars(),
+            STAR,
+            ANSI_RESET
+        )];
+        lines.extend(self.days.chunks(5).map(|week| {
+            week.iter()
+                .map(DayStatus::cell)
+                .collect::<Vec<_>>()
+                .join("  ")
+        }));
+        lines.join("\n")
+    }
+}
+
+pub fn legend() -> String {
+    format!(
+        "{} input  {} puzzle  {} tests ({} failing)  {} accepted  {} solved  {} missing",
+        colored(ANSI_GREEN, "i"),
+        colored(ANSI_GREEN, "r"),
+        colored(ANSI_GREEN, "t"),
+        colored(ANSI_RED, "t"),
+        colored(ANSI_YELLOW, STAR),
+        colored(ANSI_BOLD, EMPTY_STAR),
+        colored(ANSI_DIM, "·"),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_package_name() {
+        assert_eq!(parse_package_name(&package_name(2022, 5)), Some((2022, 5)));
+        assert_eq!(parse_package_name("advent_of_code"), None);
+    }
+
+    #[test]
+    fn test_parse_accepted() {
+        assert_eq!(parse_accepted("--- Day 1 ---"), [false, false]);
+        assert_eq!(
+            parse_accepted("Your puzzle answer was `69912`.\n--- Part Two ---"),
+            [true, false]
+        );
+        assert_eq!(
+            parse_accepted("Your puzzle answer was `1`.\nYour puzzle answer was `2`."),
+            [true, true]
+        );
+        assert_eq!(parse_accepted("Your puzzle answer was 42."), [true, false]);
+        assert_eq!(
+            accepted_answers("Your puzzle answer was `69912`.\nYour puzzle answer was `EHZFZHCZ`."),
+            [Some("69912".to_string()), Some("EHZFZHCZ".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_parse_solved_parts() {
+        assert_eq!(
+            parse_solved_parts(&format!(
+                "🎄 {}Part 1{} 🎄\n6 (elapsed: 37.03µs)\n🎄 {}Part 2{} 🎄\nnot solved.",
+                ANSI_BOLD, ANSI_RESET, ANSI_BOLD, ANSI_RESET
+            )),
+            [true, false]
+        );
+        assert_eq!(parse_solved_parts(""), [false, false]);
+    }
+
+    #[test]
+    fn test_render_has_25_days() {
+        let year = YearStatus {
+            year: 2022,
+            days: (1..=DAYS)
+                .map(|day| DayStatus {
+                    year: 2022,
+                    day,
+                    ..Default::default()
+                })
+                .collect(),
+        };
+        assert_eq!(year.render().lines().count(), 1 + 5);
+    }
+
+    #[test]
+    fn test_json() {
+        let day = DayStatus {
+            year: 2022,
+            day: 1,
+            scaffolded: true,
+            accepted: [true, false],
+            ..Default::default()
+        };
+        assert_eq!(
+            serde_json::to_string(&day).unwrap(),
+            r#"{"year":2022,"day":1,"scaffolded":true,"input":false,"puzzle":false,"tests":null,"parts":null,"accepted":[true,false]}"#
+        );
+    }
+}
@@ -0,0 +1,215 @@
+//! Lines traced from inside solutions, to follow them on a real input.
+//!
+//! `trace!` takes `format!` arguments and prints nothing unless the binary was given
+//! `--trace`, or `--trace=<module>` to keep the lines of that module only (`--trace=round`
+//! for `puzzles::round`). `--trace-limit <n>` stops each part after `n` lines. Lines go to
+//! stderr, prefixed by the year, day and part set by `solve!`. With the `trace-off`
+//! feature the calls are compiled out.
+
+use std::{
+    env, fmt, process,
+    sync::{
+        atomic::{AtomicBool, Ordering::Relaxed},
+        Mutex, OnceLock,
+    },
+};
+
+use crate::status;
+
+/// Whether the `trace-off` feature removes every `trace!`.
+pub const COMPILED_OUT: bool = cfg!(feature = "trace-off");
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static SETTINGS: OnceLock<Option<Settings>> = OnceLock::new();
+static STATE: Mutex<State> = Mutex::new(State {
+    prefix: String::new(),
+    lines: 0,
+});
+
+/// Print a line to stderr when tracing, with `format!` arguments.
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        if !$crate::trace::COMPILED_OUT && $crate::trace::is_enabled() {
+            $crate::trace::emit(module_path!(), format_args!($($arg)*));
+        }
+    };
+}
+
+/// What `--trace` and `--trace-limit` asked for.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Settings {
+    /// Modules to keep the lines of, every module when empty.
+    pub modules: Vec<String>,
+    /// Lines printed at most per part.
+    pub limit: Option<usize>,
+}
+
+impl Settings {
+    /// The settings given in `args`, `None` without `--trace`.
+    pub fn from_args<I: IntoIterator<Item = String>>(args: I) -> Result<Option<Self>, String> {
+        let mut traced = false;
+        let mut settings = Settings::default();
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            if arg == "--trace" {
+                traced = true;
+            } else if let Some(module) = arg.strip_prefix("--trace=") {
+                traced = true;
+                settings.modules.push(module.to_string());
+            } else if arg == "--trace-limit" || arg.starts_with("--trace-limit=") {
+                let value = match arg.strip_prefix("--trace-limit=") {
+                    Some(value) => Some(value.to_string()),
+                    None => args.next(),
+                };
+                let limit = value
+                    .and_then(|value| value.parse().ok())
+                    .ok_or("--trace-limit expects a number of lines")?;
+                settings.limit = Some(limit);
+            }
+        }
+        Ok(traced.then_some(settings))
+    }
+
+    /// Whether lines of `module_path` are kept, ignoring its crate name.
+    pub fn keeps(&self, module_path: &str) -> bool {
+        let path = module_path.split_once("::").map_or("", |(_, path)| path);
+        self.modules.is_empty()
+            || self.modules.iter().any(|module| {
+                [module_path, path].iter().any(|path| {
+                    path.strip_prefix(module.as_str())
+                        .is_some_and(|rest| rest.is_empty() || rest.starts_with("::"))
+                })
+            })
+    }
+}
+
+struct State {
+    prefix: String,
+    lines: usize,
+}
+
+//
+
+pub fn is_enabled() -> bool {
+    ENABLED.load(Relaxed)
+}
+
+fn settings() -> &'static Option<Settings> {
+    SETTINGS.get_or_init(|| match Settings::from_args(env::args().skip(1)) {
+        Ok(settings) => settings,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    })
+}
+
+/// Trace `part` of the day `package` from now on, when the binary was given `--trace`.
+pub fn start(package: &str, part: u8) {
+    let enabled = !COMPILED_OUT && settings().is_some();
+    let prefix = match status::parse_package_name(package) {
+        Some((year, day)) => format!("[{} day {:02} part {}]", year, day, part),
+        None => format!("[{} part {}]", package, part),
+    };
+    *STATE.lock().unwrap() = State { prefix, lines: 0 };
+    ENABLED.store(enabled, Relaxed);
+}
+
+/// The line to print for a `trace!` of `module_path`, counting it against the limit.
+fn record(settings: &Settings, module_path: &str, message: fmt::Arguments) -> Option<String> {
+    if !settings.keeps(module_path) {
+        return None;
+    }
+    let mut state = STATE.lock().unwrap();
+    state.lines += 1;
+    match settings.limit {
+        Some(limit) if state.lines == limit + 1 => Some(format!(
+            "{} trace limit of {} lines reached",
+            state.prefix, limit
+        )),
+        Some(limit) if state.lines > limit => None,
+        _ => Some(format!("{} {}", state.prefix, message)),
+    }
+}
+
+#[doc(hidden)]
+pub fn emit(module_path: &str, message: fmt::Arguments) {
+    if let Some(settings) = settings() {
+        if let Some(line) = record(settings, module_path, message) {
+            eprintln!("{}", line);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(line: &str) -> Vec<String> {
+        line.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn test_from_args() {
+        assert_eq!(Settings::from_args(args("--profile")), Ok(None));
+        assert_eq!(
+            Settings::from_args(args("--trace --trace-limit 20")),
+            Ok(Some(Settings {
+                modules: vec![],
+                limit: Some(20)
+            }))
+        );
+        assert_eq!(
+            Settings::from_args(args("--trace=round --trace=parse --trace-limit=3")),
+            Ok(Some(Settings {
+                modules: vec!["round".to_string(), "parse".to_string()],
+                limit: Some(3)
+            }))
+        );
+        assert!(Settings::from_args(args("--trace --trace-limit many")).is_err());
+    }
+
+    #[test]
+    fn test_keeps() {
+        let every = Settings::default();
+        assert!(every.keeps("puzzles"));
+
+        let round = Settings {
+            modules: vec!["round".to_string()],
+            limit: None,
+        };
+        assert!(round.keeps("puzzles::round"));
+        assert!(round.keeps("puzzles::round::turn"));
+        assert!(!round.keeps("puzzles"));
+        assert!(!round.keeps("puzzles::rounds"));
+
+        let puzzles = Settings {
+            modules: vec!["puzzles".to_string()],
+            limit: None,
+        };
+        assert!(puzzles.keeps("puzzles::round"));
+    }
+
+    // A single test, as the state is shared by the tests running in parallel.
+    #[test]
+    fn test_record() {
+        let settings = Settings {
+            modules: vec!["round".to_string()],
+            limit: Some(2),
+        };
+        start("day_2022_11", 2);
+        let lines: Vec<_> = (1..=4)
+            .filter_map(|n| record(&settings, "puzzles::round", format_args!("throw {}", n)))
+            .collect();
+        assert_eq!(record(&settings, "puzzles", format_args!("ignored")), None);
+        assert_eq!(
+            lines,
+            [
+                "[2022 day 11 part 2] throw 1",
+                "[2022 day 11 part 2] throw 2",
+                "[2022 day 11 part 2] trace limit of 2 lines reached",
+            ]
+        );
+    }
+}
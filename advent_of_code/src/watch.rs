@@ -0,0 +1,260 @@
+//! Poll the files of a day for changes, and compare the answers of successive runs.
+
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use crate::{strip_ansi, ANSI_DIM, ANSI_RESET, ANSI_YELLOW};
+
+/// Modification time of every watched file.
+pub type Snapshot = BTreeMap<PathBuf, SystemTime>;
+
+/// Modification times of the sources, examples and input of the day at `path`.
+pub fn snapshot(path: &Path) -> Snapshot {
+    let mut files = Snapshot::new();
+    collect_sources(&path.join("src"), &mut files);
+    if let Ok(entries) = fs::read_dir(path) {
+        for entry in entries.flatten() {
+            let name = entry.file_name().to_string_lossy().into_owned();
+            let is_example = name.starts_with("example") && name.ends_with(".txt");
+            if is_example || name == "input.txt" {
+                insert_modified(entry.path(), &mut files);
+            }
+        }
+    }
+    files
+}
+
+fn collect_sources(directory: &Path, files: &mut Snapshot) {
+    let Ok(entries) = fs::read_dir(directory) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            collect_sources(&path, files);
+        } else {
+            insert_modified(path, files);
+        }
+    }
+}
+
+fn insert_modified(path: PathBuf, files: &mut Snapshot) {
+    if let Ok(modified) = fs::metadata(&path).and_then(|metadata| metadata.modified()) {
+        files.insert(path, modified);
+    }
+}
+
+/// Files added, removed or modified from `before` to `after`.
+pub fn changes(before: &Snapshot, after: &Snapshot) -> Vec<PathBuf> {
+    let mut changed: Vec<PathBuf> = after
+        .iter()
+        .filter(|(path, modified)| before.get(*path) != Some(modified))
+        .map(|(path, _)| path.clone())
+        .collect();
+    changed.extend(
+        before
+            .keys()
+            .filter(|path| !after.contains_key(*path))
+            .cloned(),
+    );
+    changed.sort();
+    changed
+}
+
+/// Wait until the files stop changing for `debounce`, as editors save in several writes.
+pub fn settle(path: &Path, mut current: Snapshot, debounce: Duration) -> Snapshot {
+    loop {
+        thread::sleep(debounce);
+        let next = snapshot(path);
+        if next == current {
+            return current;
+        }
+        current = next;
+    }
+}
+
+//
+
+/// What `solve!` printed for a part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartResult {
+    pub part: u8,
+    /// `None` when not solved.
+    pub answer: Option<String>,
+    pub elapsed: Option<String>,
+}
+
+/// The answers printed by a day binary, the lines after each part title up to its timing.
+pub fn parse_results(output: &str) -> Vec<PartResult> {
+    let output = strip_ansi(output);
+    let mut results = Vec::new();
+    let mut lines = output.lines();
+
+    while let Some(line) = lines.next() {
+        let Some(part) = ["Part 1", "Part 2"].iter().position(|p| line.contains(p)) else {
+            continue;
+        };
+        let mut answer = Vec::new();
+        let mut elapsed = None;
+        for line in lines.by_ref() {
+            if line.trim() == "not solved." {
+                break;
+            }
+            match line.split_once(" (elapsed: ") {
+                Some((last, timing)) => {
+                    answer.push(last);
+                    elapsed = Some(timing.trim_end_matches(')').to_string());
+                    break;
+                }
+                None => answer.push(line),
+            }
+        }
+        results.push(PartResult {
+            part: part as u8 + 1,
+            answer: elapsed.is_some().then(|| answer.join("\n")),
+            elapsed,
+        });
+    }
+    results
+}
+
+/// Answers spanning several lines, like pictures, are summed up.
+fn compact(answer: &Option<String>) -> String {
+    match answer {
+        None => "not solved".to_string(),
+        Some(answer) if answer.contains('\n') => format!("[{} lines]", answer.lines().count()),
+        Some(answer) => answer.clone(),
+    }
+}
+
+/// One line per part, showing what changed since the `previous` run.
+pub fn diff(previous: Option<&[PartResult]>, current: &[PartResult]) -> Vec<String> {
+    current
+        .iter()
+        .map(|result| {
+            let before = previous
+                .and_then(|previous| previous.iter().find(|before| before.part == result.part));
+            let elapsed = result.elapsed.as_deref().unwrap_or("-");
+            match before {
+                Some(before) if before.answer != result.answer => format!(
+                    "Part {}: {}{} → {}{} ({})",
+                    result.part,
+                    ANSI_YELLOW,
+                    compact(&before.answer),
+                    compact(&result.answer),
+                    ANSI_RESET,
+                    elapsed
+                ),
+                Some(before) => format!(
+                    "Part {}: {} {}(unchanged, {} was {}){}",
+                    result.part,
+                    compact(&result.answer),
+                    ANSI_DIM,
+                    elapsed,
+                    before.elapsed.as_deref().unwrap_or("-"),
+                    ANSI_RESET
+                ),
+                None => format!(
+                    "Part {}: {} ({})",
+                    result.part,
+                    compact(&result.answer),
+                    elapsed
+                ),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::process;
+
+    use super::*;
+
+    #[test]
+    fn test_snapshot_changes() {
+        let day = std::env::temp_dir().join(format!("watch-snapshot-{}", process::id()));
+        fs::create_dir_all(day.join("src/bin")).unwrap();
+        for file in ["src/lib.rs", "src/bin/main.rs", "example.txt", "notes.md"] {
+            fs::write(day.join(file), "").unwrap();
+        }
+
+        let before = snapshot(&day);
+        let names: Vec<_> = before
+            .keys()
+            .map(|p| p.strip_prefix(&day).unwrap())
+            .collect();
+        assert_eq!(
+            names,
+            [
+                Path::new("example.txt"),
+                Path::new("src/bin/main.rs"),
+                Path::new("src/lib.rs")
+            ]
+        );
+
+        fs::write(day.join("input.txt"), "42").unwrap();
+        fs::remove_file(day.join("src/bin/main.rs")).unwrap();
+        let after = snapshot(&day);
+        fs::remove_dir_all(&day).unwrap();
+        assert_eq!(
+            changes(&before, &after),
+            [day.join("input.txt"), day.join("src/bin/main.rs")]
+        );
+    }
+
+    #[test]
+    fn test_parse_results() {
+        let output = "🎄 \x1b[1mPart 1\x1b[0m 🎄\n\
+            1088 \x1b[3m(elapsed: 3.72ms)\x1b[0m\n  allocs: 3, allocated: 10 B, peak: 10 B\n\
+            🎄 \x1b[1mPart 2\x1b[0m 🎄\n#..#\n#### \x1b[3m(elapsed: 12.00µs)\x1b[0m\n";
+        let results = parse_results(output);
+        assert_eq!(
+            results,
+            [
+                PartResult {
+                    part: 1,
+                    answer: Some("1088".to_string()),
+                    elapsed: Some("3.72ms".to_string())
+                },
+                PartResult {
+                    part: 2,
+                    answer: Some("#..#\n####".to_string()),
+                    elapsed: Some("12.00µs".to_string())
+                }
+            ]
+        );
+        let unsolved = parse_results("🎄 Part 1 🎄\nnot solved.\n");
+        assert_eq!(unsolved[0].answer, None);
+    }
+
+    #[test]
+    fn test_diff() {
+        let result = |part, answer: &str, elapsed: &str| PartResult {
+            part,
+            answer: Some(answer.to_string()),
+            elapsed: Some(elapsed.to_string()),
+        };
+        let first = [result(1, "21", "1ms"), result(2, "8", "2ms")];
+        let second = [result(1, "21", "3ms"), result(2, "9", "2ms")];
+
+        assert_eq!(diff(None, &first), ["Part 1: 21 (1ms)", "Part 2: 8 (2ms)"]);
+        let lines = diff(Some(&first), &second);
+        assert_eq!(
+            lines[0],
+            format!(
+                "Part 1: 21 {}(unchanged, 3ms was 1ms){}",
+                ANSI_DIM, ANSI_RESET
+            )
+        );
+        assert_eq!(
+            lines[1],
+            format!("Part 2: {}8 → 9{} (2ms)", ANSI_YELLOW, ANSI_RESET)
+        );
+    }
+}
@@ -0,0 +1,76 @@
+//! Generates the table of the days `run_all` runs, `status::scaffolded_days`, with
+//! their input and accepted answers embedded when the `embed-inputs` feature is on.
+//! Each of them must be a dependency.
+
+use std::{env, fmt::Write, fs, path::Path};
+
+use advent_of_code::{solve, status};
+
+/// Days from the dependencies of `Cargo.toml`: `y<year>_d<day> = { package = "day_<year>_<day>", ... }`.
+fn days(manifest: &str) -> Vec<(String, u16, u8)> {
+    manifest
+        .lines()
+        .filter_map(|line| {
+            let (name, _) = line.split_once(" = ")?;
+            let (year, day) = name.strip_prefix('y')?.split_once("_d")?;
+            Some((name.to_string(), year.parse().ok()?, day.parse().ok()?))
+        })
+        .collect()
+}
+
+fn main() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let root = Path::new(&manifest_dir).parent().unwrap();
+    let manifest = fs::read_to_string(Path::new(&manifest_dir).join("Cargo.toml")).unwrap();
+    let embed = env::var_os("CARGO_FEATURE_EMBED_INPUTS").is_some();
+    println!("cargo:rerun-if-changed=Cargo.toml");
+
+    let listed = days(&manifest);
+    let mut table = String::from("static DAYS: &[Day] = &[\n");
+    let mut solvers = String::new();
+    for (year, day) in status::scaffolded_days(root) {
+        let Some((name, _, _)) = listed.iter().find(|&&(_, y, d)| (y, d) == (year, day)) else {
+            println!(
+                "cargo:warning={} is not a dependency of aoc_all",
+                status::package_name(year, day)
+            );
+            continue;
+        };
+        for part in ["part_one", "part_two"] {
+            writeln!(
+                solvers,
+                "fn {name}_{part}(input: &str) -> Option<String> {{\n    \
+                 {name}::{part}(input).map(|answer| answer.to_string())\n}}"
+            )
+            .unwrap();
+        }
+
+        let path = status::day_path(root, year, day);
+        let embedded = if embed {
+            let input = path.join("input.txt");
+            for file in ["input.txt", "input.answers", "README.md"] {
+                println!("cargo:rerun-if-changed={}", path.join(file).display());
+            }
+            let answers = solve::input_answers(&path);
+            match input.is_file() {
+                true => format!(
+                    "Some(Embedded {{ input: include_str!({:?}), answers: {:?} }})",
+                    input.display().to_string(),
+                    answers
+                ),
+                false => "None".to_string(),
+            }
+        } else {
+            "None".to_string()
+        };
+        writeln!(
+            table,
+            "    Day {{ year: {year}, day: {day}, parts: [{name}_part_one, {name}_part_two], embedded: {embedded} }},"
+        )
+        .unwrap();
+    }
+    table.push_str("];\n");
+
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("days.rs");
+    fs::write(out, table + "\n" + &solvers).unwrap();
+}
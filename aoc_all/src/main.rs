@@ -0,0 +1,98 @@
+/*
+ * Every day in one binary, to run and verify them where the repository is not checked out.
+ * Build it with `--features embed-inputs` to compile the inputs and answers into it.
+ */
+use std::{env, fs, process, time::Duration};
+
+use advent_of_code::{
+    solve::{self, Cell},
+    status, ANSI_BOLD, ANSI_DIM, ANSI_ITALIC, ANSI_RESET,
+};
+
+type Solver = fn(&str) -> Option<String>;
+
+struct Embedded {
+    input: &'static str,
+    answers: [Option<&'static str>; 2],
+}
+
+struct Day {
+    year: u16,
+    day: u8,
+    parts: [Solver; 2],
+    embedded: Option<Embedded>,
+}
+
+include!(concat!(env!("OUT_DIR"), "/days.rs"));
+
+struct Args {
+    year: Option<u16>,
+    day: Option<u8>,
+    part: Option<u8>,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        year: args.opt_value_from_str(["-y", "--year"])?,
+        day: args.opt_value_from_str(["-d", "--day"])?,
+        part: args.opt_value_from_str(["-p", "--part"])?,
+    })
+}
+
+/// The input and known answers of a day, embedded or read from the repository.
+fn input_of(day: &Day) -> Option<(String, [Option<String>; 2])> {
+    if let Some(Embedded { input, answers }) = &day.embedded {
+        return Some((input.to_string(), answers.map(|a| a.map(str::to_string))));
+    }
+    if cfg!(feature = "embed-inputs") {
+        return None;
+    }
+    let path = status::day_path(&env::current_dir().ok()?, day.year, day.day);
+    let input = fs::read_to_string(path.join("input.txt")).ok()?;
+    Some((input, solve::input_answers(&path)))
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let selected = DAYS.iter().filter(|day| {
+        args.year.is_none_or(|year| year == day.year) && args.day.is_none_or(|d| d == day.day)
+    });
+
+    solve::silence_panics();
+    let mut total = Duration::ZERO;
+    let mut failures = 0;
+    for day in selected {
+        solve::print_day_header(&format!("{} Day {:02}", day.year, day.day));
+        let Some((input, answers)) = input_of(day) else {
+            println!("{}No input.{}", ANSI_DIM, ANSI_RESET);
+            continue;
+        };
+        for (index, solver) in day.parts.iter().enumerate() {
+            let part = index as u8 + 1;
+            if args.part.is_some_and(|selected| selected != part) {
+                continue;
+            }
+            let cell: Cell = solve::check_part(solver, &input, answers[index].as_deref());
+            solve::print_checked_part(part, &cell);
+            total += cell.elapsed;
+            failures += cell.is_failure() as usize;
+        }
+    }
+
+    println!(
+        "{}Total:{} {}{:.2?}{}",
+        ANSI_BOLD, ANSI_RESET, ANSI_ITALIC, total, ANSI_RESET
+    );
+    if failures > 0 {
+        println!("{} wrong or panicked parts", failures);
+        process::exit(1);
+    }
+}
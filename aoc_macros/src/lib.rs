@@ -0,0 +1,231 @@
+//! Procedural macros of `advent_of_code`, which re-exports them.
+//!
+//! They are written against `proc_macro` alone: the code is generated as text, around the
+//! tokens of the caller's expression so that errors in it still point at the caller.
+
+use proc_macro::{Delimiter, Group, TokenStream, TokenTree};
+
+/// A piece of a `scan!` pattern.
+#[derive(Debug, PartialEq, Eq)]
+enum Segment {
+    Text(String),
+    /// A `{}` placeholder, with the type written in it if any.
+    Value(Option<String>),
+}
+
+/// Split `pattern` into text and placeholders, `{{` and `}}` being literal braces.
+fn segments(pattern: &str) -> Result<Vec<Segment>, String> {
+    let mut segments = Vec::new();
+    let mut text = String::new();
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                text.push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                text.push('}');
+            }
+            '{' => {
+                let mut ty = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(c) => ty.push(c),
+                        None => return Err(format!("unclosed `{{` in `{}`", pattern)),
+                    }
+                }
+                if !text.is_empty() {
+                    segments.push(Segment::Text(std::mem::take(&mut text)));
+                } else if matches!(segments.last(), Some(Segment::Value(_))) {
+                    return Err(format!(
+                        "placeholders need some text between them in `{}`",
+                        pattern
+                    ));
+                }
+                let ty = ty.trim();
+                segments.push(Segment::Value((!ty.is_empty()).then(|| ty.to_string())));
+            }
+            '}' => return Err(format!("unmatched `}}` in `{}`, write `}}}}`", pattern)),
+            c => text.push(c),
+        }
+    }
+    if !text.is_empty() {
+        segments.push(Segment::Text(text));
+    }
+    Ok(segments)
+}
+
+/// The value of a string literal, as written in the source.
+fn string_value(literal: &str) -> Option<String> {
+    if let Some(raw) = literal.strip_prefix('r') {
+        let hashes = raw.len() - raw.trim_start_matches('#').len();
+        let raw = &raw[hashes..raw.len() - hashes];
+        return Some(raw.strip_prefix('"')?.strip_suffix('"')?.to_string());
+    }
+    let quoted = literal.strip_prefix('"')?.strip_suffix('"')?;
+    let mut value = String::with_capacity(quoted.len());
+    let mut chars = quoted.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            value.push(c);
+            continue;
+        }
+        match chars.next()? {
+            'n' => value.push('\n'),
+            't' => value.push('\t'),
+            'r' => value.push('\r'),
+            '0' => value.push('\0'),
+            c @ ('\\' | '"' | '\'') => value.push(c),
+            _ => return None,
+        }
+    }
+    Some(value)
+}
+
+/// A labelled block scanning `__scan_line` into the values of `segments`.
+fn scanner_block(segments: &[Segment]) -> String {
+    let mut code = String::from(
+        "'__scan: { let mut __scanner = ::advent_of_code::helpers::scan::Scanner::new(__scan_line);",
+    );
+    let mut values = Vec::new();
+    for (index, segment) in segments.iter().enumerate() {
+        match segment {
+            Segment::Text(text) => code.push_str(&format!(
+                "if let ::core::result::Result::Err(e) = __scanner.literal({:?}) {{ break '__scan ::core::result::Result::Err(e); }}",
+                text
+            )),
+            Segment::Value(ty) => {
+                let until = match segments.get(index + 1) {
+                    Some(Segment::Text(text)) => format!("::core::option::Option::Some({:?})", text),
+                    _ => "::core::option::Option::None".to_string(),
+                };
+                let scan = match ty.as_deref() {
+                    Some("str") => format!("__scanner.text({})", until),
+                    Some(ty) => format!("__scanner.value::<{}>({}, {:?})", ty, until, ty),
+                    None => format!("__scanner.value::<_>({}, \"a value\")", until),
+                };
+                let name = format!("__scan_{}", values.len());
+                code.push_str(&format!(
+                    "let {} = match {} {{ ::core::result::Result::Ok(v) => v, ::core::result::Result::Err(e) => break '__scan ::core::result::Result::Err(e) }};",
+                    name, scan
+                ));
+                values.push(name);
+            }
+        }
+    }
+    code.push_str(
+        "if let ::core::result::Result::Err(e) = __scanner.finish() { break '__scan ::core::result::Result::Err(e); }",
+    );
+    let result = match values.as_slice() {
+        [value] => value.clone(),
+        values => format!("({})", values.join(", ")),
+    };
+    code.push_str(&format!("::core::result::Result::Ok({}) }}", result));
+    code
+}
+
+fn expand(input: TokenStream) -> Result<TokenStream, String> {
+    const USAGE: &str = "expected `scan!(line, \"pattern\")` or `scan!(lines(input), \"pattern\")`";
+
+    let tokens: Vec<TokenTree> = input.into_iter().collect();
+    let comma = tokens
+        .iter()
+        .rposition(|token| matches!(token, TokenTree::Punct(p) if p.as_char() == ','))
+        .ok_or(USAGE)?;
+    let pattern = match &tokens[comma + 1..] {
+        [TokenTree::Literal(pattern)] => string_value(&pattern.to_string()).ok_or(USAGE)?,
+        _ => return Err(USAGE.to_string()),
+    };
+    let segments = segments(&pattern)?;
+
+    let (source, lines): (TokenStream, bool) = match &tokens[..comma] {
+        [TokenTree::Ident(ident), TokenTree::Group(group)]
+            if ident.to_string() == "lines" && group.delimiter() == Delimiter::Parenthesis =>
+        {
+            (group.stream(), true)
+        }
+        source => (source.iter().cloned().collect(), false),
+    };
+    if source.is_empty() {
+        return Err(USAGE.to_string());
+    }
+
+    let block = scanner_block(&segments);
+    let rest = if lines {
+        format!(
+            "; __scan_input.lines().enumerate().map(|(__scan_index, __scan_line)| ({}).map_err(|e| e.at_line(__scan_index + 1)))",
+            block
+        )
+    } else {
+        format!("; let __scan_line = __scan_input; {}", block)
+    };
+
+    let mut body: TokenStream = "let __scan_input: &str =".parse().unwrap();
+    body.extend([TokenTree::Group(Group::new(Delimiter::Parenthesis, source))]);
+    body.extend(rest.parse::<TokenStream>().map_err(|e| e.to_string())?);
+    Ok(TokenTree::Group(Group::new(Delimiter::Brace, body)).into())
+}
+
+/// Scan a line against a pattern whose `{}` placeholders are parsed with `FromStr`.
+///
+/// `scan!(line, "move {usize} from {usize} to {usize}")` is a
+/// `Result<(usize, usize, usize), ParseError>`; a placeholder without a type, `{}`, is
+/// inferred and `{str}` is the text itself. A placeholder ends where the text following
+/// it starts. `scan!(lines(input), "...")` scans every line of `input` lazily, locating
+/// errors at their line.
+#[proc_macro]
+pub fn scan(input: TokenStream) -> TokenStream {
+    expand(input).unwrap_or_else(|message| {
+        format!("::core::compile_error!({:?})", message)
+            .parse()
+            .unwrap()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_segments() {
+        assert_eq!(
+            segments("move {usize} from {} to {{{str}}}"),
+            Ok(vec![
+                Segment::Text("move ".to_string()),
+                Segment::Value(Some("usize".to_string())),
+                Segment::Text(" from ".to_string()),
+                Segment::Value(None),
+                Segment::Text(" to {".to_string()),
+                Segment::Value(Some("str".to_string())),
+                Segment::Text("}".to_string()),
+            ])
+        );
+        assert!(segments("{}{}").is_err());
+        assert!(segments("{usize").is_err());
+        assert!(segments("a } b").is_err());
+    }
+
+    #[test]
+    fn test_string_value() {
+        assert_eq!(
+            string_value(r#""a\tb \"c\"""#),
+            Some("a\tb \"c\"".to_string())
+        );
+        assert_eq!(
+            string_value(r##"r#"a\t"b""#"##),
+            Some(r#"a\t"b""#.to_string())
+        );
+        assert_eq!(string_value("42"), None);
+    }
+
+    #[test]
+    fn test_scanner_block_result() {
+        let block = scanner_block(&segments("{u8}").unwrap());
+        assert!(block.ends_with("::core::result::Result::Ok(__scan_0) }"));
+        let block = scanner_block(&segments("{u8}-{u8}").unwrap());
+        assert!(block.ends_with("::core::result::Result::Ok((__scan_0, __scan_1)) }"));
+    }
+}